@@ -532,7 +532,7 @@ impl Catalog {
             .open(
                 now(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 epoch_lower_bound,
             )
             .await?;
@@ -592,6 +592,7 @@ impl Catalog {
                     build_info: &DUMMY_BUILD_INFO,
                     environment_id: environment_id.unwrap_or(EnvironmentId::for_tests()),
                     now,
+                    enforce_monotonic_now: true,
                     boot_ts: previous_ts,
                     skip_migrations: true,
                     cluster_replica_sizes: Default::default(),
@@ -11,7 +11,6 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 
 use futures::future::BoxFuture;
-use maplit::btreeset;
 use mz_catalog::durable::{Item, Transaction};
 use mz_catalog::memory::objects::{StateDiff, StateUpdate, StateUpdateKind};
 use mz_ore::collections::CollectionExt;
@@ -329,9 +328,9 @@ fn assign_new_user_global_ids(
                 }
             };
 
-            let comments = tx.drop_comments(&btreeset! { curr_id })?;
-            for (_id, subcomponent, comment) in comments {
-                tx.update_comment(comment_id, subcomponent, Some(comment))?;
+            let comments = tx.delete_comments_for_object(curr_id)?;
+            for comment in comments {
+                tx.update_comment(comment_id, comment.sub_component, Some(comment.comment))?;
             }
         }
     }
@@ -11,6 +11,7 @@
 
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::str::FromStr;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -20,7 +21,7 @@ use mz_catalog::builtin::{
     Builtin, BuiltinTable, BuiltinView, Fingerprint, BUILTINS, BUILTIN_CLUSTERS,
     BUILTIN_CLUSTER_REPLICAS, BUILTIN_PREFIXES, BUILTIN_ROLES,
 };
-use mz_catalog::config::StateConfig;
+use mz_catalog::config::{monotonic_now, StateConfig};
 use mz_catalog::durable::objects::{
     SystemObjectDescription, SystemObjectMapping, SystemObjectUniqueIdentifier,
 };
@@ -38,6 +39,7 @@ use mz_cluster_client::ReplicaId;
 use mz_compute_client::logging::LogVariant;
 use mz_controller::clusters::ReplicaLogging;
 use mz_controller_types::{is_cluster_size_v2, ClusterId};
+use mz_ore::assert::SOFT_ASSERTIONS;
 use mz_ore::cast::usize_to_u64;
 use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::instrument;
@@ -164,7 +166,7 @@ impl Catalog {
     /// external to a [mz_catalog::durable::DurableCatalogState]
     /// (for example: no [mz_secrets::SecretsReader]).
     pub async fn initialize_state<'a>(
-        config: StateConfig,
+        mut config: StateConfig,
         storage: &'a mut Box<dyn mz_catalog::durable::DurableCatalogState>,
     ) -> Result<
         (
@@ -190,6 +192,10 @@ impl Catalog {
                 );
         }
 
+        if config.enforce_monotonic_now && SOFT_ASSERTIONS.load(Ordering::Relaxed) {
+            config.now = monotonic_now(config.now);
+        }
+
         let mut state = CatalogState {
             database_by_name: BTreeMap::new(),
             database_by_id: BTreeMap::new(),
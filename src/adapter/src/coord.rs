@@ -3381,6 +3381,7 @@ pub fn serve(
                         build_info,
                         environment_id: environment_id.clone(),
                         now: now.clone(),
+                        enforce_monotonic_now: true,
                         boot_ts: boot_ts.clone(),
                         skip_migrations: false,
                         cluster_replica_sizes,
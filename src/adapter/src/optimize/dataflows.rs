@@ -31,11 +31,12 @@ use mz_expr::{
     UnmaterializableFunc, RECURSION_LIMIT,
 };
 use mz_ore::cast::ReinterpretCast;
+use mz_ore::now::to_datetime;
 use mz_ore::stack::{maybe_grow, CheckedRecursion, RecursionGuard, RecursionLimitError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::explain::trace_plan;
 use mz_repr::role_id::RoleId;
-use mz_repr::{Datum, GlobalId, Row};
+use mz_repr::{strconv, Datum, GlobalId, Row};
 use mz_sql::catalog::CatalogRole;
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -585,6 +586,15 @@ fn eval_unmaterializable_func(
         UnmaterializableFunc::MzEnvironmentId => {
             pack(Datum::from(&*state.config().environment_id.to_string()))
         }
+        UnmaterializableFunc::MzEnvironmentIdParts => {
+            let environment_id = &state.config().environment_id;
+            let mut row = Row::default();
+            row.packer().push_list_with(|packer| {
+                packer.push(Datum::Uuid(environment_id.organization_id()));
+                packer.push(Datum::UInt64(environment_id.ordinal()));
+            });
+            Ok(MirScalarExpr::Literal(Ok(row), f.output_type()))
+        }
         UnmaterializableFunc::MzIsSuperuser => pack(Datum::from(session.is_superuser())),
         UnmaterializableFunc::MzNow => match logical_time {
             EvalTime::Time(logical_time) => pack(Datum::MzTimestamp(logical_time)),
@@ -643,6 +653,14 @@ fn eval_unmaterializable_func(
             let t: Datum = state.config().start_time.try_into()?;
             pack(t)
         }
+        UnmaterializableFunc::TimeOfDay => {
+            // Unlike `CurrentTimestamp`, this reports the actual time of evaluation rather than
+            // the start of the transaction.
+            let now = to_datetime((state.config().now)());
+            let mut buf = String::new();
+            strconv::format_time_of_day(&mut buf, &now);
+            pack(Datum::from(buf.as_str()))
+        }
         UnmaterializableFunc::Version => {
             let build_info = state.config().build_info;
             let version = format!(
@@ -507,7 +507,7 @@ async fn upgrade_check(
                     "DEFAULT CLUSTER REPLICA SIZE IS ONLY USED FOR NEW ENVIRONMENTS".into(),
                 bootstrap_role: None,
             },
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await?;
@@ -532,6 +532,7 @@ async fn upgrade_check(
             build_info: &BUILD_INFO,
             environment_id: EnvironmentId::for_tests(),
             now,
+            enforce_monotonic_now: true,
             boot_ts,
             skip_migrations: false,
             cluster_replica_sizes,
@@ -9,6 +9,7 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::net::Ipv4Addr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -19,10 +20,12 @@ use mz_controller::clusters::ReplicaAllocation;
 use mz_orchestrator::MemoryLimit;
 use mz_ore::cast::CastFrom;
 use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::NowFn;
 use mz_repr::GlobalId;
 use mz_sql::catalog::EnvironmentId;
 use mz_sql::session::vars::ConnectionCounter;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use crate::durable::DurableCatalogState;
 
@@ -50,6 +53,9 @@ pub struct StateConfig {
     pub environment_id: EnvironmentId,
     /// Function to generate wall clock now; can be mocked.
     pub now: mz_ore::now::NowFn,
+    /// Whether to wrap `now` with [`monotonic_now`], so that the catalog never observes the wall
+    /// clock going backwards. The check is only installed when soft assertions are enabled.
+    pub enforce_monotonic_now: bool,
     /// Linearizable timestamp of when this environment booted.
     pub boot_ts: mz_repr::Timestamp,
     /// Whether or not to skip catalog migrations.
@@ -85,6 +91,28 @@ pub struct StateConfig {
     pub active_connection_count: Arc<std::sync::Mutex<ConnectionCounter>>,
 }
 
+/// Wraps `now` so that it never returns a value less than one it has already returned.
+///
+/// If `now` jumps backwards, the largest value returned so far is returned instead, until `now`
+/// catches up again. A warning is logged once per backward jump, not on every call.
+pub fn monotonic_now(now: NowFn) -> NowFn {
+    let last = AtomicU64::new(0);
+    let behind = AtomicBool::new(false);
+    NowFn::from(move || {
+        let ts = now();
+        let prev = last.fetch_max(ts, Ordering::SeqCst);
+        if ts < prev {
+            if !behind.swap(true, Ordering::SeqCst) {
+                warn!("now function went backwards from {prev} to {ts}; returning {prev}");
+            }
+            prev
+        } else {
+            behind.store(false, Ordering::SeqCst);
+            ts
+        }
+    })
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClusterReplicaSizeMap(pub BTreeMap<String, ReplicaAllocation>);
 
@@ -268,3 +296,24 @@ impl AwsPrincipalContext {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use mz_ore::now::NowFn;
+
+    use crate::config::monotonic_now;
+
+    #[mz_ore::test]
+    fn test_monotonic_now() {
+        const READINGS: [u64; 7] = [10, 20, 15, 5, 20, 30, 25];
+        let next = AtomicUsize::new(0);
+        let now = NowFn::from(move || READINGS[next.fetch_add(1, Ordering::SeqCst)]);
+        let now = monotonic_now(now);
+
+        let observed: Vec<_> = READINGS.iter().map(|_| now()).collect();
+        assert_eq!(observed, [10, 20, 20, 20, 20, 30, 30]);
+        assert!(observed.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
@@ -9,12 +9,15 @@
 
 //! This crate is responsible for durably storing and modifying the catalog contents.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::num::NonZeroI64;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
+use tracing::warn;
 use uuid::Uuid;
 
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
@@ -23,22 +26,25 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::EpochMillis;
 use mz_persist_client::PersistClient;
+use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
+use mz_sql::names::DatabaseId;
 use mz_storage_types::controller::TxnWalTablesImpl;
 
 use crate::durable::debug::{DebugCatalogState, Trace};
 pub use crate::durable::error::{CatalogError, DurableCatalogError};
 pub use crate::durable::metrics::Metrics;
+pub use crate::durable::objects::state_update::CollectionUpdate;
 use crate::durable::objects::Snapshot;
 pub use crate::durable::objects::{
-    Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, Comment,
-    Database, DefaultPrivilege, IntrospectionSourceIndex, Item, ReplicaConfig, ReplicaLocation,
-    Role, Schema, StorageCollectionMetadata, SystemConfiguration, SystemObjectDescription,
-    SystemObjectMapping, UnfinalizedShard,
+    CatalogExport, Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged,
+    Comment, Database, DefaultPrivilege, IntrospectionSourceIndex, Item, NameResolutionContext,
+    ReplicaConfig, ReplicaLocation, Role, Schema, SnapshotView, StorageCollectionMetadata,
+    SystemConfiguration, SystemObjectDescription, SystemObjectMapping, UnfinalizedShard,
 };
 use crate::durable::persist::UnopenedPersistCatalogState;
-pub use crate::durable::transaction::Transaction;
 use crate::durable::transaction::TransactionBatch;
+pub use crate::durable::transaction::{SavepointToken, Transaction};
 pub use crate::durable::upgrade::CATALOG_VERSION;
 
 pub mod debug;
@@ -62,6 +68,8 @@ pub const SYSTEM_REPLICA_ID_ALLOC_KEY: &str = "system_replica";
 pub const AUDIT_LOG_ID_ALLOC_KEY: &str = "auditlog";
 pub const STORAGE_USAGE_ID_ALLOC_KEY: &str = "storage_usage";
 pub const OID_ALLOC_KEY: &str = "oid";
+/// The maximum number of storage usage events deleted in a single transaction when pruning.
+pub const STORAGE_USAGE_PRUNE_CHUNK_SIZE: usize = 10_000;
 pub(crate) const CATALOG_CONTENT_VERSION_KEY: &str = "catalog_content_version";
 
 #[derive(Clone, Debug)]
@@ -77,6 +85,13 @@ pub type Epoch = NonZeroI64;
 /// If a catalog is not opened, then resources should be release via [`Self::expire`].
 #[async_trait]
 pub trait OpenableDurableCatalogState: Debug + Send {
+    /// Supplies the deploy generation used by [`Self::open`] and [`Self::open_savepoint`] when
+    /// they're not passed one explicitly.
+    fn with_deploy_generation(
+        self: Box<Self>,
+        deploy_generation: u64,
+    ) -> Box<dyn OpenableDurableCatalogState>;
+
     /// Opens the catalog in a mode that accepts and buffers all writes,
     /// but never durably commits them. This is used to check and see if
     /// opening the catalog would be successful, without making any durable
@@ -90,11 +105,15 @@ pub trait OpenableDurableCatalogState: Debug + Send {
     ///   - Catalog migrations fail.
     ///
     /// `initial_ts` is used as the initial timestamp for new environments.
+    ///
+    /// `deploy_generation` takes precedence over a deploy generation supplied via
+    /// [`Self::with_deploy_generation`]. If neither is provided, then the catalog's existing
+    /// deploy generation is left unchanged, and opening an uninitialized catalog fails.
     async fn open_savepoint(
         mut self: Box<Self>,
         initial_ts: EpochMillis,
         bootstrap_args: &BootstrapArgs,
-        deploy_generation: u64,
+        deploy_generation: Option<u64>,
         epoch_lower_bound: Option<Epoch>,
     ) -> Result<Box<dyn DurableCatalogState>, CatalogError>;
 
@@ -108,6 +127,15 @@ pub trait OpenableDurableCatalogState: Debug + Send {
         bootstrap_args: &BootstrapArgs,
     ) -> Result<Box<dyn DurableCatalogState>, CatalogError>;
 
+    /// Checks whether [`Self::open_read_only`] would succeed, without opening the catalog or
+    /// making any changes to it.
+    ///
+    /// Returns the same error that [`Self::open_read_only`] would return if the catalog is
+    /// uninitialized or too old to migrate. A catalog that requires migrations can still be opened
+    /// in read only mode, since the migrations are only applied in memory. This allows callers,
+    /// e.g. a standby, to decide whether to wait for another process to initialize the catalog.
+    async fn would_open_read_only_succeed(&mut self) -> Result<(), CatalogError>;
+
     /// Opens the catalog in a writeable mode. Optionally initializes the
     /// catalog, if it has not been initialized, and perform any migrations
     /// needed.
@@ -115,11 +143,15 @@ pub trait OpenableDurableCatalogState: Debug + Send {
     /// `initial_ts` is used as the initial timestamp for new environments.
     /// `epoch_lower_bound` is used as a lower bound for the epoch that is used by the returned
     /// catalog.
+    ///
+    /// `deploy_generation` takes precedence over a deploy generation supplied via
+    /// [`Self::with_deploy_generation`]. If neither is provided, then the catalog's existing
+    /// deploy generation is left unchanged, and opening an uninitialized catalog fails.
     async fn open(
         mut self: Box<Self>,
         initial_ts: EpochMillis,
         bootstrap_args: &BootstrapArgs,
-        deploy_generation: u64,
+        deploy_generation: Option<u64>,
         epoch_lower_bound: Option<Epoch>,
     ) -> Result<Box<dyn DurableCatalogState>, CatalogError>;
 
@@ -142,8 +174,19 @@ pub trait OpenableDurableCatalogState: Debug + Send {
     async fn epoch(&mut self) -> Result<Epoch, CatalogError>;
 
     /// Get the deployment generation of this instance.
+    ///
+    /// The value is read from the durable store on the first successful call and cached
+    /// afterwards. Later calls only check whether the catalog has been written to since the value
+    /// was cached, which is cheaper than reading it again, and re-read it if so. They therefore
+    /// observe a new deployment generation written by another instance, and return an error if
+    /// this instance has been fenced out. Use [`Self::get_deployment_generation_uncached`] to
+    /// always read the value from the durable store.
     async fn get_deployment_generation(&mut self) -> Result<u64, CatalogError>;
 
+    /// Get the deployment generation of this instance directly from the durable store, bypassing
+    /// (and refreshing) the cache used by [`Self::get_deployment_generation`].
+    async fn get_deployment_generation_uncached(&mut self) -> Result<u64, CatalogError>;
+
     /// Get the `enable_0dt_deployment` config value of this instance.
     ///
     /// This mirrors the `enable_0dt_deployment` "system var" so that we can
@@ -208,6 +251,107 @@ pub trait ReadOnlyDurableCatalogState: Debug + Send {
 
     /// Get a snapshot of the catalog.
     async fn snapshot(&mut self) -> Result<Snapshot, CatalogError>;
+
+    /// Get a fingerprint of the entire contents of the catalog.
+    ///
+    /// The fingerprint changes whenever a committed change modifies the contents of the catalog,
+    /// so callers can compare it to detect when cached state needs to be reloaded. Computing it
+    /// reads, serializes, and hashes the entire catalog, so it is as expensive as taking a
+    /// [`snapshot`](Self::snapshot) and is not suitable for polling in a tight loop. See
+    /// [`Snapshot::fingerprint`].
+    async fn content_fingerprint(&mut self) -> Result<u64, CatalogError> {
+        Ok(self.snapshot().await?.fingerprint())
+    }
+
+    /// Get a snapshot of the catalog as it existed at the historical timestamp `ts`.
+    ///
+    /// This is intended for debugging and point-in-time inspection. Returns an error if `ts` is
+    /// before the since of the catalog or not before its upper. Catalogs whose changes aren't
+    /// durably recorded, such as savepoint catalogs, return [`DurableCatalogError::Unsupported`].
+    ///
+    /// Backends only retain a bounded amount of recent history, older timestamps are compacted
+    /// away and become unreadable.
+    async fn snapshot_as_of(&mut self, ts: mz_repr::Timestamp) -> Result<Snapshot, CatalogError>;
+
+    /// Get all persisted system configurations, sorted by name.
+    async fn get_system_configurations(
+        &mut self,
+    ) -> Result<Vec<SystemConfiguration>, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(SnapshotView::new(&snapshot).system_configurations()?)
+    }
+
+    /// Get the value of the persisted system configuration `name`, or `None` if it isn't set.
+    ///
+    /// The default implementation reads a full snapshot, so backends should override it to only
+    /// read the requested configuration. Callers that look up many configurations should use
+    /// [`ReadOnlyDurableCatalogState::get_system_configurations_map`] instead.
+    async fn get_system_configuration(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<String>, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(SnapshotView::new(&snapshot).system_configuration(name))
+    }
+
+    /// Get all persisted system configurations as a map from name to value.
+    async fn get_system_configurations_map(
+        &mut self,
+    ) -> Result<BTreeMap<String, String>, CatalogError> {
+        Ok(self
+            .get_system_configurations()
+            .await?
+            .into_iter()
+            .map(|config| (config.name, config.value))
+            .collect())
+    }
+
+    /// Get the IDs of all roles that `role` is a member of, either directly or transitively.
+    ///
+    /// `role` is only included in the result if it is a member of itself through a cycle.
+    async fn get_role_membership_closure(
+        &mut self,
+        role: RoleId,
+    ) -> Result<BTreeSet<RoleId>, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(SnapshotView::new(&snapshot).role_membership_closure(role)?)
+    }
+
+    /// Get all schemas that belong to the database `database_id`, sorted by ID.
+    ///
+    /// Ambient schemas, which don't belong to any database, are never returned.
+    async fn get_schemas_in_database(
+        &mut self,
+        database_id: DatabaseId,
+    ) -> Result<Vec<Schema>, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(SnapshotView::new(&snapshot).schemas_in_database(database_id)?)
+    }
+
+    /// Get all replicas that belong to the cluster `cluster_id`, sorted by replica ID.
+    async fn get_cluster_replicas_for(
+        &mut self,
+        cluster_id: ClusterId,
+    ) -> Result<Vec<ClusterReplica>, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(SnapshotView::new(&snapshot).cluster_replicas_for(cluster_id)?)
+    }
+
+    /// Returns every object in the catalog, taken from a single consistent snapshot.
+    async fn export(&mut self) -> Result<CatalogExport, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(CatalogExport::from_snapshot(snapshot)?)
+    }
+
+    /// Returns the databases, schemas, and roles needed for name resolution, along with maps from
+    /// their names to their IDs.
+    ///
+    /// All three are taken from a single consistent snapshot, so a concurrent commit is either
+    /// entirely visible or not visible at all.
+    async fn get_name_resolution_context(&mut self) -> Result<NameResolutionContext, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(NameResolutionContext::from_snapshot(snapshot)?)
+    }
 }
 
 /// A read-write API for the durable catalog state.
@@ -223,11 +367,59 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
     async fn commit_transaction(&mut self, txn_batch: TransactionBatch)
         -> Result<(), CatalogError>;
 
+    /// Commits `updates` to the durable catalog state as a single atomic write, at the current
+    /// upper of the catalog.
+    ///
+    /// Unlike [`DurableCatalogState::commit_transaction`], callers don't need to open a
+    /// [`Transaction`], which copies the entire catalog, and build a [`TransactionBatch`]. Callers
+    /// must not have an open [`Transaction`] while calling this.
+    ///
+    /// Every update must either insert a key that doesn't exist or retract the current value of a
+    /// key. If any update is invalid, an error is returned and none of the updates are committed.
+    /// Validating the updates requires buffering all of them before anything is written, so peak
+    /// memory usage still grows with the number of updates.
+    async fn commit_transaction_streaming(
+        &mut self,
+        updates: Box<dyn Iterator<Item = CollectionUpdate> + Send + '_>,
+    ) -> Result<(), CatalogError>;
+
     /// Confirms that this catalog is connected as the current leader.
     ///
     /// NB: We may remove this in later iterations of Pv2.
     async fn confirm_leadership(&mut self) -> Result<(), CatalogError>;
 
+    /// Confirms that this catalog is connected as the current leader, retrying transient errors.
+    ///
+    /// Makes at most `max_attempts` attempts, sleeping for `backoff` between them, and returns the
+    /// last error if every attempt fails. Only errors that are
+    /// [transient](DurableCatalogError::is_transient) are retried. Any other error, such as a
+    /// fence error, which means that another catalog has taken over, is returned immediately.
+    async fn confirm_leadership_with_retry(
+        &mut self,
+        max_attempts: usize,
+        backoff: Duration,
+    ) -> Result<(), CatalogError> {
+        retry_confirm_leadership(self, max_attempts, backoff, |state| {
+            state.confirm_leadership()
+        })
+        .await
+    }
+
+    /// Ensures that every transaction previously committed through this catalog is durable and
+    /// visible to any catalog that is opened afterwards.
+    ///
+    /// Returns an error if this catalog has been fenced out. Changes made to a savepoint catalog
+    /// are never durable, so this is a no-op for savepoint and read-only catalogs.
+    async fn sync(&mut self) -> Result<(), CatalogError>;
+
+    /// Waits until this catalog has been fenced out by another catalog and returns the fence
+    /// error.
+    ///
+    /// Resolves immediately if this catalog has already been fenced.
+    ///
+    /// NB: This method is not cancel safe.
+    async fn await_fenced(&mut self) -> DurableCatalogError;
+
     /// Gets all storage usage events and permanently deletes from the catalog those
     /// that happened more than the retention period ago from boot_ts.
     ///
@@ -239,7 +431,20 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
         wait_for_consolidation: bool,
     ) -> Result<Vec<VersionedStorageUsage>, CatalogError>;
 
+    /// Permanently deletes the audit log events with the given `ids` from the catalog, in a
+    /// single transaction.
+    ///
+    /// This is only meant for redacting specific events, e.g. for compliance reasons. The audit
+    /// log is otherwise append-only and events are never pruned. IDs that don't correspond to an
+    /// event are ignored.
+    ///
+    /// Returns the number of events that were deleted.
+    async fn delete_audit_logs(&mut self, ids: &[u64]) -> Result<usize, CatalogError>;
+
     /// Allocates and returns `amount` IDs of `id_type`.
+    ///
+    /// If `amount` is 0, then an empty `Vec` is returned without advancing the allocator or
+    /// writing to the catalog.
     #[mz_ore::instrument(level = "debug")]
     async fn allocate_id(&mut self, id_type: &str, amount: u64) -> Result<Vec<u64>, CatalogError> {
         if amount == 0 {
@@ -286,6 +491,29 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
     }
 }
 
+/// Calls `confirm` on `state` until it succeeds, it returns an error that isn't transient, or
+/// `max_attempts` attempts have been made. A `max_attempts` of zero is treated as one.
+async fn retry_confirm_leadership<T: ?Sized>(
+    state: &mut T,
+    max_attempts: usize,
+    backoff: Duration,
+    mut confirm: impl for<'a> FnMut(&'a mut T) -> BoxFuture<'a, Result<(), CatalogError>> + Send,
+) -> Result<(), CatalogError> {
+    let max_attempts = max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match confirm(state).await {
+            Ok(()) => return Ok(()),
+            Err(CatalogError::Durable(e)) if e.is_transient() && attempt < max_attempts => {
+                warn!("failed to confirm catalog leadership (attempt {attempt}), retrying: {e}");
+                attempt += 1;
+                tokio::time::sleep(backoff).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 /// Creates an openable durable catalog state implemented using persist.
 pub async fn persist_backed_catalog_state(
     persist_client: PersistClient,
@@ -330,3 +558,95 @@ pub fn test_bootstrap_args() -> BootstrapArgs {
         bootstrap_role: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::time::Duration;
+
+    use futures::FutureExt;
+    use mz_sql::catalog::CatalogError as SqlCatalogError;
+
+    use crate::durable::{retry_confirm_leadership, CatalogError, DurableCatalogError};
+
+    /// Stands in for a catalog whose leadership checks return queued results.
+    #[derive(Debug, Default)]
+    struct MockLeader {
+        results: VecDeque<Result<(), CatalogError>>,
+        attempts: usize,
+    }
+
+    impl MockLeader {
+        fn new(results: impl IntoIterator<Item = Result<(), CatalogError>>) -> Self {
+            MockLeader {
+                results: results.into_iter().collect(),
+                attempts: 0,
+            }
+        }
+
+        async fn confirm_leadership_with_retry(
+            &mut self,
+            max_attempts: usize,
+        ) -> Result<(), CatalogError> {
+            retry_confirm_leadership(self, max_attempts, Duration::ZERO, |state| {
+                state.attempts += 1;
+                let result = state.results.pop_front().expect("unexpected attempt");
+                futures::future::ready(result).boxed()
+            })
+            .await
+        }
+    }
+
+    fn transient_error() -> CatalogError {
+        DurableCatalogError::Conflict("the catalog advanced concurrently".into()).into()
+    }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_confirm_leadership_retries_transient_errors() {
+        let mut leader = MockLeader::new([Err(transient_error()), Err(transient_error()), Ok(())]);
+        leader
+            .confirm_leadership_with_retry(5)
+            .await
+            .expect("third attempt succeeds");
+        assert_eq!(leader.attempts, 3);
+
+        let mut leader = MockLeader::new([Err(transient_error()), Err(transient_error())]);
+        let err = leader
+            .confirm_leadership_with_retry(2)
+            .await
+            .expect_err("attempts exhausted");
+        assert!(matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::Conflict(_))
+        ));
+        assert_eq!(leader.attempts, 2);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_confirm_leadership_does_not_retry_permanent_errors() {
+        let permanent_errors: Vec<fn() -> CatalogError> = vec![
+            || DurableCatalogError::Fence("fenced by a newer catalog".into()).into(),
+            || DurableCatalogError::Uninitialized.into(),
+            || {
+                DurableCatalogError::IncompatibleDataVersion {
+                    found_version: 1,
+                    min_catalog_version: 2,
+                    catalog_version: 3,
+                }
+                .into()
+            },
+            || DurableCatalogError::NotWritable("catalog is read only".into()).into(),
+            || SqlCatalogError::UnknownRole("foo".into()).into(),
+        ];
+        for permanent_error in permanent_errors {
+            let expected = permanent_error().to_string();
+            let mut leader = MockLeader::new([Err(permanent_error()), Ok(())]);
+            let err = leader
+                .confirm_leadership_with_retry(5)
+                .await
+                .expect_err("permanent errors are not retried");
+            assert_eq!(err.to_string(), expected);
+            assert_eq!(leader.attempts, 1, "{expected}");
+        }
+    }
+}
@@ -9,6 +9,7 @@
 
 use std::fmt::Debug;
 
+use mz_controller_types::{ClusterId, ReplicaId};
 use mz_proto::TryFromProtoError;
 use mz_repr::Timestamp;
 use mz_sql::catalog::CatalogError as SqlCatalogError;
@@ -65,9 +66,41 @@ pub enum DurableCatalogError {
     /// Uniqueness violation occurred in some catalog collection.
     #[error("uniqueness violation")]
     UniquenessViolation,
+    /// An object was given the same name as another object in the same scope.
+    #[error("name '{name}' is already in use in {scope}")]
+    DuplicateName { name: String, scope: String },
+    /// A cluster replica was written with a different cluster than the one it belongs to.
+    #[error(
+        "cluster replica {replica_id} belongs to cluster {cluster_id}, not cluster {requested_cluster_id}"
+    )]
+    ReplicaClusterMismatch {
+        replica_id: ReplicaId,
+        cluster_id: ClusterId,
+        requested_cluster_id: ClusterId,
+    },
     /// A programming error occurred during a [`mz_storage_client::controller::StorageTxn`].
     #[error(transparent)]
     Storage(StorageError<Timestamp>),
+    /// A historical read was requested at a timestamp that is not readable.
+    #[error("timestamp {as_of} is not readable, valid timestamps are in [{since}, {upper})")]
+    InvalidReadTimestamp {
+        as_of: Timestamp,
+        since: Timestamp,
+        upper: Timestamp,
+    },
+    /// The requested operation is not supported by this catalog, because of its backend or the
+    /// mode it was opened in.
+    #[error("{operation} is not supported by this catalog")]
+    Unsupported { operation: &'static str },
+    /// A transaction conflicted with another transaction that committed after it started.
+    #[error("transaction conflict: {0}")]
+    Conflict(String),
+    /// An update is inconsistent with the current contents of the catalog.
+    #[error("invalid update: {0}")]
+    InvalidUpdate(String),
+    /// A savepoint was rolled back to in a transaction other than the one that created it.
+    #[error("savepoint was created by a different transaction")]
+    InvalidSavepoint,
 }
 
 impl DurableCatalogError {
@@ -82,7 +115,38 @@ impl DurableCatalogError {
             | DurableCatalogError::NotWritable(_)
             | DurableCatalogError::DuplicateKey
             | DurableCatalogError::UniquenessViolation
-            | DurableCatalogError::Storage(_) => false,
+            | DurableCatalogError::DuplicateName { .. }
+            | DurableCatalogError::ReplicaClusterMismatch { .. }
+            | DurableCatalogError::Storage(_)
+            | DurableCatalogError::InvalidReadTimestamp { .. }
+            | DurableCatalogError::Unsupported { .. }
+            | DurableCatalogError::Conflict(_)
+            | DurableCatalogError::InvalidUpdate(_)
+            | DurableCatalogError::InvalidSavepoint => false,
+        }
+    }
+
+    /// Reports whether the error is transient, meaning that retrying the operation that
+    /// returned it may succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DurableCatalogError::Conflict(_)
+            | DurableCatalogError::Storage(StorageError::ResourceExhausted(_)) => true,
+            DurableCatalogError::Fence(_)
+            | DurableCatalogError::IncompatibleDataVersion { .. }
+            | DurableCatalogError::IncompatiblePersistVersion { .. }
+            | DurableCatalogError::Uninitialized
+            | DurableCatalogError::NotWritable(_)
+            | DurableCatalogError::Proto(_)
+            | DurableCatalogError::DuplicateKey
+            | DurableCatalogError::UniquenessViolation
+            | DurableCatalogError::DuplicateName { .. }
+            | DurableCatalogError::ReplicaClusterMismatch { .. }
+            | DurableCatalogError::Storage(_)
+            | DurableCatalogError::InvalidReadTimestamp { .. }
+            | DurableCatalogError::Unsupported { .. }
+            | DurableCatalogError::InvalidUpdate(_)
+            | DurableCatalogError::InvalidSavepoint => false,
         }
     }
 
@@ -28,11 +28,13 @@
 pub mod serialization;
 pub(crate) mod state_update;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
 use mz_controller::clusters::ReplicaLogging;
 use mz_controller_types::{ClusterId, ReplicaId};
+use mz_ore::cast::CastFrom;
+use mz_proto::{RustType, TryFromProtoError};
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
@@ -43,6 +45,8 @@ use mz_sql::catalog::{
 use mz_sql::names::{CommentObjectId, DatabaseId, SchemaId};
 use mz_sql::plan::ClusterSchedule;
 use proptest_derive::Arbitrary;
+use prost::Message;
+use sha2::{Digest, Sha256};
 
 use crate::durable::objects::serialization::proto;
 
@@ -922,6 +926,275 @@ impl DurableType for UnfinalizedShard {
     }
 }
 
+/// All objects in the catalog, as of a single consistent snapshot.
+#[derive(Debug, Clone)]
+pub struct CatalogExport {
+    pub databases: Vec<Database>,
+    pub schemas: Vec<Schema>,
+    pub roles: Vec<Role>,
+    pub items: Vec<Item>,
+    pub comments: Vec<Comment>,
+    pub clusters: Vec<Cluster>,
+    pub cluster_replicas: Vec<ClusterReplica>,
+    pub introspection_sources: Vec<IntrospectionSourceIndex>,
+    pub id_allocators: Vec<IdAlloc>,
+    pub configs: Vec<Config>,
+    pub settings: Vec<Setting>,
+    pub system_object_mappings: Vec<SystemObjectMapping>,
+    pub system_configurations: Vec<SystemConfiguration>,
+    pub default_privileges: Vec<DefaultPrivilege>,
+    pub system_privileges: Vec<MzAclItem>,
+    pub storage_collection_metadata: Vec<StorageCollectionMetadata>,
+    pub unfinalized_shards: Vec<UnfinalizedShard>,
+}
+
+impl CatalogExport {
+    /// Converts every collection in `snapshot` into its [`DurableType`] representation.
+    pub(crate) fn from_snapshot(
+        Snapshot {
+            databases,
+            schemas,
+            roles,
+            items,
+            comments,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            system_object_mappings,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            storage_collection_metadata,
+            unfinalized_shards,
+            txn_wal_shard: _,
+        }: Snapshot,
+    ) -> Result<CatalogExport, TryFromProtoError> {
+        Ok(CatalogExport {
+            databases: from_proto(databases)?,
+            schemas: from_proto(schemas)?,
+            roles: from_proto(roles)?,
+            items: from_proto(items)?,
+            comments: from_proto(comments)?,
+            clusters: from_proto(clusters)?,
+            cluster_replicas: from_proto(cluster_replicas)?,
+            introspection_sources: from_proto(introspection_sources)?,
+            id_allocators: from_proto(id_allocator)?,
+            configs: from_proto(configs)?,
+            settings: from_proto(settings)?,
+            system_object_mappings: from_proto(system_object_mappings)?,
+            system_configurations: from_proto(system_configurations)?,
+            default_privileges: from_proto(default_privileges)?,
+            system_privileges: from_proto(system_privileges)?,
+            storage_collection_metadata: from_proto(storage_collection_metadata)?,
+            unfinalized_shards: from_proto(unfinalized_shards)?,
+        })
+    }
+}
+
+/// The databases, schemas, and roles in the catalog, as of a single consistent snapshot, along
+/// with maps from their names to their IDs.
+#[derive(Debug, Clone)]
+pub struct NameResolutionContext {
+    pub databases: Vec<Database>,
+    pub schemas: Vec<Schema>,
+    pub roles: Vec<Role>,
+    /// Maps database names to database IDs.
+    pub database_ids: BTreeMap<String, DatabaseId>,
+    /// Maps the database ID, or `None` for ambient schemas, and name of each schema to its ID.
+    pub schema_ids: BTreeMap<(Option<DatabaseId>, String), SchemaId>,
+    /// Maps role names to role IDs.
+    pub role_ids: BTreeMap<String, RoleId>,
+}
+
+impl NameResolutionContext {
+    /// Converts the databases, schemas, and roles in `snapshot` and indexes them by name.
+    pub(crate) fn from_snapshot(
+        Snapshot {
+            databases,
+            schemas,
+            roles,
+            ..
+        }: Snapshot,
+    ) -> Result<NameResolutionContext, TryFromProtoError> {
+        let databases: Vec<Database> = from_proto(databases)?;
+        let schemas: Vec<Schema> = from_proto(schemas)?;
+        let roles: Vec<Role> = from_proto(roles)?;
+        let database_ids = databases
+            .iter()
+            .map(|database| (database.name.clone(), database.id))
+            .collect();
+        let schema_ids = schemas
+            .iter()
+            .map(|schema| ((schema.database_id, schema.name.clone()), schema.id))
+            .collect();
+        let role_ids = roles
+            .iter()
+            .map(|role| (role.name.clone(), role.id))
+            .collect();
+        Ok(NameResolutionContext {
+            databases,
+            schemas,
+            roles,
+            database_ids,
+            schema_ids,
+            role_ids,
+        })
+    }
+}
+
+/// Typed getters over a [`Snapshot`].
+///
+/// Every [`crate::durable::ReadOnlyDurableCatalogState`] can produce a [`Snapshot`], so getters
+/// that only need to decode and filter a collection are implemented once here rather than once
+/// per backend. Backends that want to avoid building a full [`Snapshot`] can build one that only
+/// contains the collections a getter reads.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotView<'a> {
+    snapshot: &'a Snapshot,
+}
+
+impl<'a> SnapshotView<'a> {
+    pub fn new(snapshot: &'a Snapshot) -> SnapshotView<'a> {
+        SnapshotView { snapshot }
+    }
+
+    /// Returns the next ID of `id_type`, or `None` if there is no such ID allocator.
+    pub fn next_id(&self, id_type: &str) -> Option<u64> {
+        self.snapshot
+            .id_allocator
+            .iter()
+            .find(|(key, _)| key.name == id_type)
+            .map(|(_, value)| value.next_id)
+    }
+
+    /// Returns the value of the config `key`, or `None` if it isn't set.
+    pub fn config(&self, key: &str) -> Option<u64> {
+        self.snapshot
+            .configs
+            .iter()
+            .find(|(config_key, _)| config_key.key == key)
+            .map(|(_, value)| value.value)
+    }
+
+    /// Returns all persisted system configurations, sorted by name.
+    pub fn system_configurations(&self) -> Result<Vec<SystemConfiguration>, TryFromProtoError> {
+        self.snapshot
+            .system_configurations
+            .iter()
+            .map(|(key, value)| decode(key, value))
+            .collect()
+    }
+
+    /// Returns the value of the persisted system configuration `name`, or `None` if it isn't
+    /// set.
+    pub fn system_configuration(&self, name: &str) -> Option<String> {
+        let key = proto::ServerConfigurationKey {
+            name: name.to_string(),
+        };
+        self.snapshot
+            .system_configurations
+            .get(&key)
+            .map(|value| value.value.clone())
+    }
+
+    /// Returns the IDs of all roles that `role` is a member of, either directly or transitively.
+    ///
+    /// `role` is only included in the result if it is a member of itself through a cycle.
+    pub fn role_membership_closure(
+        &self,
+        role: RoleId,
+    ) -> Result<BTreeSet<RoleId>, TryFromProtoError> {
+        let mut memberships = BTreeMap::new();
+        for (key, value) in &self.snapshot.roles {
+            let role: Role = decode(key, value)?;
+            memberships.insert(role.id, role.membership.map.into_keys().collect::<Vec<_>>());
+        }
+
+        let mut closure = BTreeSet::new();
+        let mut to_visit = vec![role];
+        while let Some(role) = to_visit.pop() {
+            for member_of in memberships.get(&role).into_iter().flatten() {
+                // Only visit each role once, so that cycles terminate.
+                if closure.insert(*member_of) {
+                    to_visit.push(*member_of);
+                }
+            }
+        }
+        Ok(closure)
+    }
+
+    /// Returns all schemas that belong to the database `database_id`, sorted by ID.
+    ///
+    /// Ambient schemas, which don't belong to any database, are never returned.
+    pub fn schemas_in_database(
+        &self,
+        database_id: DatabaseId,
+    ) -> Result<Vec<Schema>, TryFromProtoError> {
+        let mut schemas = Vec::new();
+        for (key, value) in &self.snapshot.schemas {
+            let schema: Schema = decode(key, value)?;
+            if schema.database_id == Some(database_id) {
+                schemas.push(schema);
+            }
+        }
+        schemas.sort_by_key(|schema| schema.id);
+        Ok(schemas)
+    }
+
+    /// Returns all replicas that belong to the cluster `cluster_id`, sorted by replica ID.
+    pub fn cluster_replicas_for(
+        &self,
+        cluster_id: ClusterId,
+    ) -> Result<Vec<ClusterReplica>, TryFromProtoError> {
+        let proto_cluster_id = Some(cluster_id.into_proto());
+        let mut replicas = Vec::new();
+        for (key, value) in &self.snapshot.cluster_replicas {
+            // Compare the encoded cluster ID first, to avoid decoding replicas we'll discard.
+            if value.cluster_id != proto_cluster_id {
+                continue;
+            }
+            replicas.push(decode(key, value)?);
+        }
+        replicas.sort_by_key(|replica: &ClusterReplica| replica.replica_id);
+        Ok(replicas)
+    }
+}
+
+/// Converts a single entry of a [`Snapshot`] into its [`DurableType`] representation.
+fn decode<T, KP, VP>(key: &KP, value: &VP) -> Result<T, TryFromProtoError>
+where
+    T: DurableType,
+    T::Key: RustType<KP>,
+    T::Value: RustType<VP>,
+    KP: Clone,
+    VP: Clone,
+{
+    Ok(T::from_key_value(
+        RustType::from_proto(key.clone())?,
+        RustType::from_proto(value.clone())?,
+    ))
+}
+
+/// Converts a collection of a [`Snapshot`] into its [`DurableType`] representation.
+fn from_proto<T, KP, VP>(map: BTreeMap<KP, VP>) -> Result<Vec<T>, TryFromProtoError>
+where
+    T: DurableType,
+    T::Key: RustType<KP>,
+    T::Value: RustType<VP>,
+{
+    map.into_iter()
+        .map(|(key, value)| {
+            Ok(T::from_key_value(
+                RustType::from_proto(key)?,
+                RustType::from_proto(value)?,
+            ))
+        })
+        .collect()
+}
+
 // Structs used internally to represent on-disk state.
 
 /// A snapshot of the current on-disk state.
@@ -956,6 +1229,86 @@ impl Snapshot {
     pub fn empty() -> Snapshot {
         Snapshot::default()
     }
+
+    /// Returns a fingerprint of the contents of every collection in the snapshot.
+    ///
+    /// The fingerprint is stable across processes and versions, and any difference in contents
+    /// results in a different fingerprint with overwhelming probability. Collections are
+    /// iterated in key order, so the fingerprint does not depend on the order in which entries
+    /// were inserted.
+    ///
+    /// Every key and value is re-encoded and hashed on each call, so the cost is linear in the
+    /// size of the snapshot.
+    pub fn fingerprint(&self) -> u64 {
+        fn hash_collection<K: Message, V: Message>(
+            hasher: &mut Sha256,
+            name: &str,
+            collection: &BTreeMap<K, V>,
+        ) {
+            // Length prefix everything so that adjacent entries can't be confused with each
+            // other.
+            let mut update = |bytes: &[u8]| {
+                hasher.update(u64::cast_from(bytes.len()).to_le_bytes());
+                hasher.update(bytes);
+            };
+            update(name.as_bytes());
+            update(&u64::cast_from(collection.len()).to_le_bytes());
+            for (key, value) in collection {
+                update(&key.encode_to_vec());
+                update(&value.encode_to_vec());
+            }
+        }
+
+        let Snapshot {
+            databases,
+            schemas,
+            roles,
+            items,
+            comments,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            system_object_mappings,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            storage_collection_metadata,
+            unfinalized_shards,
+            txn_wal_shard,
+        } = self;
+        let mut hasher = Sha256::new();
+        hash_collection(&mut hasher, "databases", databases);
+        hash_collection(&mut hasher, "schemas", schemas);
+        hash_collection(&mut hasher, "roles", roles);
+        hash_collection(&mut hasher, "items", items);
+        hash_collection(&mut hasher, "comments", comments);
+        hash_collection(&mut hasher, "clusters", clusters);
+        hash_collection(&mut hasher, "cluster_replicas", cluster_replicas);
+        hash_collection(&mut hasher, "introspection_sources", introspection_sources);
+        hash_collection(&mut hasher, "id_allocator", id_allocator);
+        hash_collection(&mut hasher, "configs", configs);
+        hash_collection(&mut hasher, "settings", settings);
+        hash_collection(
+            &mut hasher,
+            "system_object_mappings",
+            system_object_mappings,
+        );
+        hash_collection(&mut hasher, "system_configurations", system_configurations);
+        hash_collection(&mut hasher, "default_privileges", default_privileges);
+        hash_collection(&mut hasher, "system_privileges", system_privileges);
+        hash_collection(
+            &mut hasher,
+            "storage_collection_metadata",
+            storage_collection_metadata,
+        );
+        hash_collection(&mut hasher, "unfinalized_shards", unfinalized_shards);
+        hash_collection(&mut hasher, "txn_wal_shard", txn_wal_shard);
+        let digest = hasher.finalize();
+        u64::from_le_bytes(digest[..8].try_into().expect("digest is 32 bytes"))
+    }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Ord, Hash)]
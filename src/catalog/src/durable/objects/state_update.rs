@@ -15,7 +15,7 @@ use mz_repr::Diff;
 use mz_storage_types::sources::SourceData;
 use proptest_derive::Arbitrary;
 
-use crate::durable::debug::CollectionType;
+use crate::durable::debug::{Collection, CollectionType};
 use crate::durable::objects::serialization::proto;
 use crate::durable::objects::DurableType;
 use crate::durable::persist::Timestamp;
@@ -68,6 +68,25 @@ where
     }
 }
 
+/// A single update to one of the durable catalog collections.
+///
+/// See [`crate::durable::DurableCatalogState::commit_transaction_streaming`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionUpdate {
+    pub(crate) kind: StateUpdateKind,
+    pub(crate) diff: Diff,
+}
+
+impl CollectionUpdate {
+    /// Creates an update that applies `diff` to the entry `key` and `value` of collection `T`.
+    pub fn new<T: Collection>(key: T::Key, value: T::Value, diff: Diff) -> CollectionUpdate {
+        CollectionUpdate {
+            kind: T::update(key, value),
+            diff,
+        }
+    }
+}
+
 /// A single update to the catalog state.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct StateUpdate<T: IntoStateUpdateKindRaw = StateUpdateKind> {
@@ -298,6 +317,49 @@ impl StateUpdateKind {
             StateUpdateKind::TxnWalShard(_, _) => Some(CollectionType::TxnWalShard),
         }
     }
+
+    /// Returns a copy of this update with its value replaced by the default value, so that two
+    /// updates to the same key of the same collection compare equal. Returns `None` for updates
+    /// that don't belong to a collection.
+    pub(crate) fn key(&self) -> Option<StateUpdateKind> {
+        fn key<K: Clone, V: Default>(
+            key: &K,
+            kind: fn(K, V) -> StateUpdateKind,
+        ) -> Option<StateUpdateKind> {
+            Some(kind(key.clone(), V::default()))
+        }
+        match self {
+            StateUpdateKind::AuditLog(k, _) => key(k, StateUpdateKind::AuditLog),
+            StateUpdateKind::Cluster(k, _) => key(k, StateUpdateKind::Cluster),
+            StateUpdateKind::ClusterReplica(k, _) => key(k, StateUpdateKind::ClusterReplica),
+            StateUpdateKind::Comment(k, _) => key(k, StateUpdateKind::Comment),
+            StateUpdateKind::Config(k, _) => key(k, StateUpdateKind::Config),
+            StateUpdateKind::Database(k, _) => key(k, StateUpdateKind::Database),
+            StateUpdateKind::DefaultPrivilege(k, _) => key(k, StateUpdateKind::DefaultPrivilege),
+            StateUpdateKind::Epoch(_) => None,
+            StateUpdateKind::IdAllocator(k, _) => key(k, StateUpdateKind::IdAllocator),
+            StateUpdateKind::IntrospectionSourceIndex(k, _) => {
+                key(k, StateUpdateKind::IntrospectionSourceIndex)
+            }
+            StateUpdateKind::Item(k, _) => key(k, StateUpdateKind::Item),
+            StateUpdateKind::Role(k, _) => key(k, StateUpdateKind::Role),
+            StateUpdateKind::Schema(k, _) => key(k, StateUpdateKind::Schema),
+            StateUpdateKind::Setting(k, _) => key(k, StateUpdateKind::Setting),
+            StateUpdateKind::StorageUsage(k, _) => key(k, StateUpdateKind::StorageUsage),
+            StateUpdateKind::SystemConfiguration(k, _) => {
+                key(k, StateUpdateKind::SystemConfiguration)
+            }
+            StateUpdateKind::SystemObjectMapping(k, _) => {
+                key(k, StateUpdateKind::SystemObjectMapping)
+            }
+            StateUpdateKind::SystemPrivilege(k, _) => key(k, StateUpdateKind::SystemPrivilege),
+            StateUpdateKind::StorageCollectionMetadata(k, _) => {
+                key(k, StateUpdateKind::StorageCollectionMetadata)
+            }
+            StateUpdateKind::UnfinalizedShard(k, _) => key(k, StateUpdateKind::UnfinalizedShard),
+            StateUpdateKind::TxnWalShard(k, _) => key(k, StateUpdateKind::TxnWalShard),
+        }
+    }
 }
 
 impl RustType<proto::StateUpdateKind> for StateUpdateKind {
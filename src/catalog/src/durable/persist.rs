@@ -11,7 +11,7 @@
 mod tests;
 
 use std::cmp::max;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::future::Future;
 use std::str::FromStr;
@@ -23,6 +23,7 @@ use differential_dataflow::lattice::Lattice;
 use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
+use mz_controller_types::ClusterId;
 use mz_ore::metrics::MetricsFutureExt;
 use mz_ore::now::EpochMillis;
 use mz_ore::retry::{Retry, RetryResult};
@@ -39,6 +40,7 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_persist_types::Opaque;
 use mz_proto::{RustType, TryFromProtoError};
 use mz_repr::{Diff, RelationDesc, ScalarType};
+use mz_sql::names::DatabaseId;
 use mz_storage_types::controller::TxnWalTablesImpl;
 use mz_storage_types::sources::SourceData;
 use sha2::Digest;
@@ -57,12 +59,15 @@ use crate::durable::objects::state_update::{
     IntoStateUpdateKindRaw, StateUpdate, StateUpdateKind, StateUpdateKindRaw,
     TryIntoStateUpdateKind,
 };
-use crate::durable::objects::{AuditLogKey, Snapshot, StorageUsageKey};
+use crate::durable::objects::{
+    AuditLogKey, ClusterReplica, Schema, Snapshot, SnapshotView, StorageUsageKey,
+};
 use crate::durable::transaction::TransactionBatch;
-use crate::durable::upgrade::upgrade;
+use crate::durable::upgrade::{upgrade, CATALOG_VERSION, MIN_CATALOG_VERSION};
 use crate::durable::{
-    initialize, BootstrapArgs, CatalogError, DurableCatalogError, DurableCatalogState, Epoch,
-    OpenableDurableCatalogState, ReadOnlyDurableCatalogState, Transaction,
+    initialize, BootstrapArgs, CatalogError, CollectionUpdate, DurableCatalogError,
+    DurableCatalogState, Epoch, OpenableDurableCatalogState, ReadOnlyDurableCatalogState,
+    Transaction, STORAGE_USAGE_PRUNE_CHUNK_SIZE,
 };
 
 /// New-type used to represent timestamps in persist.
@@ -79,6 +84,12 @@ const CATALOG_SHARD_NAME: &str = "catalog";
 /// Human readable catalog upgrade shard name.
 const UPGRADE_SHARD_NAME: &str = "catalog_upgrade";
 
+/// Number of the most recent catalog timestamps that are kept readable by `snapshot_as_of`.
+///
+/// Every commit advances the upper by one, so this is the number of most recent commits whose
+/// state can be inspected. The since of the catalog shard is held back by this much.
+const SNAPSHOT_AS_OF_RETENTION: u64 = 1000;
+
 /// Seed used to generate the persist shard ID for the catalog.
 const CATALOG_SEED: usize = 1;
 /// Seed used to generate the catalog upgrade shard ID.
@@ -272,7 +283,7 @@ impl<T: TryIntoStateUpdateKind, U: ApplyUpdate<T>> PersistHandle<T, U> {
     #[mz_ore::instrument]
     pub(crate) async fn compare_and_append<S: IntoStateUpdateKindRaw>(
         &mut self,
-        updates: Vec<(S, Diff)>,
+        updates: impl IntoIterator<Item = (S, Diff)>,
     ) -> Result<(), CatalogError> {
         let updates = updates.into_iter().map(|(kind, diff)| {
             let kind: StateUpdateKindRaw = kind.into();
@@ -294,8 +305,10 @@ impl<T: TryIntoStateUpdateKind, U: ApplyUpdate<T>> PersistHandle<T, U> {
                 ))
             })?;
 
-        // Lag the shard's upper by 1 to keep it readable.
-        let downgrade_to = Antichain::from_elem(next_upper.saturating_sub(1));
+        // Lag the shard's upper by 1 to keep it readable, and hold back the since further so that
+        // recent history remains readable by `snapshot_as_of`.
+        let downgrade_to =
+            Antichain::from_elem(next_upper.saturating_sub(1 + SNAPSHOT_AS_OF_RETENTION));
 
         // The since handle gives us the ability to fence out other writes using an opaque token.
         // (See the method documentation for details.)
@@ -502,6 +515,86 @@ impl<T: TryIntoStateUpdateKind, U: ApplyUpdate<T>> PersistHandle<T, U> {
 }
 
 impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
+    /// Validates `updates` against the current catalog snapshot and returns them in the order
+    /// they should be applied.
+    ///
+    /// Every update must insert a key that isn't present or retract the current value of a key.
+    /// Audit log and storage usage events aren't kept in the snapshot, so like in
+    /// [`Transaction`]s they are only checked for a valid diff.
+    ///
+    /// All of `updates` are collected in memory, along with the current value of every key they
+    /// touch, since retractions have to be checked before insertions.
+    fn validate_collection_updates(
+        &self,
+        updates: impl IntoIterator<Item = CollectionUpdate>,
+    ) -> Result<Vec<(StateUpdateKind, Diff)>, DurableCatalogError> {
+        fn snapshot_key(kind: &StateUpdateKind) -> Option<StateUpdateKind> {
+            match kind {
+                StateUpdateKind::AuditLog(_, _) | StateUpdateKind::StorageUsage(_, _) => None,
+                kind => kind.key(),
+            }
+        }
+
+        let mut updates: Vec<_> = updates
+            .into_iter()
+            .map(|CollectionUpdate { kind, diff }| (kind, diff))
+            .collect();
+        // Retractions must be applied before insertions, or we would reject replacing a value.
+        updates.sort_by(|(_, diff1), (_, diff2)| diff1.cmp(diff2));
+
+        let mut current: BTreeMap<StateUpdateKind, Option<&StateUpdateKind>> = BTreeMap::new();
+        for (kind, diff) in &updates {
+            if *diff != 1 && *diff != -1 {
+                return Err(DurableCatalogError::InvalidUpdate(format!(
+                    "invalid diff {diff} for {kind:?}"
+                )));
+            }
+            if kind.key().is_none() {
+                return Err(DurableCatalogError::InvalidUpdate(format!(
+                    "{kind:?} does not belong to a catalog collection"
+                )));
+            }
+            if let Some(key) = snapshot_key(kind) {
+                current.insert(key, None);
+            }
+        }
+        for (kind, _, _) in &self.snapshot {
+            if let Some(value) = snapshot_key(kind).and_then(|key| current.get_mut(&key)) {
+                *value = Some(kind);
+            }
+        }
+
+        for (kind, diff) in &updates {
+            let Some(value) = snapshot_key(kind).and_then(|key| current.get_mut(&key)) else {
+                continue;
+            };
+            if *diff == -1 {
+                if *value != Some(kind) {
+                    // If another transaction allocated ids since the caller read the id
+                    // allocator, then the ids handed out by the caller may have already been
+                    // handed out.
+                    if let StateUpdateKind::IdAllocator(key, _) = kind {
+                        return Err(DurableCatalogError::Conflict(format!(
+                            "id allocator {} was modified by a concurrent transaction",
+                            key.name
+                        )));
+                    }
+                    return Err(DurableCatalogError::InvalidUpdate(format!(
+                        "retraction of {kind:?} does not match the current value {value:?}"
+                    )));
+                }
+                *value = None;
+            } else {
+                if value.is_some() {
+                    return Err(DurableCatalogError::DuplicateKey);
+                }
+                *value = Some(kind);
+            }
+        }
+
+        Ok(updates)
+    }
+
     /// Execute and return the results of `f` on the current catalog snapshot.
     ///
     /// Will return an error if the catalog has been fenced out.
@@ -509,108 +602,62 @@ impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
         &mut self,
         f: impl FnOnce(Snapshot) -> Result<T, CatalogError>,
     ) -> Result<T, CatalogError> {
-        fn apply<K, V>(map: &mut BTreeMap<K, V>, key: &K, value: &V, diff: Diff)
-        where
-            K: Ord + Clone,
-            V: Ord + Clone + Debug,
-        {
-            let key = key.clone();
-            let value = value.clone();
-            if diff == 1 {
-                let prev = map.insert(key, value);
-                assert_eq!(
-                    prev, None,
-                    "values must be explicitly retracted before inserting a new value"
-                );
-            } else if diff == -1 {
-                let prev = map.remove(&key);
-                assert_eq!(
-                    prev,
-                    Some(value),
-                    "retraction does not match existing value"
-                );
-            }
-        }
+        self.with_trace(|trace| f(snapshot_from_trace(trace))).await
+    }
 
+    /// Execute and return the results of `f` on a view of the current catalog snapshot that only
+    /// contains the entries for which `filter` returns true.
+    ///
+    /// This avoids copying the entire snapshot when `f` only reads a small part of it.
+    ///
+    /// Will return an error if the catalog has been fenced out.
+    async fn with_filtered_snapshot<T>(
+        &mut self,
+        filter: impl Fn(&StateUpdateKind) -> bool,
+        f: impl FnOnce(SnapshotView<'_>) -> Result<T, CatalogError>,
+    ) -> Result<T, CatalogError> {
         self.with_trace(|trace| {
-            let mut snapshot = Snapshot::empty();
-            for (kind, ts, diff) in trace {
-                let diff = *diff;
-                if diff != 1 && diff != -1 {
-                    panic!("invalid update in consolidated trace: ({kind:?}, {ts:?}, {diff:?})");
-                }
-
-                match kind {
-                    StateUpdateKind::AuditLog(_key, ()) => {
-                        // Ignore for snapshots.
-                    }
-                    StateUpdateKind::Cluster(key, value) => {
-                        apply(&mut snapshot.clusters, key, value, diff);
-                    }
-                    StateUpdateKind::ClusterReplica(key, value) => {
-                        apply(&mut snapshot.cluster_replicas, key, value, diff);
-                    }
-                    StateUpdateKind::Comment(key, value) => {
-                        apply(&mut snapshot.comments, key, value, diff);
-                    }
-                    StateUpdateKind::Config(key, value) => {
-                        apply(&mut snapshot.configs, key, value, diff);
-                    }
-                    StateUpdateKind::Database(key, value) => {
-                        apply(&mut snapshot.databases, key, value, diff);
-                    }
-                    StateUpdateKind::DefaultPrivilege(key, value) => {
-                        apply(&mut snapshot.default_privileges, key, value, diff);
-                    }
-                    StateUpdateKind::Epoch(_epoch) => {
-                        // Ignore for snapshots.
-                    }
-                    StateUpdateKind::IdAllocator(key, value) => {
-                        apply(&mut snapshot.id_allocator, key, value, diff);
-                    }
-                    StateUpdateKind::IntrospectionSourceIndex(key, value) => {
-                        apply(&mut snapshot.introspection_sources, key, value, diff);
-                    }
-                    StateUpdateKind::Item(key, value) => {
-                        apply(&mut snapshot.items, key, value, diff);
-                    }
-                    StateUpdateKind::Role(key, value) => {
-                        apply(&mut snapshot.roles, key, value, diff);
-                    }
-                    StateUpdateKind::Schema(key, value) => {
-                        apply(&mut snapshot.schemas, key, value, diff);
-                    }
-                    StateUpdateKind::Setting(key, value) => {
-                        apply(&mut snapshot.settings, key, value, diff);
-                    }
-                    StateUpdateKind::StorageUsage(_key, ()) => {
-                        // Ignore for snapshots.
-                    }
-                    StateUpdateKind::SystemConfiguration(key, value) => {
-                        apply(&mut snapshot.system_configurations, key, value, diff);
-                    }
-                    StateUpdateKind::SystemObjectMapping(key, value) => {
-                        apply(&mut snapshot.system_object_mappings, key, value, diff);
-                    }
-                    StateUpdateKind::SystemPrivilege(key, value) => {
-                        apply(&mut snapshot.system_privileges, key, value, diff);
-                    }
-                    StateUpdateKind::StorageCollectionMetadata(key, value) => {
-                        apply(&mut snapshot.storage_collection_metadata, key, value, diff);
-                    }
-                    StateUpdateKind::UnfinalizedShard(key, ()) => {
-                        apply(&mut snapshot.unfinalized_shards, key, &(), diff);
-                    }
-                    StateUpdateKind::TxnWalShard((), value) => {
-                        apply(&mut snapshot.txn_wal_shard, &(), value, diff);
-                    }
-                }
-            }
-            f(snapshot)
+            let trace: Vec<_> = trace
+                .iter()
+                .filter(|(kind, _, _)| filter(kind))
+                .cloned()
+                .collect();
+            f(SnapshotView::new(&snapshot_from_trace(&trace)))
         })
         .await
     }
 
+    /// Returns the catalog snapshot as of the historical timestamp `ts`, read directly from
+    /// persist.
+    ///
+    /// Returns an error if `ts` is before the since of the catalog shard or not before its upper.
+    /// The since is held back by [`SNAPSHOT_AS_OF_RETENTION`], so the most recent
+    /// [`SNAPSHOT_AS_OF_RETENTION`] timestamps before the upper are always readable.
+    #[mz_ore::instrument(level = "debug")]
+    async fn persist_snapshot_as_of(&mut self, ts: Timestamp) -> Result<Snapshot, CatalogError> {
+        let upper = self.current_upper().await;
+        let mut read_handle = self.read_handle().await;
+        let since = read_handle.since().clone();
+        if !since.less_equal(&ts) || ts >= upper {
+            read_handle.expire().await;
+            return Err(DurableCatalogError::InvalidReadTimestamp {
+                as_of: ts,
+                since: since.into_option().unwrap_or(Timestamp::MAX),
+                upper,
+            }
+            .into());
+        }
+        let trace: Vec<_> = snapshot_binary(&mut read_handle, ts, &self.metrics)
+            .await
+            .map(|update| {
+                let update: StateUpdate = update.try_into().expect("kind decoding error");
+                (update.kind, update.ts, update.diff)
+            })
+            .collect();
+        read_handle.expire().await;
+        Ok(snapshot_from_trace(&trace))
+    }
+
     /// Generates an iterator of [`StateUpdate`] that contain all updates to the catalog
     /// state.
     ///
@@ -631,12 +678,118 @@ impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
     }
 }
 
+/// Converts a consolidated trace of catalog updates into a [`Snapshot`].
+fn snapshot_from_trace(trace: &[(StateUpdateKind, Timestamp, Diff)]) -> Snapshot {
+    fn apply<K, V>(map: &mut BTreeMap<K, V>, key: &K, value: &V, diff: Diff)
+    where
+        K: Ord + Clone,
+        V: Ord + Clone + Debug,
+    {
+        let key = key.clone();
+        let value = value.clone();
+        if diff == 1 {
+            let prev = map.insert(key, value);
+            assert_eq!(
+                prev, None,
+                "values must be explicitly retracted before inserting a new value"
+            );
+        } else if diff == -1 {
+            let prev = map.remove(&key);
+            assert_eq!(
+                prev,
+                Some(value),
+                "retraction does not match existing value"
+            );
+        }
+    }
+
+    let mut snapshot = Snapshot::empty();
+    for (kind, ts, diff) in trace {
+        let diff = *diff;
+        if diff != 1 && diff != -1 {
+            panic!("invalid update in consolidated trace: ({kind:?}, {ts:?}, {diff:?})");
+        }
+
+        match kind {
+            StateUpdateKind::AuditLog(_key, ()) => {
+                // Ignore for snapshots.
+            }
+            StateUpdateKind::Cluster(key, value) => {
+                apply(&mut snapshot.clusters, key, value, diff);
+            }
+            StateUpdateKind::ClusterReplica(key, value) => {
+                apply(&mut snapshot.cluster_replicas, key, value, diff);
+            }
+            StateUpdateKind::Comment(key, value) => {
+                apply(&mut snapshot.comments, key, value, diff);
+            }
+            StateUpdateKind::Config(key, value) => {
+                apply(&mut snapshot.configs, key, value, diff);
+            }
+            StateUpdateKind::Database(key, value) => {
+                apply(&mut snapshot.databases, key, value, diff);
+            }
+            StateUpdateKind::DefaultPrivilege(key, value) => {
+                apply(&mut snapshot.default_privileges, key, value, diff);
+            }
+            StateUpdateKind::Epoch(_epoch) => {
+                // Ignore for snapshots.
+            }
+            StateUpdateKind::IdAllocator(key, value) => {
+                apply(&mut snapshot.id_allocator, key, value, diff);
+            }
+            StateUpdateKind::IntrospectionSourceIndex(key, value) => {
+                apply(&mut snapshot.introspection_sources, key, value, diff);
+            }
+            StateUpdateKind::Item(key, value) => {
+                apply(&mut snapshot.items, key, value, diff);
+            }
+            StateUpdateKind::Role(key, value) => {
+                apply(&mut snapshot.roles, key, value, diff);
+            }
+            StateUpdateKind::Schema(key, value) => {
+                apply(&mut snapshot.schemas, key, value, diff);
+            }
+            StateUpdateKind::Setting(key, value) => {
+                apply(&mut snapshot.settings, key, value, diff);
+            }
+            StateUpdateKind::StorageUsage(_key, ()) => {
+                // Ignore for snapshots.
+            }
+            StateUpdateKind::SystemConfiguration(key, value) => {
+                apply(&mut snapshot.system_configurations, key, value, diff);
+            }
+            StateUpdateKind::SystemObjectMapping(key, value) => {
+                apply(&mut snapshot.system_object_mappings, key, value, diff);
+            }
+            StateUpdateKind::SystemPrivilege(key, value) => {
+                apply(&mut snapshot.system_privileges, key, value, diff);
+            }
+            StateUpdateKind::StorageCollectionMetadata(key, value) => {
+                apply(&mut snapshot.storage_collection_metadata, key, value, diff);
+            }
+            StateUpdateKind::UnfinalizedShard(key, ()) => {
+                apply(&mut snapshot.unfinalized_shards, key, &(), diff);
+            }
+            StateUpdateKind::TxnWalShard((), value) => {
+                apply(&mut snapshot.txn_wal_shard, &(), value, diff);
+            }
+        }
+    }
+    snapshot
+}
+
 #[derive(Debug)]
 pub(crate) struct UnopenedCatalogStateInner {
     /// The organization ID of the environment.
     organization_id: Uuid,
     /// A cache of the config collection of the catalog.
     configs: BTreeMap<String, u64>,
+    /// The deployment generation, cached after it's first read from the durable store, along with
+    /// the upper that it was read at.
+    deploy_generation: Option<(u64, Timestamp)>,
+    /// The deployment generation to open the catalog with, if one isn't provided when opening.
+    open_deploy_generation: Option<u64>,
 }
 
 impl UnopenedCatalogStateInner {
@@ -644,6 +797,8 @@ impl UnopenedCatalogStateInner {
         UnopenedCatalogStateInner {
             organization_id,
             configs: BTreeMap::new(),
+            deploy_generation: None,
+            open_deploy_generation: None,
         }
     }
 }
@@ -860,6 +1015,9 @@ impl UnopenedPersistCatalogState {
         );
         self.epoch = current_epoch;
         if matches!(mode, Mode::Writable) {
+            // The epoch bump is a compare-and-append against the upper we just synced to, so if
+            // multiple catalogs race to open, exactly one of them can write the next epoch. The
+            // others observe an upper mismatch and are fenced before they ever commit anything.
             self.compare_and_append(fence_updates).await?;
         }
 
@@ -976,25 +1134,61 @@ impl UnopenedPersistCatalogState {
 
 #[async_trait]
 impl OpenableDurableCatalogState for UnopenedPersistCatalogState {
+    fn with_deploy_generation(
+        mut self: Box<Self>,
+        deploy_generation: u64,
+    ) -> Box<dyn OpenableDurableCatalogState> {
+        self.update_applier.open_deploy_generation = Some(deploy_generation);
+        self
+    }
+
     #[mz_ore::instrument]
     async fn open_savepoint(
         mut self: Box<Self>,
         initial_ts: EpochMillis,
         bootstrap_args: &BootstrapArgs,
-        deploy_generation: u64,
+        deploy_generation: Option<u64>,
         epoch_lower_bound: Option<Epoch>,
     ) -> Result<Box<dyn DurableCatalogState>, CatalogError> {
+        let deploy_generation = deploy_generation.or(self.update_applier.open_deploy_generation);
         self.open_inner(
             Mode::Savepoint,
             initial_ts,
             bootstrap_args,
-            Some(deploy_generation),
+            deploy_generation,
             epoch_lower_bound,
         )
         .boxed()
         .await
     }
 
+    #[mz_ore::instrument]
+    async fn would_open_read_only_succeed(&mut self) -> Result<(), CatalogError> {
+        let mode = Mode::Readonly;
+        self.sync_to_current_upper().await?;
+        self.epoch.validate()?;
+        if !self.is_initialized_inner() {
+            return Err(CatalogError::Durable(DurableCatalogError::NotWritable(
+                format!("catalog tables do not exist; will not create in {mode:?} mode"),
+            )));
+        }
+        let version = self
+            .get_user_version()
+            .await?
+            .expect("initialized catalog must have a version");
+        // Mirror `upgrade`, which only rejects versions that are too old to migrate from.
+        if version < MIN_CATALOG_VERSION {
+            return Err(CatalogError::Durable(
+                DurableCatalogError::IncompatibleDataVersion {
+                    found_version: version,
+                    min_catalog_version: MIN_CATALOG_VERSION,
+                    catalog_version: CATALOG_VERSION,
+                },
+            ));
+        }
+        Ok(())
+    }
+
     #[mz_ore::instrument]
     async fn open_read_only(
         mut self: Box<Self>,
@@ -1010,14 +1204,15 @@ impl OpenableDurableCatalogState for UnopenedPersistCatalogState {
         mut self: Box<Self>,
         initial_ts: EpochMillis,
         bootstrap_args: &BootstrapArgs,
-        deploy_generation: u64,
+        deploy_generation: Option<u64>,
         epoch_lower_bound: Option<Epoch>,
     ) -> Result<Box<dyn DurableCatalogState>, CatalogError> {
+        let deploy_generation = deploy_generation.or(self.update_applier.open_deploy_generation);
         self.open_inner(
             Mode::Writable,
             initial_ts,
             bootstrap_args,
-            Some(deploy_generation),
+            deploy_generation,
             epoch_lower_bound,
         )
         .boxed()
@@ -1045,9 +1240,26 @@ impl OpenableDurableCatalogState for UnopenedPersistCatalogState {
 
     #[mz_ore::instrument]
     async fn get_deployment_generation(&mut self) -> Result<u64, CatalogError> {
-        self.get_current_config(DEPLOY_GENERATION)
+        // The cached value is only valid while nothing has been written to the catalog since it
+        // was read. Fencing this instance out requires writing a new epoch, so any write
+        // invalidates the cache and the fence is observed while re-reading.
+        if let Some((deploy_generation, upper)) = self.update_applier.deploy_generation {
+            self.epoch.validate()?;
+            if self.current_upper().await == upper {
+                return Ok(deploy_generation);
+            }
+        }
+        self.get_deployment_generation_uncached().await
+    }
+
+    #[mz_ore::instrument]
+    async fn get_deployment_generation_uncached(&mut self) -> Result<u64, CatalogError> {
+        let deploy_generation = self
+            .get_current_config(DEPLOY_GENERATION)
             .await?
-            .ok_or(CatalogError::Durable(DurableCatalogError::Uninitialized))
+            .ok_or(CatalogError::Durable(DurableCatalogError::Uninitialized))?;
+        self.update_applier.deploy_generation = Some((deploy_generation, self.upper));
+        Ok(deploy_generation)
     }
 
     #[mz_ore::instrument(level = "debug")]
@@ -1255,37 +1467,60 @@ impl ReadOnlyDurableCatalogState for PersistCatalogState {
 
     #[mz_ore::instrument(level = "debug")]
     async fn get_next_id(&mut self, id_type: &str) -> Result<u64, CatalogError> {
-        self.with_trace(|trace| {
-            Ok(trace
-                .into_iter()
-                .rev()
-                .filter_map(|(kind, _, _)| match kind {
-                    StateUpdateKind::IdAllocator(key, value) if key.name == id_type => {
-                        Some(value.next_id)
-                    }
-                    _ => None,
-                })
-                .next()
-                .expect("must exist"))
-        })
+        self.with_filtered_snapshot(
+            |kind| matches!(kind, StateUpdateKind::IdAllocator(key, _) if key.name == id_type),
+            |view| Ok(view.next_id(id_type).expect("must exist")),
+        )
+        .await
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn get_system_configuration(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<String>, CatalogError> {
+        self.with_filtered_snapshot(
+            |kind| matches!(kind, StateUpdateKind::SystemConfiguration(key, _) if key.name == name),
+            |view| Ok(view.system_configuration(name)),
+        )
+        .await
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn get_schemas_in_database(
+        &mut self,
+        database_id: DatabaseId,
+    ) -> Result<Vec<Schema>, CatalogError> {
+        self.with_filtered_snapshot(
+            |kind| matches!(kind, StateUpdateKind::Schema(_, _)),
+            |view| Ok(view.schemas_in_database(database_id)?),
+        )
+        .await
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn get_cluster_replicas_for(
+        &mut self,
+        cluster_id: ClusterId,
+    ) -> Result<Vec<ClusterReplica>, CatalogError> {
+        let proto_cluster_id = Some(cluster_id.into_proto());
+        self.with_filtered_snapshot(
+            |kind| {
+                matches!(kind, StateUpdateKind::ClusterReplica(_, value)
+                    if value.cluster_id == proto_cluster_id)
+            },
+            |view| Ok(view.cluster_replicas_for(cluster_id)?),
+        )
         .await
     }
 
     #[mz_ore::instrument(level = "debug")]
     async fn get_txn_wal_tables(&mut self) -> Result<Option<TxnWalTablesImpl>, CatalogError> {
         let value = self
-            .with_trace(|trace| {
-                Ok(trace
-                    .into_iter()
-                    .rev()
-                    .filter_map(|(kind, _, _)| match kind {
-                        StateUpdateKind::Config(key, value) if key.key == TXN_WAL_TABLES => {
-                            Some(value.value)
-                        }
-                        _ => None,
-                    })
-                    .next())
-            })
+            .with_filtered_snapshot(
+                |kind| matches!(kind, StateUpdateKind::Config(key, _) if key.key == TXN_WAL_TABLES),
+                |view| Ok(view.config(TXN_WAL_TABLES)),
+            )
             .await?;
         value
             .map(TxnWalTablesImpl::try_from)
@@ -1300,6 +1535,19 @@ impl ReadOnlyDurableCatalogState for PersistCatalogState {
     async fn snapshot(&mut self) -> Result<Snapshot, CatalogError> {
         self.with_snapshot(Ok).await
     }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn snapshot_as_of(&mut self, ts: Timestamp) -> Result<Snapshot, CatalogError> {
+        // Changes committed in savepoint mode are never written to persist, so its history
+        // doesn't reflect them.
+        if matches!(self.update_applier.mode, Mode::Savepoint) {
+            return Err(DurableCatalogError::Unsupported {
+                operation: "snapshot_as_of",
+            }
+            .into());
+        }
+        self.persist_snapshot_as_of(ts).await
+    }
 }
 
 #[async_trait]
@@ -1336,14 +1584,17 @@ impl DurableCatalogState for PersistCatalogState {
 
             // If the current upper does not match the transaction's commit timestamp, then the
             // catalog must have changed since the transaction was started, making the transaction
-            // invalid. When/if we want a multi-writer catalog, this will likely have to change
-            // from an assert to a retry.
-            assert_eq!(
-                catalog.upper, txn_batch.commit_ts,
-                "only one transaction at a time is supported"
-            );
+            // invalid. In particular, any ids it allocated may have already been handed out.
+            // When/if we want a multi-writer catalog, this will likely have to change to a retry.
+            if catalog.upper != txn_batch.commit_ts {
+                return Err(DurableCatalogError::Conflict(format!(
+                    "transaction started at {} but the catalog has since advanced to {}",
+                    txn_batch.commit_ts, catalog.upper
+                ))
+                .into());
+            }
 
-            let updates = StateUpdate::from_txn_batch(txn_batch).collect();
+            let updates: Vec<_> = StateUpdate::from_txn_batch(txn_batch).collect();
             debug!("committing updates: {updates:?}");
 
             if matches!(catalog.update_applier.mode, Mode::Writable) {
@@ -1367,6 +1618,54 @@ impl DurableCatalogState for PersistCatalogState {
             .await
     }
 
+    #[mz_ore::instrument(level = "debug")]
+    async fn commit_transaction_streaming(
+        &mut self,
+        updates: Box<dyn Iterator<Item = CollectionUpdate> + Send + '_>,
+    ) -> Result<(), CatalogError> {
+        async fn commit_transaction_streaming_inner(
+            catalog: &mut PersistCatalogState,
+            updates: Box<dyn Iterator<Item = CollectionUpdate> + Send + '_>,
+        ) -> Result<(), CatalogError> {
+            let mut updates = updates.peekable();
+            // Like `commit_transaction`, committing no updates is allowed even in read-only mode.
+            if updates.peek().is_none() {
+                return Ok(());
+            }
+            if catalog.is_read_only() {
+                return Err(DurableCatalogError::NotWritable(
+                    "cannot commit a transaction in a read-only catalog".to_string(),
+                )
+                .into());
+            }
+
+            // There is no transaction to validate the updates, so validate them against the
+            // current contents of the catalog instead.
+            catalog.sync_to_current_upper().await?;
+            let updates = catalog.validate_collection_updates(updates)?;
+            debug!("committing updates: {updates:?}");
+
+            if matches!(catalog.update_applier.mode, Mode::Writable) {
+                catalog.compare_and_append(updates).await?;
+            } else if matches!(catalog.update_applier.mode, Mode::Savepoint) {
+                let ts = catalog.upper;
+                let updates =
+                    updates
+                        .into_iter()
+                        .map(|(kind, diff)| StateUpdate { kind, ts, diff });
+                catalog.apply_updates(updates)?;
+            }
+
+            Ok(())
+        }
+        self.metrics.transaction_commits.inc();
+        let counter = self.metrics.transaction_commit_latency_seconds.clone();
+        commit_transaction_streaming_inner(self, updates)
+            .wall_time()
+            .inc_by(counter)
+            .await
+    }
+
     #[mz_ore::instrument(level = "debug")]
     async fn confirm_leadership(&mut self) -> Result<(), CatalogError> {
         // Read only catalog does not care about leadership.
@@ -1377,11 +1676,72 @@ impl DurableCatalogState for PersistCatalogState {
         Ok(())
     }
 
+    #[mz_ore::instrument(level = "debug")]
+    async fn sync(&mut self) -> Result<(), CatalogError> {
+        if !matches!(self.update_applier.mode, Mode::Writable) {
+            return Ok(());
+        }
+        // `compare_and_append` only returns once a write is durable in persist, so every
+        // committed transaction is already durable. Syncing to the current upper confirms that
+        // the shard's upper includes all of our commits, so any new reader will observe them, and
+        // that we haven't been fenced out in the meantime.
+        let upper = self.upper;
+        self.sync_to_current_upper().await?;
+        assert!(
+            self.upper >= upper,
+            "catalog upper regressed from {upper:?} to {:?}",
+            self.upper
+        );
+        Ok(())
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn await_fenced(&mut self) -> DurableCatalogError {
+        // Keep applying updates as they arrive until one of them contains a newer epoch.
+        loop {
+            let target_upper = self.upper.step_forward();
+            if let Err(e) = self.sync(target_upper).await {
+                return e;
+            }
+        }
+    }
+
     // TODO(jkosh44) For most modifications we delegate to transactions to avoid duplicate code.
     // This is slightly inefficient because we have to clone an entire snapshot when we usually
     // only need one part of the snapshot. A Potential mitigation against these performance hits is
     // to utilize `CoW`s in `Transaction`s to avoid cloning unnecessary state.
 
+    #[mz_ore::instrument]
+    async fn delete_audit_logs(&mut self, ids: &[u64]) -> Result<usize, CatalogError> {
+        self.sync_to_current_upper().await?;
+        let ids: BTreeSet<_> = ids.iter().copied().collect();
+        // Read the audit logs directly from persist, so that we don't consume the startup cache.
+        let deleted: Vec<_> = self
+            .persist_snapshot()
+            .await
+            .filter_map(
+                |StateUpdate {
+                     kind,
+                     ts: _,
+                     diff: _,
+                 }| match kind {
+                    StateUpdateKind::AuditLog(key, ()) => Some(key),
+                    _ => None,
+                },
+            )
+            .map(RustType::from_proto)
+            .map_ok(|key: AuditLogKey| key.event)
+            .filter_ok(|event| ids.contains(&event.sortable_id()))
+            .collect::<Result<_, _>>()?;
+        let count = deleted.len();
+
+        let mut txn = self.transaction().await?;
+        txn.remove_audit_log_events(deleted);
+        txn.commit().await?;
+
+        Ok(count)
+    }
+
     #[mz_ore::instrument]
     async fn get_and_prune_storage_usage(
         &mut self,
@@ -1436,9 +1796,22 @@ impl DurableCatalogState for PersistCatalogState {
         events.sort_by(|event1, event2| event1.sortable_id().cmp(&event2.sortable_id()));
 
         if !self.is_read_only() {
-            let mut txn = self.transaction().await?;
-            txn.remove_storage_usage_events(expired);
-            txn.commit().await?;
+            // Delete the expired events in bounded chunks, so that a large backlog of events
+            // doesn't result in a single oversized transaction. At least one transaction is always
+            // committed, which also confirms that we're still the leader.
+            let mut expired = expired.into_iter().peekable();
+            loop {
+                let chunk: Vec<_> = expired
+                    .by_ref()
+                    .take(STORAGE_USAGE_PRUNE_CHUNK_SIZE)
+                    .collect();
+                let mut txn = self.transaction().await?;
+                txn.remove_storage_usage_events(chunk);
+                txn.commit().await?;
+                if expired.peek().is_none() {
+                    break;
+                }
+            }
         } else {
             self.confirm_leadership().await?;
         }
@@ -10,10 +10,16 @@
 use mz_ore::now::NOW_ZERO;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::PersistLocation;
+use mz_repr::role_id::RoleId;
+use mz_sql::names::DatabaseId;
 use uuid::Uuid;
 
 use crate::durable::persist::{fetch_catalog_upgrade_shard_version, shard_id, UPGRADE_SEED};
-use crate::durable::{test_bootstrap_args, test_persist_backed_catalog_state_with_version};
+use crate::durable::{
+    test_bootstrap_args, test_persist_backed_catalog_state,
+    test_persist_backed_catalog_state_with_version, CatalogError, DurableCatalogError,
+    USER_ITEM_ALLOC_KEY,
+};
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
@@ -44,7 +50,12 @@ async fn test_upgrade_shard() {
     .await
     .expect("failed to create persist catalog");
     let _persist_state = persist_openable_state
-        .open(NOW_ZERO(), &test_bootstrap_args(), deploy_generation, None)
+        .open(
+            NOW_ZERO(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
         .await
         .expect("failed to open persist catalog");
 
@@ -67,7 +78,12 @@ async fn test_upgrade_shard() {
     .await
     .expect("failed to create persist catalog");
     let _persist_state = persist_openable_state
-        .open_savepoint(NOW_ZERO(), &test_bootstrap_args(), deploy_generation, None)
+        .open_savepoint(
+            NOW_ZERO(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
         .await
         .expect("failed to open savepoint persist catalog");
 
@@ -95,3 +111,92 @@ async fn test_upgrade_shard() {
         "opening a readonly catalog should not increment the upgrade version"
     );
 }
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_concurrent_id_allocation_conflict() {
+    let persist_client = PersistClientCache::new_no_metrics()
+        .open(PersistLocation::new_in_mem())
+        .await
+        .expect("in-mem location is valid");
+    let mut state = test_persist_backed_catalog_state(persist_client, Uuid::new_v4())
+        .await
+        .open(NOW_ZERO(), &test_bootstrap_args(), None, None)
+        .await
+        .expect("failed to open persist catalog");
+    let next_id = state
+        .get_next_id(USER_ITEM_ALLOC_KEY)
+        .await
+        .expect("failed to get next id");
+
+    // Allocate an id, but hold on to the transaction instead of committing it.
+    let mut txn = state
+        .transaction()
+        .await
+        .expect("failed to start transaction");
+    let stale_id = txn
+        .get_and_increment_id(USER_ITEM_ALLOC_KEY.to_string())
+        .expect("failed to allocate id");
+    let (stale_batch, state) = txn.into_parts();
+
+    // Allocate and commit an id from the same high-water mark.
+    let mut txn = state
+        .transaction()
+        .await
+        .expect("failed to start transaction");
+    let id = txn
+        .get_and_increment_id(USER_ITEM_ALLOC_KEY.to_string())
+        .expect("failed to allocate id");
+    txn.commit().await.expect("failed to commit transaction");
+    assert_eq!(id, next_id);
+    assert_eq!(stale_id, id);
+
+    // Committing the stale allocation must conflict rather than hand out the same id twice.
+    let err = state
+        .commit_transaction(stale_batch)
+        .await
+        .expect_err("stale id allocation must conflict");
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Conflict(_))),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(
+        state
+            .get_next_id(USER_ITEM_ALLOC_KEY)
+            .await
+            .expect("failed to get next id"),
+        next_id + 1
+    );
+
+    // Any other transaction that was started before a concurrent commit must also conflict.
+    let mut txn = state
+        .transaction()
+        .await
+        .expect("failed to start transaction");
+    txn.insert_user_schema(DatabaseId::User(1), "stale", RoleId::User(1), Vec::new())
+        .expect("failed to insert schema");
+    let (stale_batch, state) = txn.into_parts();
+
+    let mut txn = state
+        .transaction()
+        .await
+        .expect("failed to start transaction");
+    txn.insert_user_schema(DatabaseId::User(1), "fresh", RoleId::User(1), Vec::new())
+        .expect("failed to insert schema");
+    txn.commit().await.expect("failed to commit transaction");
+
+    let err = state
+        .commit_transaction(stale_batch)
+        .await
+        .expect_err("stale transaction must conflict");
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Conflict(_))),
+        "unexpected error: {err:?}"
+    );
+    let schemas = state
+        .get_schemas_in_database(DatabaseId::User(1))
+        .await
+        .expect("failed to get schemas");
+    assert!(schemas.iter().any(|schema| schema.name == "fresh"));
+    assert!(!schemas.iter().any(|schema| schema.name == "stale"));
+}
@@ -9,6 +9,7 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use anyhow::anyhow;
 use derivative::Derivative;
@@ -40,9 +41,9 @@ use crate::durable::objects::serialization::proto;
 use crate::durable::objects::{
     AuditLogKey, Cluster, ClusterConfig, ClusterIntrospectionSourceIndexKey,
     ClusterIntrospectionSourceIndexValue, ClusterKey, ClusterReplica, ClusterReplicaKey,
-    ClusterReplicaValue, ClusterValue, CommentKey, CommentValue, Config, ConfigKey, ConfigValue,
-    Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
-    DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
+    ClusterReplicaValue, ClusterValue, Comment, CommentKey, CommentValue, Config, ConfigKey,
+    ConfigValue, Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
+    DefaultPrivilegesValue, DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
     IntrospectionSourceIndex, Item, ItemKey, ItemValue, ReplicaConfig, Role, RoleKey, RoleValue,
     Schema, SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey,
     SettingValue, StorageCollectionMetadataKey, StorageCollectionMetadataValue, StorageUsageKey,
@@ -67,6 +68,9 @@ pub struct Transaction<'a> {
     #[derivative(Debug = "ignore")]
     #[derivative(PartialEq = "ignore")]
     durable_catalog: &'a mut dyn DurableCatalogState,
+    /// A process-wide unique ID of this transaction, used to tie savepoints to it.
+    #[derivative(PartialEq = "ignore")]
+    id: u64,
     databases: TableTransaction<DatabaseKey, DatabaseValue>,
     schemas: TableTransaction<SchemaKey, SchemaValue>,
     items: TableTransaction<ItemKey, ItemValue>,
@@ -97,6 +101,9 @@ pub struct Transaction<'a> {
     op_id: Timestamp,
 }
 
+/// The ID of the next [`Transaction`] to be created.
+static NEXT_TRANSACTION_ID: AtomicU64 = AtomicU64::new(0);
+
 impl<'a> Transaction<'a> {
     pub fn new(
         durable_catalog: &'a mut dyn DurableCatalogState,
@@ -124,6 +131,7 @@ impl<'a> Transaction<'a> {
     ) -> Result<Transaction, CatalogError> {
         Ok(Transaction {
             durable_catalog,
+            id: NEXT_TRANSACTION_ID.fetch_add(1, Ordering::Relaxed),
             databases: TableTransaction::new(databases, |a: &DatabaseValue, b| a.name == b.name)?,
             schemas: TableTransaction::new(schemas, |a: &SchemaValue, b| {
                 a.database_id == b.database_id && a.name == b.name
@@ -168,6 +176,11 @@ impl<'a> Transaction<'a> {
         })
     }
 
+    /// Returns the timestamp that this transaction will be committed at.
+    pub fn commit_ts(&self) -> mz_repr::Timestamp {
+        self.commit_ts
+    }
+
     pub fn get_items(&self) -> impl Iterator<Item = Item> {
         self.items
             .items()
@@ -525,6 +538,55 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Inserts cluster replica `replica_id` into the transaction, or replaces it if it already
+    /// exists.
+    ///
+    /// Returns an error if `cluster_id` does not exist, if the replica already exists in a
+    /// different cluster, or if a different replica in the cluster is already named
+    /// `replica_name`.
+    pub fn upsert_cluster_replica(
+        &mut self,
+        replica_id: ReplicaId,
+        cluster_id: ClusterId,
+        replica_name: &str,
+        config: ReplicaConfig,
+        owner_id: RoleId,
+    ) -> Result<(), CatalogError> {
+        let Some(cluster) = self.clusters.get(&ClusterKey { id: cluster_id }) else {
+            return Err(SqlCatalogError::UnknownCluster(cluster_id.to_string()).into());
+        };
+        let key = ClusterReplicaKey { id: replica_id };
+        if let Some(prev) = self.cluster_replicas.get(&key) {
+            if prev.cluster_id != cluster_id {
+                return Err(DurableCatalogError::ReplicaClusterMismatch {
+                    replica_id,
+                    cluster_id: prev.cluster_id,
+                    requested_cluster_id: cluster_id,
+                }
+                .into());
+            }
+        }
+        self.cluster_replicas
+            .set(
+                key,
+                Some(ClusterReplicaValue {
+                    cluster_id,
+                    name: replica_name.into(),
+                    config,
+                    owner_id,
+                }),
+                self.op_id,
+            )
+            .map_err(|err| match err {
+                DurableCatalogError::UniquenessViolation => DurableCatalogError::DuplicateName {
+                    name: replica_name.to_string(),
+                    scope: format!("cluster '{}'", cluster.name),
+                },
+                err => err,
+            })?;
+        Ok(())
+    }
+
     /// Updates persisted information about persisted introspection source
     /// indexes.
     ///
@@ -1028,6 +1090,14 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Removes all audit log events in `events` from the transaction.
+    pub(crate) fn remove_audit_log_events(&mut self, events: Vec<VersionedEvent>) {
+        let events = events
+            .into_iter()
+            .map(|event| (AuditLogKey { event }, -1, self.op_id));
+        self.audit_log_updates.extend(events);
+    }
+
     /// Removes all storage usage events in `events` from the transaction.
     pub(crate) fn remove_storage_usage_events(&mut self, events: Vec<VersionedStorageUsage>) {
         let events = events
@@ -1639,20 +1709,31 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Removes every comment on any of `object_ids`, including comments on their sub-components,
+    /// and returns the removed comments.
     pub fn drop_comments(
         &mut self,
         object_ids: &BTreeSet<CommentObjectId>,
-    ) -> Result<Vec<(CommentObjectId, Option<usize>, String)>, CatalogError> {
+    ) -> Result<Vec<Comment>, CatalogError> {
         let deleted = self
             .comments
             .delete(|k, _v| object_ids.contains(&k.object_id), self.op_id);
         let deleted = deleted
             .into_iter()
-            .map(|(k, v)| (k.object_id, k.sub_component, v.comment))
+            .map(|(k, v)| DurableType::from_key_value(k, v))
             .collect();
         Ok(deleted)
     }
 
+    /// Removes every comment on `object_id`, including comments on its sub-components (e.g.
+    /// columns), and returns the removed comments.
+    pub fn delete_comments_for_object(
+        &mut self,
+        object_id: CommentObjectId,
+    ) -> Result<Vec<Comment>, CatalogError> {
+        self.drop_comments(&BTreeSet::from([object_id]))
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), CatalogError> {
         let key = ServerConfigurationKey {
@@ -1730,6 +1811,24 @@ impl<'a> Transaction<'a> {
             .map(|(k, v)| DurableType::from_key_value(k, v))
     }
 
+    pub fn get_comments(&self) -> impl Iterator<Item = Comment> {
+        self.comments
+            .items()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    /// Returns every comment on `object_id`, including comments on its sub-components.
+    pub fn get_comments_for_object(
+        &self,
+        object_id: CommentObjectId,
+    ) -> impl Iterator<Item = Comment> {
+        self.get_comments()
+            .filter(move |comment| comment.object_id == object_id)
+    }
+
+    /// Returns the id and OID of every introspection source index on `cluster_id`, keyed and
+    /// ordered by index name.
     pub fn get_introspection_source_indexes(
         &mut self,
         cluster_id: ClusterId,
@@ -1742,6 +1841,30 @@ impl<'a> Transaction<'a> {
             .collect()
     }
 
+    /// Returns the name of the introspection source index on `cluster_id` with the id `id`, or
+    /// `None` if the cluster has no such index.
+    pub fn get_introspection_source_index_name(
+        &self,
+        cluster_id: ClusterId,
+        id: GlobalId,
+    ) -> Result<Option<String>, CatalogError> {
+        // Introspection source indexes always have system ids.
+        let GlobalId::System(index_id) = id else {
+            return Ok(None);
+        };
+        let mut names = self
+            .introspection_sources
+            .items()
+            .into_iter()
+            .filter(|(k, v)| k.cluster_id == cluster_id && v.index_id == index_id)
+            .map(|(k, _v)| k.name);
+        let name = names.next();
+        if names.next().is_some() {
+            return Err(DurableCatalogError::UniquenessViolation.into());
+        }
+        Ok(name)
+    }
+
     pub fn get_catalog_content_version(&self) -> Option<String> {
         self.settings
             .get(&SettingKey {
@@ -2061,6 +2184,92 @@ impl<'a> Transaction<'a> {
         self.op_id += 1;
     }
 
+    /// Records the changes staged in this transaction so far, so that any changes staged after
+    /// this point can later be discarded with [`Self::rollback_to`].
+    pub fn savepoint(&self) -> SavepointToken {
+        SavepointToken {
+            transaction_id: self.id,
+            databases: self.databases.savepoint(),
+            schemas: self.schemas.savepoint(),
+            items: self.items.savepoint(),
+            comments: self.comments.savepoint(),
+            roles: self.roles.savepoint(),
+            clusters: self.clusters.savepoint(),
+            cluster_replicas: self.cluster_replicas.savepoint(),
+            introspection_sources: self.introspection_sources.savepoint(),
+            id_allocator: self.id_allocator.savepoint(),
+            configs: self.configs.savepoint(),
+            settings: self.settings.savepoint(),
+            system_gid_mapping: self.system_gid_mapping.savepoint(),
+            system_configurations: self.system_configurations.savepoint(),
+            default_privileges: self.default_privileges.savepoint(),
+            system_privileges: self.system_privileges.savepoint(),
+            storage_collection_metadata: self.storage_collection_metadata.savepoint(),
+            unfinalized_shards: self.unfinalized_shards.savepoint(),
+            txn_wal_shard: self.txn_wal_shard.savepoint(),
+            audit_log_updates: self.audit_log_updates.len(),
+            storage_usage_updates: self.storage_usage_updates.len(),
+        }
+    }
+
+    /// Discards all changes staged in this transaction after `token` was created by
+    /// [`Self::savepoint`]. Changes staged before `token` was created are kept.
+    ///
+    /// Returns an error, without discarding any changes, if `token` was created by a different
+    /// transaction.
+    pub fn rollback_to(&mut self, token: SavepointToken) -> Result<(), CatalogError> {
+        let SavepointToken {
+            transaction_id,
+            databases,
+            schemas,
+            items,
+            comments,
+            roles,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            system_gid_mapping,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            storage_collection_metadata,
+            unfinalized_shards,
+            txn_wal_shard,
+            audit_log_updates,
+            storage_usage_updates,
+        } = token;
+        if transaction_id != self.id {
+            return Err(DurableCatalogError::InvalidSavepoint.into());
+        }
+        self.databases.rollback_to(databases);
+        self.schemas.rollback_to(schemas);
+        self.items.rollback_to(items);
+        self.comments.rollback_to(comments);
+        self.roles.rollback_to(roles);
+        self.clusters.rollback_to(clusters);
+        self.cluster_replicas.rollback_to(cluster_replicas);
+        self.introspection_sources
+            .rollback_to(introspection_sources);
+        self.id_allocator.rollback_to(id_allocator);
+        self.configs.rollback_to(configs);
+        self.settings.rollback_to(settings);
+        self.system_gid_mapping.rollback_to(system_gid_mapping);
+        self.system_configurations
+            .rollback_to(system_configurations);
+        self.default_privileges.rollback_to(default_privileges);
+        self.system_privileges.rollback_to(system_privileges);
+        self.storage_collection_metadata
+            .rollback_to(storage_collection_metadata);
+        self.unfinalized_shards.rollback_to(unfinalized_shards);
+        self.txn_wal_shard.rollback_to(txn_wal_shard);
+        self.audit_log_updates.truncate(audit_log_updates);
+        self.storage_usage_updates.truncate(storage_usage_updates);
+        Ok(())
+    }
+
     /// Commits the storage transaction to durable storage. Any error returned indicates the catalog may be
     /// in an indeterminate state and needs to be fully re-read before proceeding. In general, this
     /// must be fatal to the calling process. We do not panic/halt inside this function itself so
@@ -2229,6 +2438,36 @@ impl StorageTxn<mz_repr::Timestamp> for Transaction<'_> {
     }
 }
 
+/// A point within a [`Transaction`] that staged changes can be rolled back to with
+/// [`Transaction::rollback_to`]. Created with [`Transaction::savepoint`].
+#[derive(Debug)]
+pub struct SavepointToken {
+    /// The ID of the transaction that created this savepoint.
+    transaction_id: u64,
+    databases: PendingUpdates<DatabaseKey, DatabaseValue>,
+    schemas: PendingUpdates<SchemaKey, SchemaValue>,
+    items: PendingUpdates<ItemKey, ItemValue>,
+    comments: PendingUpdates<CommentKey, CommentValue>,
+    roles: PendingUpdates<RoleKey, RoleValue>,
+    clusters: PendingUpdates<ClusterKey, ClusterValue>,
+    cluster_replicas: PendingUpdates<ClusterReplicaKey, ClusterReplicaValue>,
+    introspection_sources:
+        PendingUpdates<ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue>,
+    id_allocator: PendingUpdates<IdAllocKey, IdAllocValue>,
+    configs: PendingUpdates<ConfigKey, ConfigValue>,
+    settings: PendingUpdates<SettingKey, SettingValue>,
+    system_gid_mapping: PendingUpdates<GidMappingKey, GidMappingValue>,
+    system_configurations: PendingUpdates<ServerConfigurationKey, ServerConfigurationValue>,
+    default_privileges: PendingUpdates<DefaultPrivilegesKey, DefaultPrivilegesValue>,
+    system_privileges: PendingUpdates<SystemPrivilegesKey, SystemPrivilegesValue>,
+    storage_collection_metadata:
+        PendingUpdates<StorageCollectionMetadataKey, StorageCollectionMetadataValue>,
+    unfinalized_shards: PendingUpdates<UnfinalizedShardKey, ()>,
+    txn_wal_shard: PendingUpdates<(), TxnWalShardValue>,
+    audit_log_updates: usize,
+    storage_usage_updates: usize,
+}
+
 /// Describes a set of changes to apply as the result of a catalog transaction.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct TransactionBatch {
@@ -2331,6 +2570,9 @@ struct TransactionUpdate<V> {
     diff: Diff,
 }
 
+/// The pending changes of a [`TableTransaction`], keyed by the key they update.
+type PendingUpdates<K, V> = BTreeMap<K, Vec<TransactionUpdate<V>>>;
+
 /// TableTransaction emulates some features of a typical SQL transaction over
 /// table for a Collection.
 ///
@@ -2405,6 +2647,18 @@ where
             .collect()
     }
 
+    /// Returns a copy of the pending changes, which can later be restored with
+    /// [`Self::rollback_to`].
+    fn savepoint(&self) -> PendingUpdates<K, V> {
+        self.pending.clone()
+    }
+
+    /// Replaces the pending changes with `savepoint`, discarding any changes made since
+    /// `savepoint` was taken.
+    fn rollback_to(&mut self, savepoint: PendingUpdates<K, V>) {
+        self.pending = savepoint;
+    }
+
     fn verify(&self) -> Result<(), DurableCatalogError> {
         // Compare each value to each other value and ensure they are unique.
         let items = self.items();
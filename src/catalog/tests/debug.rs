@@ -136,7 +136,12 @@ async fn test_debug<'a>(
     // Use `NOW_ZERO` for consistent timestamps in the snapshots.
     let deploy_generation = 0;
     let _ = openable_state1
-        .open(NOW_ZERO(), &test_bootstrap_args(), deploy_generation, None)
+        .open(
+            NOW_ZERO(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
         .await
         .unwrap();
 
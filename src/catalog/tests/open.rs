@@ -9,15 +9,17 @@
 
 use futures::future::BoxFuture;
 use futures::FutureExt;
+use mz_catalog::durable::debug::ConfigCollection;
 use mz_catalog::durable::initialize::USER_VERSION_KEY;
 use mz_catalog::durable::objects::serialization::proto;
 use mz_catalog::durable::objects::{DurableType, Snapshot};
 use mz_catalog::durable::{
-    test_bootstrap_args, test_persist_backed_catalog_state,
+    persist_backed_catalog_state, test_bootstrap_args, test_persist_backed_catalog_state,
     test_persist_backed_catalog_state_with_version, CatalogError, Database, DurableCatalogError,
-    Epoch, OpenableDurableCatalogState, Schema, CATALOG_VERSION,
+    Epoch, Metrics, OpenableDurableCatalogState, Schema, CATALOG_VERSION,
 };
 use mz_ore::cast::usize_to_u64;
+use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{NOW_ZERO, SYSTEM_TIME};
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::{PersistClient, PersistLocation};
@@ -26,6 +28,7 @@ use mz_repr::role_id::RoleId;
 use mz_sql::catalog::{RoleAttributes, RoleMembership, RoleVars};
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// A new type for [`Snapshot`] that excludes the user_version from the debug output. The
@@ -118,7 +121,7 @@ async fn test_is_initialized(
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -163,7 +166,7 @@ async fn test_get_deployment_generation(
     );
 
     let state = openable_state1
-        .open(SYSTEM_TIME(), &test_bootstrap_args(), 42, None)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), Some(42), None)
         .await
         .unwrap();
     state.expire().await;
@@ -182,6 +185,107 @@ async fn test_get_deployment_generation(
     );
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_get_deployment_generation_cached() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let state = openable_state
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), Some(42), None)
+        .await
+        .unwrap();
+    state.expire().await;
+
+    let metrics = Arc::new(Metrics::new(&MetricsRegistry::new()));
+    let mut openable_state = persist_backed_catalog_state(
+        persist_client,
+        organization_id,
+        semver::Version::new(0, 0, 0),
+        Arc::clone(&metrics),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        openable_state.get_deployment_generation().await.unwrap(),
+        42
+    );
+    let syncs = metrics.syncs.get();
+    assert_eq!(
+        openable_state.get_deployment_generation().await.unwrap(),
+        42
+    );
+    assert_eq!(
+        metrics.syncs.get(),
+        syncs,
+        "cached deployment generation should not read from the durable store"
+    );
+
+    assert_eq!(
+        openable_state
+            .get_deployment_generation_uncached()
+            .await
+            .unwrap(),
+        42
+    );
+    assert!(metrics.syncs.get() > syncs);
+
+    openable_state.expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_with_deploy_generation() {
+    let persist_client = PersistClient::new_for_tests().await;
+
+    // Supplying the deploy generation via the builder is equivalent to passing it to `open`.
+    let builder_organization_id = Uuid::new_v4();
+    let state = test_persist_backed_catalog_state(persist_client.clone(), builder_organization_id)
+        .await
+        .with_deploy_generation(42)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None, None)
+        .await
+        .unwrap();
+    state.expire().await;
+
+    let positional_organization_id = Uuid::new_v4();
+    let state =
+        test_persist_backed_catalog_state(persist_client.clone(), positional_organization_id)
+            .await
+            .open(SYSTEM_TIME(), &test_bootstrap_args(), Some(42), None)
+            .await
+            .unwrap();
+    state.expire().await;
+
+    for organization_id in [builder_organization_id, positional_organization_id] {
+        let mut openable_state =
+            test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+        assert_eq!(
+            openable_state.get_deployment_generation().await.unwrap(),
+            42
+        );
+        openable_state.expire().await;
+    }
+
+    // A deploy generation passed to `open` takes precedence over the builder.
+    let state = test_persist_backed_catalog_state(persist_client.clone(), builder_organization_id)
+        .await
+        .with_deploy_generation(42)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), Some(43), None)
+        .await
+        .unwrap();
+    state.expire().await;
+    let mut openable_state =
+        test_persist_backed_catalog_state(persist_client, builder_organization_id).await;
+    assert_eq!(
+        openable_state.get_deployment_generation().await.unwrap(),
+        43
+    );
+    openable_state.expire().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_open_savepoint() {
@@ -218,7 +322,7 @@ async fn test_open_savepoint(
             .open_savepoint(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -235,7 +339,7 @@ async fn test_open_savepoint(
             .open(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -251,7 +355,7 @@ async fn test_open_savepoint(
             .open_savepoint(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -336,7 +440,7 @@ async fn test_open_savepoint(
             .open(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -395,7 +499,7 @@ async fn test_open_read_only(
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -439,6 +543,121 @@ async fn test_open_read_only(
     Box::new(state).expire().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_would_open_read_only_succeed() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    test_would_open_read_only_succeed(move || {
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).boxed()
+    })
+    .await;
+}
+
+async fn test_would_open_read_only_succeed<'a>(
+    openable_state_generator: impl Fn() -> BoxFuture<'a, Box<dyn OpenableDurableCatalogState>>,
+) {
+    let deploy_generation = 0;
+
+    fn not_writable_message(err: CatalogError) -> String {
+        match err {
+            CatalogError::Durable(DurableCatalogError::NotWritable(msg)) => msg,
+            err => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    // An uninitialized catalog can't be opened in read only mode.
+    let mut openable_state = openable_state_generator().await;
+    let err = openable_state
+        .would_open_read_only_succeed()
+        .await
+        .unwrap_err();
+    let uninitialized_msg = not_writable_message(err);
+    assert!(
+        uninitialized_msg.contains("catalog tables do not exist"),
+        "{uninitialized_msg}"
+    );
+    let err = openable_state
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap_err();
+    assert_eq!(not_writable_message(err), uninitialized_msg);
+
+    // Initialize the catalog.
+    let state = openable_state_generator()
+        .await
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    Box::new(state).expire().await;
+
+    let mut openable_state = openable_state_generator().await;
+    openable_state.would_open_read_only_succeed().await.unwrap();
+    let read_only_state = openable_state
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap();
+    Box::new(read_only_state).expire().await;
+
+    // A catalog that requires migrations can still be opened in read only mode, because the
+    // migrations are only applied in memory.
+    let user_version_key = proto::ConfigKey {
+        key: USER_VERSION_KEY.to_string(),
+    };
+    let mut debug_state = openable_state_generator().await.open_debug().await.unwrap();
+    debug_state
+        .edit::<ConfigCollection>(
+            user_version_key.clone(),
+            proto::ConfigValue {
+                value: CATALOG_VERSION - 1,
+            },
+        )
+        .await
+        .unwrap();
+
+    let mut openable_state = openable_state_generator().await;
+    openable_state.would_open_read_only_succeed().await.unwrap();
+    let read_only_state = openable_state
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap();
+    Box::new(read_only_state).expire().await;
+
+    // A catalog that is too old to migrate is incompatible.
+    debug_state
+        .edit::<ConfigCollection>(user_version_key, proto::ConfigValue { value: 1 })
+        .await
+        .unwrap();
+    let mut openable_state = openable_state_generator().await;
+    let err = openable_state
+        .would_open_read_only_succeed()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::IncompatibleDataVersion { .. })
+        ),
+        "unexpected error: {err:?}"
+    );
+    let err = openable_state
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::IncompatibleDataVersion { .. })
+        ),
+        "unexpected error: {err:?}"
+    );
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_open() {
@@ -468,7 +687,12 @@ async fn test_open(
     let (snapshot, audit_log) = {
         let mut state = openable_state1
             // Use `NOW_ZERO` for consistent timestamps in the snapshots.
-            .open(NOW_ZERO(), &test_bootstrap_args(), deploy_generation, None)
+            .open(
+                NOW_ZERO(),
+                &test_bootstrap_args(),
+                Some(deploy_generation),
+                None,
+            )
             .await
             .unwrap();
 
@@ -493,7 +717,7 @@ async fn test_open(
             .open(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -511,7 +735,7 @@ async fn test_open(
             .open(
                 SYSTEM_TIME(),
                 &test_bootstrap_args(),
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -557,7 +781,7 @@ async fn test_unopened_fencing(
             .open(
                 NOW_ZERO(),
                 &test_bootstrap_args(),
-                deployment_generation,
+                Some(deployment_generation),
                 None,
             )
             .await
@@ -577,7 +801,7 @@ async fn test_unopened_fencing(
         .open(
             NOW_ZERO(),
             &test_bootstrap_args(),
-            deployment_generation + 1,
+            Some(deployment_generation + 1),
             None,
         )
         .await
@@ -600,6 +824,96 @@ async fn test_unopened_fencing(
     );
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_concurrent_open() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let persist_openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let persist_openable_state2 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let persist_openable_state3 =
+        test_persist_backed_catalog_state(persist_client, organization_id).await;
+    test_concurrent_open(
+        persist_openable_state1,
+        persist_openable_state2,
+        persist_openable_state3,
+    )
+    .await;
+}
+
+async fn test_concurrent_open(
+    openable_state1: Box<dyn OpenableDurableCatalogState>,
+    openable_state2: Box<dyn OpenableDurableCatalogState>,
+    openable_state3: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deployment_generation = 42;
+
+    // Initialize catalog.
+    {
+        let state = openable_state1
+            .open(
+                SYSTEM_TIME(),
+                &test_bootstrap_args(),
+                Some(deployment_generation),
+                None,
+            )
+            .await
+            .unwrap();
+        Box::new(state).expire().await;
+    }
+
+    // Open two catalogs in parallel. Both read the same epoch and race to write its successor.
+    let bootstrap_args = test_bootstrap_args();
+    let (res2, res3) = futures::join!(
+        openable_state2.open(SYSTEM_TIME(), &bootstrap_args, None, None),
+        openable_state3.open(SYSTEM_TIME(), &bootstrap_args, None, None),
+    );
+
+    let mut states = Vec::new();
+    for res in [res2, res3] {
+        match res {
+            Ok(state) => states.push(state),
+            // Losing the race during `open` fences the loser out immediately.
+            Err(err) => assert!(
+                matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+                "unexpected err: {err:?}"
+            ),
+        }
+    }
+    assert!(!states.is_empty(), "at least one open must succeed");
+
+    // If both opens succeeded, they must have distinct epochs and only the later one may commit.
+    // Attempt to commit with the earlier epoch first, so that a successful commit can't be
+    // explained by the winner having already fenced out the loser via its own write.
+    states.sort_by_key(|state| state.epoch());
+    let epochs: Vec<_> = states.iter().map(|state| state.epoch()).collect();
+    let mut epochs_dedup = epochs.clone();
+    epochs_dedup.dedup();
+    assert_eq!(epochs, epochs_dedup, "epochs must be unique");
+
+    let mut committed = Vec::new();
+    for state in &mut states {
+        match state.allocate_user_id().await {
+            Ok(_) => committed.push(state.epoch()),
+            Err(err) => assert!(
+                matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+                "unexpected err: {err:?}"
+            ),
+        }
+    }
+    assert_eq!(
+        committed,
+        vec![*epochs.last().expect("known to be non-empty")],
+        "exactly the latest epoch must be able to commit"
+    );
+
+    for state in states {
+        Box::new(state).expire().await;
+    }
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_fencing() {
@@ -623,7 +937,12 @@ async fn test_persist_fencing() {
         .await
         .unwrap();
         let _persist_state = persist_openable_state
-            .open(NOW_ZERO(), &test_bootstrap_args(), deploy_generation, None)
+            .open(
+                NOW_ZERO(),
+                &test_bootstrap_args(),
+                Some(deploy_generation),
+                None,
+            )
             .await
             .unwrap();
 
@@ -13,18 +13,31 @@ use mz_audit_log::{
     EventDetails, EventType, EventV1, IdNameV1, StorageUsageV1, VersionedEvent,
     VersionedStorageUsage,
 };
-use mz_catalog::durable::objects::{DurableType, IdAlloc};
+use mz_catalog::builtin::{
+    MZ_DATAFLOW_ADDRESSES_PER_WORKER, MZ_DATAFLOW_CHANNELS_PER_WORKER,
+    MZ_DATAFLOW_OPERATORS_PER_WORKER,
+};
+use mz_catalog::durable::debug::{Collection, IdAllocatorCollection, SchemaCollection};
+use mz_catalog::durable::objects::{Config, DurableType, IdAlloc, Snapshot};
 use mz_catalog::durable::{
-    test_bootstrap_args, test_persist_backed_catalog_state, CatalogError, DurableCatalogError,
-    Item, OpenableDurableCatalogState, USER_ITEM_ALLOC_KEY,
+    test_bootstrap_args, test_persist_backed_catalog_state, CatalogError, ClusterReplica,
+    CollectionUpdate, DurableCatalogError, Item, OpenableDurableCatalogState, ReplicaConfig,
+    ReplicaLocation, Role, Schema, SnapshotView, SystemConfiguration,
+    STORAGE_USAGE_PRUNE_CHUNK_SIZE, SYSTEM_ITEM_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY,
+    USER_ITEM_ALLOC_KEY, USER_REPLICA_ID_ALLOC_KEY,
 };
+use mz_controller_types::{ClusterId, ReplicaId};
+use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::now::SYSTEM_TIME;
 use mz_persist_client::PersistClient;
 use mz_proto::RustType;
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
-use mz_sql::names::{DatabaseId, ResolvedDatabaseSpecifier, SchemaId};
+use mz_sql::catalog::{CatalogError as SqlCatalogError, RoleAttributes, RoleMembership, RoleVars};
+use mz_sql::names::{CommentObjectId, DatabaseId, ResolvedDatabaseSpecifier, SchemaId};
+use mz_storage_types::controller::TxnWalTablesImpl;
+use std::collections::{BTreeMap, BTreeSet};
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -48,7 +61,7 @@ async fn test_confirm_leadership(
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -59,7 +72,7 @@ async fn test_confirm_leadership(
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -85,6 +98,57 @@ async fn test_confirm_leadership(
     Box::new(state2).expire().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_await_fenced() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let openable_state2 = test_persist_backed_catalog_state(persist_client, organization_id).await;
+    test_await_fenced(openable_state1, openable_state2).await;
+}
+
+async fn test_await_fenced(
+    openable_state1: Box<dyn OpenableDurableCatalogState>,
+    openable_state2: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deploy_generation = 0;
+    let mut state1 = openable_state1
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let fenced = mz_ore::task::spawn(|| "await_fenced", async move {
+        let err = state1.await_fenced().await;
+        (state1, err)
+    });
+
+    let state2 = openable_state2
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let (mut state1, err) = fenced.await.unwrap();
+    assert!(matches!(err, DurableCatalogError::Fence(_)), "{err:?}");
+
+    // Once fenced, the future resolves immediately.
+    let err = state1.await_fenced().await;
+    assert!(matches!(err, DurableCatalogError::Fence(_)), "{err:?}");
+
+    Box::new(state1).expire().await;
+    Box::new(state2).expire().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_get_and_prune_storage_usage() {
@@ -115,7 +179,7 @@ async fn test_get_and_prune_storage_usage(openable_state: Box<dyn OpenableDurabl
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -157,6 +221,277 @@ async fn test_get_and_prune_storage_usage(openable_state: Box<dyn OpenableDurabl
     Box::new(state).expire().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_get_and_prune_storage_usage_in_chunks() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_get_and_prune_storage_usage_in_chunks(openable_state).await;
+}
+
+async fn test_get_and_prune_storage_usage_in_chunks(
+    openable_state: Box<dyn OpenableDurableCatalogState>,
+) {
+    let num_old_events = 2 * STORAGE_USAGE_PRUNE_CHUNK_SIZE + 1;
+    let old_events =
+        (0..num_old_events).map(|id| VersionedStorageUsage::new(u64::cast_from(id), None, 42, 10));
+    let recent_event = VersionedStorageUsage::new(u64::cast_from(num_old_events), None, 42, 20);
+    let deploy_generation = 0;
+    let boot_ts = mz_repr::Timestamp::new(23);
+
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_storage_usage_events(old_events.chain(std::iter::once(recent_event.clone())));
+    txn.commit().await.unwrap();
+
+    let commit_ts_before = state.transaction().await.unwrap().commit_ts();
+    let events = state
+        .get_and_prune_storage_usage(Some(Duration::from_millis(10)), boot_ts, false)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.into_element(), recent_event);
+
+    // The expired events should have been removed in three separate transactions.
+    let commit_ts_after = state.transaction().await.unwrap().commit_ts();
+    assert_eq!(
+        commit_ts_after,
+        commit_ts_before.step_forward_by(&mz_repr::Timestamp::new(3))
+    );
+
+    // Pruning again shouldn't find anything else to remove.
+    let events = state
+        .get_and_prune_storage_usage(Some(Duration::from_millis(10)), boot_ts, false)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.into_element(), recent_event);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_content_fingerprint() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_content_fingerprint(openable_state).await;
+}
+
+async fn test_content_fingerprint(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Reads, and transactions that don't change anything, don't change the fingerprint.
+    let fingerprint = state.content_fingerprint().await.unwrap();
+    let _ = state.snapshot().await.unwrap();
+    assert_eq!(state.content_fingerprint().await.unwrap(), fingerprint);
+    let txn = state.transaction().await.unwrap();
+    txn.commit().await.unwrap();
+    assert_eq!(state.content_fingerprint().await.unwrap(), fingerprint);
+
+    // Any single mutation changes the fingerprint.
+    let _ = state.allocate_id(USER_ITEM_ALLOC_KEY, 1).await.unwrap();
+    let fingerprint_after_allocate = state.content_fingerprint().await.unwrap();
+    assert_ne!(fingerprint_after_allocate, fingerprint);
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("fingerprint_test".to_string(), Some(1))
+        .unwrap();
+    txn.commit().await.unwrap();
+    let fingerprint_after_insert = state.content_fingerprint().await.unwrap();
+    assert_ne!(fingerprint_after_insert, fingerprint_after_allocate);
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("fingerprint_test".to_string(), Some(2))
+        .unwrap();
+    txn.commit().await.unwrap();
+    let fingerprint_after_update = state.content_fingerprint().await.unwrap();
+    assert_ne!(fingerprint_after_update, fingerprint_after_insert);
+
+    // Reverting a change restores the previous fingerprint, since it only depends on contents.
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("fingerprint_test".to_string(), None)
+        .unwrap();
+    txn.commit().await.unwrap();
+    assert_eq!(
+        state.content_fingerprint().await.unwrap(),
+        fingerprint_after_allocate
+    );
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_sync() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let reader_openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let reader_openable_state2 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let fencing_openable_state =
+        test_persist_backed_catalog_state(persist_client, organization_id).await;
+    test_sync(
+        openable_state,
+        reader_openable_state1,
+        reader_openable_state2,
+        fencing_openable_state,
+    )
+    .await;
+}
+
+async fn test_sync(
+    openable_state: Box<dyn OpenableDurableCatalogState>,
+    reader_openable_state1: Box<dyn OpenableDurableCatalogState>,
+    reader_openable_state2: Box<dyn OpenableDurableCatalogState>,
+    fencing_openable_state: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("sync_test".to_string(), Some(42)).unwrap();
+    txn.commit().await.unwrap();
+    state.sync().await.unwrap();
+
+    // A catalog opened after the sync observes the latest commit.
+    let mut reader1 = reader_openable_state1
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap();
+    let mut txn = reader1.transaction().await.unwrap();
+    assert_eq!(txn.get_config("sync_test".to_string()), Some(42));
+    drop(txn);
+
+    // The writer keeps committing while the first reader is still open.
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("sync_test".to_string(), Some(43)).unwrap();
+    txn.commit().await.unwrap();
+    state.sync().await.unwrap();
+
+    // Both a catalog opened after the second sync and the already open reader observe the
+    // concurrent commit.
+    let mut reader2 = reader_openable_state2
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap();
+    let mut txn = reader2.transaction().await.unwrap();
+    assert_eq!(txn.get_config("sync_test".to_string()), Some(43));
+    drop(txn);
+    let mut txn = reader1.transaction().await.unwrap();
+    assert_eq!(txn.get_config("sync_test".to_string()), Some(43));
+    drop(txn);
+
+    // Syncing a read-only catalog is a no-op.
+    reader1.sync().await.unwrap();
+
+    // Syncing fails once another writer has fenced us out.
+    let fencing_state = fencing_openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let err = state.sync().await.unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+        "{err:?}"
+    );
+
+    Box::new(reader1).expire().await;
+    Box::new(reader2).expire().await;
+    Box::new(fencing_state).expire().await;
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_savepoint() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_savepoint(openable_state).await;
+}
+
+async fn test_savepoint(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_config("savepoint_a".to_string(), Some(1)).unwrap();
+    txn.set_config("savepoint_b".to_string(), Some(2)).unwrap();
+    let savepoint = txn.savepoint();
+    txn.set_config("savepoint_c".to_string(), Some(3)).unwrap();
+    txn.set_config("savepoint_a".to_string(), Some(4)).unwrap();
+    assert_eq!(txn.get_config("savepoint_c".to_string()), Some(3));
+    txn.rollback_to(savepoint).unwrap();
+    assert_eq!(txn.get_config("savepoint_a".to_string()), Some(1));
+    assert_eq!(txn.get_config("savepoint_c".to_string()), None);
+    let stale_savepoint = txn.savepoint();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_config("savepoint_a".to_string()), Some(1));
+    assert_eq!(txn.get_config("savepoint_b".to_string()), Some(2));
+    assert_eq!(txn.get_config("savepoint_c".to_string()), None);
+
+    // A savepoint can only be rolled back to in the transaction that created it.
+    txn.set_config("savepoint_d".to_string(), Some(5)).unwrap();
+    let err = txn.rollback_to(stale_savepoint).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::InvalidSavepoint)
+        ),
+        "unexpected err: {err:?}"
+    );
+    assert_eq!(txn.get_config("savepoint_d".to_string()), Some(5));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_allocate_id() {
@@ -174,7 +509,7 @@ async fn test_allocate_id(openable_state: Box<dyn OpenableDurableCatalogState>)
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -198,6 +533,10 @@ async fn test_allocate_id(openable_state: Box<dyn OpenableDurableCatalogState>)
         name: id_type.to_string(),
         next_id: start_id + 3,
     }));
+
+    let ids = state.allocate_id(id_type, 0).await.unwrap();
+    assert_eq!(ids, Vec::<u64>::new());
+    assert_eq!(state.get_next_id(id_type).await.unwrap(), start_id + 3);
     Box::new(state).expire().await;
 }
 
@@ -256,7 +595,7 @@ async fn test_audit_logs(openable_state: Box<dyn OpenableDurableCatalogState>) {
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -274,6 +613,73 @@ async fn test_audit_logs(openable_state: Box<dyn OpenableDurableCatalogState>) {
     Box::new(state).expire().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_delete_audit_logs() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state = test_persist_backed_catalog_state(persist_client, organization_id).await;
+    test_delete_audit_logs(openable_state).await;
+}
+
+async fn test_delete_audit_logs(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let audit_logs: Vec<_> = (1001..=1005)
+        .map(|id| {
+            VersionedEvent::V1(EventV1 {
+                id,
+                event_type: EventType::Drop,
+                object_type: mz_audit_log::ObjectType::View,
+                details: EventDetails::IdNameV1(IdNameV1 {
+                    id: id.to_string(),
+                    name: format!("v{id}"),
+                }),
+                user: Some("joe".to_string()),
+                occurred_at: id,
+            })
+        })
+        .collect();
+
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_audit_log_events(audit_logs.clone());
+    txn.commit().await.unwrap();
+
+    // IDs that don't exist are ignored.
+    let deleted = state.delete_audit_logs(&[1002, 1004, 9999]).await.unwrap();
+    assert_eq!(deleted, 2);
+
+    let remaining: Vec<_> = state
+        .get_audit_logs()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|event| event.sortable_id() > 1000)
+        .collect();
+    assert_eq!(
+        remaining,
+        vec![
+            audit_logs[0].clone(),
+            audit_logs[2].clone(),
+            audit_logs[4].clone()
+        ]
+    );
+
+    // Deleting the same IDs again is a no-op.
+    let deleted = state.delete_audit_logs(&[1002, 1004]).await.unwrap();
+    assert_eq!(deleted, 0);
+
+    Box::new(state).expire().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_persist_items() {
@@ -311,7 +717,7 @@ async fn test_items(openable_state: Box<dyn OpenableDurableCatalogState>) {
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -363,7 +769,7 @@ async fn test_schemas(openable_state: Box<dyn OpenableDurableCatalogState>) {
         .open(
             SYSTEM_TIME(),
             &test_bootstrap_args(),
-            deploy_generation,
+            Some(deploy_generation),
             None,
         )
         .await
@@ -401,3 +807,1287 @@ async fn test_schemas(openable_state: Box<dyn OpenableDurableCatalogState>) {
 
     Box::new(state).expire().await;
 }
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_schemas_in_database() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_schemas_in_database(openable_state).await;
+}
+
+async fn test_schemas_in_database(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let (db1, _oid) = txn
+        .insert_user_database("db1", RoleId::User(1), Vec::new())
+        .unwrap();
+    let (db2, _oid) = txn
+        .insert_user_database("db2", RoleId::User(1), Vec::new())
+        .unwrap();
+    for (database_id, name) in [(db1, "a"), (db1, "b"), (db2, "c")] {
+        txn.insert_user_schema(database_id, name, RoleId::User(1), Vec::new())
+            .unwrap();
+    }
+    // Ambient schemas are created during bootstrap.
+    assert!(txn.get_schemas().any(|schema| schema.database_id.is_none()));
+    txn.commit().await.unwrap();
+
+    let names = |schemas: Vec<Schema>| {
+        schemas
+            .into_iter()
+            .map(|schema| schema.name)
+            .collect::<Vec<_>>()
+    };
+
+    let schemas = state.get_schemas_in_database(db1).await.unwrap();
+    assert!(schemas.iter().all(|schema| schema.database_id == Some(db1)));
+    assert_eq!(names(schemas), vec!["a", "b"]);
+
+    let schemas = state.get_schemas_in_database(db2).await.unwrap();
+    assert_eq!(names(schemas), vec!["c"]);
+
+    let schemas = state
+        .get_schemas_in_database(DatabaseId::User(100))
+        .await
+        .unwrap();
+    assert!(schemas.is_empty());
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_cluster_replicas_for() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_cluster_replicas_for(openable_state).await;
+}
+
+async fn test_cluster_replicas_for(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    // The default user cluster `u1` and its replica `u1` are created during bootstrap.
+    let cluster1 = ClusterId::User(1);
+    let cluster2 = ClusterId::User(2);
+    let cluster_config = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster1)
+        .unwrap()
+        .config;
+    let replica_config = txn
+        .get_cluster_replicas()
+        .find(|replica| replica.cluster_id == cluster1)
+        .unwrap()
+        .config;
+    txn.insert_user_cluster(
+        cluster2,
+        "c2",
+        Vec::new(),
+        RoleId::User(1),
+        Vec::new(),
+        cluster_config,
+    )
+    .unwrap();
+    for (cluster_id, replica_id, name) in [
+        (cluster1, ReplicaId::User(3), "r3"),
+        (cluster2, ReplicaId::User(4), "r4"),
+        (cluster1, ReplicaId::User(2), "r2"),
+    ] {
+        txn.insert_cluster_replica(
+            cluster_id,
+            replica_id,
+            name,
+            replica_config.clone(),
+            RoleId::User(1),
+        )
+        .unwrap();
+    }
+    txn.commit().await.unwrap();
+
+    let replica_ids = |replicas: Vec<ClusterReplica>| {
+        replicas
+            .into_iter()
+            .map(|replica| replica.replica_id)
+            .collect::<Vec<_>>()
+    };
+
+    let replicas = state.get_cluster_replicas_for(cluster1).await.unwrap();
+    assert!(replicas
+        .iter()
+        .all(|replica| replica.cluster_id == cluster1));
+    assert_eq!(
+        replica_ids(replicas),
+        vec![ReplicaId::User(1), ReplicaId::User(2), ReplicaId::User(3)]
+    );
+
+    let replicas = state.get_cluster_replicas_for(cluster2).await.unwrap();
+    assert_eq!(replica_ids(replicas), vec![ReplicaId::User(4)]);
+
+    let replicas = state
+        .get_cluster_replicas_for(ClusterId::User(100))
+        .await
+        .unwrap();
+    assert!(replicas.is_empty());
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_filtered_getters() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_filtered_getters(openable_state).await;
+}
+
+async fn test_filtered_getters(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.allocate_user_item_ids(3).unwrap();
+    txn.set_txn_wal_tables(TxnWalTablesImpl::Lazy).unwrap();
+    txn.upsert_system_config("foo", "bar".to_string()).unwrap();
+    txn.commit().await.unwrap();
+
+    // The backend only reads the updates that each getter needs, so its answers must agree with
+    // the same lookups over a full snapshot.
+    let snapshot = state.snapshot().await.unwrap();
+    let view = SnapshotView::new(&snapshot);
+
+    for id_type in [
+        USER_ITEM_ALLOC_KEY,
+        SYSTEM_ITEM_ALLOC_KEY,
+        USER_REPLICA_ID_ALLOC_KEY,
+        SYSTEM_REPLICA_ID_ALLOC_KEY,
+    ] {
+        assert_eq!(
+            Some(state.get_next_id(id_type).await.unwrap()),
+            view.next_id(id_type),
+            "{id_type}"
+        );
+    }
+
+    for database_id in [DatabaseId::User(1), DatabaseId::User(100)] {
+        assert_eq!(
+            state.get_schemas_in_database(database_id).await.unwrap(),
+            view.schemas_in_database(database_id).unwrap(),
+        );
+    }
+    assert!(!view
+        .schemas_in_database(DatabaseId::User(1))
+        .unwrap()
+        .is_empty());
+
+    for cluster_id in [
+        ClusterId::User(1),
+        ClusterId::System(1),
+        ClusterId::User(100),
+    ] {
+        assert_eq!(
+            state.get_cluster_replicas_for(cluster_id).await.unwrap(),
+            view.cluster_replicas_for(cluster_id).unwrap(),
+        );
+    }
+    assert!(!view
+        .cluster_replicas_for(ClusterId::User(1))
+        .unwrap()
+        .is_empty());
+
+    assert_eq!(
+        state.get_txn_wal_tables().await.unwrap(),
+        Some(TxnWalTablesImpl::Lazy),
+    );
+
+    for name in ["foo", "nonexistent"] {
+        assert_eq!(
+            state.get_system_configuration(name).await.unwrap(),
+            view.system_configuration(name),
+            "{name}"
+        );
+    }
+    assert_eq!(view.system_configuration("foo"), Some("bar".to_string()));
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test]
+fn test_snapshot_view() {
+    fn insert<T, KP: Ord, VP>(map: &mut BTreeMap<KP, VP>, object: T)
+    where
+        T: DurableType,
+        T::Key: RustType<KP>,
+        T::Value: RustType<VP>,
+    {
+        let (key, value) = object.into_key_value();
+        map.insert(key.into_proto(), value.into_proto());
+    }
+
+    fn role(id: u64, member_of: &[u64]) -> Role {
+        let mut membership = RoleMembership::new();
+        for member_of in member_of {
+            membership
+                .map
+                .insert(RoleId::User(*member_of), RoleId::User(1));
+        }
+        Role {
+            id: RoleId::User(id),
+            oid: 20_000 + u32::try_from(id).unwrap(),
+            name: format!("role{id}"),
+            attributes: RoleAttributes::new(),
+            membership,
+            vars: RoleVars::default(),
+        }
+    }
+
+    fn schema(id: u64, database_id: Option<DatabaseId>) -> Schema {
+        Schema {
+            id: SchemaId::User(id),
+            oid: 30_000 + u32::try_from(id).unwrap(),
+            name: format!("schema{id}"),
+            database_id,
+            owner_id: RoleId::User(1),
+            privileges: Vec::new(),
+        }
+    }
+
+    fn replica(cluster_id: ClusterId, id: u64) -> ClusterReplica {
+        ClusterReplica {
+            cluster_id,
+            replica_id: ReplicaId::User(id),
+            name: format!("replica{id}"),
+            config: ReplicaConfig {
+                location: ReplicaLocation::Managed {
+                    size: "1".to_string(),
+                    availability_zone: None,
+                    disk: false,
+                    internal: false,
+                    billed_as: None,
+                    pending: false,
+                },
+                logging: Default::default(),
+            },
+            owner_id: RoleId::User(1),
+        }
+    }
+
+    let mut snapshot = Snapshot::default();
+    insert(
+        &mut snapshot.id_allocator,
+        IdAlloc {
+            name: USER_ITEM_ALLOC_KEY.to_string(),
+            next_id: 7,
+        },
+    );
+    insert(
+        &mut snapshot.configs,
+        Config {
+            key: "foo".to_string(),
+            value: 42,
+        },
+    );
+    for (name, value) in [("b", "2"), ("a", "1")] {
+        insert(
+            &mut snapshot.system_configurations,
+            SystemConfiguration {
+                name: name.to_string(),
+                value: value.to_string(),
+            },
+        );
+    }
+    // Role 1 is a member of 2, which is a member of 3, which is a member of 2 again.
+    for role in [role(1, &[2]), role(2, &[3]), role(3, &[2]), role(4, &[])] {
+        insert(&mut snapshot.roles, role);
+    }
+    for schema in [
+        schema(3, Some(DatabaseId::User(1))),
+        schema(2, Some(DatabaseId::User(2))),
+        schema(1, Some(DatabaseId::User(1))),
+        schema(4, None),
+    ] {
+        insert(&mut snapshot.schemas, schema);
+    }
+    for replica in [
+        replica(ClusterId::User(1), 3),
+        replica(ClusterId::User(2), 2),
+        replica(ClusterId::User(1), 1),
+    ] {
+        insert(&mut snapshot.cluster_replicas, replica);
+    }
+
+    let view = SnapshotView::new(&snapshot);
+
+    assert_eq!(view.next_id(USER_ITEM_ALLOC_KEY), Some(7));
+    assert_eq!(view.next_id(SYSTEM_ITEM_ALLOC_KEY), None);
+
+    assert_eq!(view.config("foo"), Some(42));
+    assert_eq!(view.config("bar"), None);
+
+    assert_eq!(
+        view.system_configurations().unwrap(),
+        vec![
+            SystemConfiguration {
+                name: "a".to_string(),
+                value: "1".to_string(),
+            },
+            SystemConfiguration {
+                name: "b".to_string(),
+                value: "2".to_string(),
+            },
+        ]
+    );
+    assert_eq!(view.system_configuration("b"), Some("2".to_string()));
+    assert_eq!(view.system_configuration("c"), None);
+
+    assert_eq!(
+        view.role_membership_closure(RoleId::User(1)).unwrap(),
+        BTreeSet::from([RoleId::User(2), RoleId::User(3)])
+    );
+    // A role is only a member of itself through a cycle.
+    assert_eq!(
+        view.role_membership_closure(RoleId::User(2)).unwrap(),
+        BTreeSet::from([RoleId::User(2), RoleId::User(3)])
+    );
+    assert_eq!(
+        view.role_membership_closure(RoleId::User(4)).unwrap(),
+        BTreeSet::new()
+    );
+
+    let schema_ids = |database_id| {
+        view.schemas_in_database(database_id)
+            .unwrap()
+            .into_iter()
+            .map(|schema| schema.id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        schema_ids(DatabaseId::User(1)),
+        vec![SchemaId::User(1), SchemaId::User(3)]
+    );
+    assert_eq!(schema_ids(DatabaseId::User(2)), vec![SchemaId::User(2)]);
+    assert_eq!(schema_ids(DatabaseId::User(3)), Vec::new());
+
+    let replica_ids = |cluster_id| {
+        view.cluster_replicas_for(cluster_id)
+            .unwrap()
+            .into_iter()
+            .map(|replica| replica.replica_id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        replica_ids(ClusterId::User(1)),
+        vec![ReplicaId::User(1), ReplicaId::User(3)]
+    );
+    assert_eq!(replica_ids(ClusterId::User(2)), vec![ReplicaId::User(2)]);
+    assert_eq!(replica_ids(ClusterId::System(1)), Vec::new());
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_system_configuration() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_system_configuration(openable_state).await;
+}
+
+async fn test_system_configuration(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    txn.upsert_system_config("enable_foo", "on".to_string())
+        .unwrap();
+    txn.upsert_system_config("max_bar", "42".to_string())
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    assert_eq!(
+        state.get_system_configuration("enable_foo").await.unwrap(),
+        Some("on".to_string())
+    );
+    assert_eq!(
+        state.get_system_configuration("max_bar").await.unwrap(),
+        Some("42".to_string())
+    );
+    assert_eq!(
+        state.get_system_configuration("nonexistent").await.unwrap(),
+        None
+    );
+
+    let configs = state.get_system_configurations().await.unwrap();
+    assert!(configs.contains(&SystemConfiguration {
+        name: "enable_foo".to_string(),
+        value: "on".to_string(),
+    }));
+    let map = state.get_system_configurations_map().await.unwrap();
+    assert_eq!(map.len(), configs.len());
+    assert_eq!(map.get("max_bar").map(String::as_str), Some("42"));
+    assert_eq!(map.get("nonexistent"), None);
+
+    // Removed configurations are no longer found.
+    let mut txn = state.transaction().await.unwrap();
+    txn.remove_system_config("enable_foo");
+    txn.commit().await.unwrap();
+    assert_eq!(
+        state.get_system_configuration("enable_foo").await.unwrap(),
+        None
+    );
+    assert!(!state
+        .get_system_configurations_map()
+        .await
+        .unwrap()
+        .contains_key("enable_foo"));
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_introspection_source_index_name() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_introspection_source_index_name(openable_state).await;
+}
+
+async fn test_introspection_source_index_name(
+    openable_state: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let cluster1 = ClusterId::User(1);
+    let cluster2 = ClusterId::User(2);
+    let cluster_config = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster1)
+        .unwrap()
+        .config;
+    let logs = [
+        (&*MZ_DATAFLOW_OPERATORS_PER_WORKER, GlobalId::System(1000)),
+        (&*MZ_DATAFLOW_ADDRESSES_PER_WORKER, GlobalId::System(1001)),
+        (&*MZ_DATAFLOW_CHANNELS_PER_WORKER, GlobalId::System(1002)),
+    ];
+    txn.insert_user_cluster(
+        cluster2,
+        "c2",
+        logs.to_vec(),
+        RoleId::User(1),
+        Vec::new(),
+        cluster_config,
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let indexes = txn.get_introspection_source_indexes(cluster2);
+    let names: Vec<_> = indexes.keys().cloned().collect();
+    let mut expected_names: Vec<_> = logs.iter().map(|(log, _)| log.name.to_string()).collect();
+    expected_names.sort();
+    assert_eq!(names, expected_names);
+
+    for (log, id) in logs {
+        assert_eq!(
+            txn.get_introspection_source_index_name(cluster2, id)
+                .unwrap(),
+            Some(log.name.to_string())
+        );
+    }
+
+    // Unknown ids, indexes on other clusters, and non-system ids are absent.
+    assert_eq!(
+        txn.get_introspection_source_index_name(cluster2, GlobalId::System(2000))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        txn.get_introspection_source_index_name(cluster1, GlobalId::System(1000))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        txn.get_introspection_source_index_name(cluster2, GlobalId::User(1000))
+            .unwrap(),
+        None
+    );
+    drop(txn);
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_snapshot_as_of() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_snapshot_as_of(openable_state).await;
+}
+
+async fn test_snapshot_as_of(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    fn schema_names(snapshot: &Snapshot) -> BTreeSet<String> {
+        snapshot
+            .schemas
+            .values()
+            .map(|value| value.name.clone())
+            .collect()
+    }
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_user_schema(DatabaseId::User(1), "foo", RoleId::User(1), vec![])
+        .unwrap();
+    let first_ts = txn.commit_ts();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_user_schema(DatabaseId::User(1), "bar", RoleId::User(1), vec![])
+        .unwrap();
+    let second_ts = txn.commit_ts();
+    txn.commit().await.unwrap();
+    assert!(first_ts < second_ts);
+
+    // The earlier version is still readable, and only contains the first schema.
+    let snapshot = state.snapshot_as_of(first_ts).await.unwrap();
+    let names = schema_names(&snapshot);
+    assert!(names.contains("foo"));
+    assert!(!names.contains("bar"));
+
+    // The latest version matches the current snapshot.
+    let snapshot = state.snapshot_as_of(second_ts).await.unwrap();
+    let names = schema_names(&snapshot);
+    assert!(names.contains("foo"));
+    assert!(names.contains("bar"));
+    assert_eq!(snapshot, state.snapshot().await.unwrap());
+
+    // Timestamps at or beyond the upper are not readable.
+    let err = state
+        .snapshot_as_of(second_ts.step_forward())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::InvalidReadTimestamp { .. })
+        ),
+        "unexpected error: {err:?}"
+    );
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_snapshot_as_of_savepoint() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let openable_state2 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_snapshot_as_of_savepoint(openable_state1, openable_state2).await;
+}
+
+async fn test_snapshot_as_of_savepoint(
+    openable_state1: Box<dyn OpenableDurableCatalogState>,
+    openable_state2: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deploy_generation = 0;
+    let state = openable_state1
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    Box::new(state).expire().await;
+
+    let mut savepoint_state = openable_state2
+        .open_savepoint(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = savepoint_state.transaction().await.unwrap();
+    txn.insert_user_schema(DatabaseId::User(1), "foo", RoleId::User(1), vec![])
+        .unwrap();
+    let ts = txn.commit_ts();
+    txn.commit().await.unwrap();
+
+    // The savepoint commit was never written durably, so there's no history to read it from.
+    let err = savepoint_state.snapshot_as_of(ts).await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::Unsupported {
+                operation: "snapshot_as_of"
+            })
+        ),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(
+        err.to_string(),
+        "snapshot_as_of is not supported by this catalog"
+    );
+
+    Box::new(savepoint_state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_commit_transaction_streaming() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let batch_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let streaming_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    test_commit_transaction_streaming(batch_state, streaming_state).await;
+}
+
+async fn test_commit_transaction_streaming(
+    batch_state: Box<dyn OpenableDurableCatalogState>,
+    streaming_state: Box<dyn OpenableDurableCatalogState>,
+) {
+    /// Returns the updates that turn `before` into `after`.
+    fn diff<T: Collection>(
+        before: &BTreeMap<T::Key, T::Value>,
+        after: &BTreeMap<T::Key, T::Value>,
+    ) -> Vec<CollectionUpdate>
+    where
+        T::Key: Ord + Clone,
+        T::Value: PartialEq + Clone,
+    {
+        let retractions = before
+            .iter()
+            .filter(|(key, value)| after.get(key) != Some(value))
+            .map(|(key, value)| CollectionUpdate::new::<T>(key.clone(), value.clone(), -1));
+        let insertions = after
+            .iter()
+            .filter(|(key, value)| before.get(key) != Some(value))
+            .map(|(key, value)| CollectionUpdate::new::<T>(key.clone(), value.clone(), 1));
+        retractions.chain(insertions).collect()
+    }
+
+    let now = SYSTEM_TIME();
+    let deploy_generation = 0;
+    let mut batch_state = batch_state
+        .open(now, &test_bootstrap_args(), Some(deploy_generation), None)
+        .await
+        .unwrap();
+    let mut streaming_state = streaming_state
+        .open(now, &test_bootstrap_args(), Some(deploy_generation), None)
+        .await
+        .unwrap();
+    let before = streaming_state.snapshot().await.unwrap();
+    assert_eq!(before, batch_state.snapshot().await.unwrap());
+
+    let mut txn = batch_state.transaction().await.unwrap();
+    txn.insert_user_schema(DatabaseId::User(1), "foo", RoleId::User(1), Vec::new())
+        .unwrap();
+    txn.insert_user_schema(DatabaseId::User(1), "bar", RoleId::User(1), Vec::new())
+        .unwrap();
+    txn.commit().await.unwrap();
+    let after = batch_state.snapshot().await.unwrap();
+    assert_ne!(before, after);
+
+    // Stream the equivalent updates into the other catalog.
+    let updates: Vec<_> = diff::<SchemaCollection>(&before.schemas, &after.schemas)
+        .into_iter()
+        .chain(diff::<IdAllocatorCollection>(
+            &before.id_allocator,
+            &after.id_allocator,
+        ))
+        .collect();
+    streaming_state
+        .commit_transaction_streaming(Box::new(updates.into_iter()))
+        .await
+        .unwrap();
+    assert_eq!(streaming_state.snapshot().await.unwrap(), after);
+
+    // Committing no updates is a no-op.
+    streaming_state
+        .commit_transaction_streaming(Box::new(std::iter::empty()))
+        .await
+        .unwrap();
+    assert_eq!(streaming_state.snapshot().await.unwrap(), after);
+
+    // Invalid updates are rejected and nothing is committed.
+    let (schema_key, schema_value) = after.schemas.first_key_value().unwrap();
+    let mut other_value = schema_value.clone();
+    other_value.name = "baz".to_string();
+    let invalid_updates = [
+        // Retracting a value that doesn't match the current value.
+        vec![CollectionUpdate::new::<SchemaCollection>(
+            schema_key.clone(),
+            other_value.clone(),
+            -1,
+        )],
+        // Inserting a key that already exists.
+        vec![CollectionUpdate::new::<SchemaCollection>(
+            schema_key.clone(),
+            other_value.clone(),
+            1,
+        )],
+        // Retracting the same value twice.
+        vec![
+            CollectionUpdate::new::<SchemaCollection>(schema_key.clone(), schema_value.clone(), -1),
+            CollectionUpdate::new::<SchemaCollection>(schema_key.clone(), schema_value.clone(), -1),
+        ],
+        // A diff other than 1 or -1.
+        vec![CollectionUpdate::new::<SchemaCollection>(
+            schema_key.clone(),
+            schema_value.clone(),
+            2,
+        )],
+    ];
+    for updates in invalid_updates {
+        let err = streaming_state
+            .commit_transaction_streaming(Box::new(updates.into_iter()))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                CatalogError::Durable(
+                    DurableCatalogError::InvalidUpdate(_) | DurableCatalogError::DuplicateKey
+                )
+            ),
+            "unexpected error: {err:?}"
+        );
+        assert_eq!(streaming_state.snapshot().await.unwrap(), after);
+    }
+
+    // Retracting a stale id allocator value is a conflict.
+    let stale_updates = diff::<IdAllocatorCollection>(&before.id_allocator, &after.id_allocator);
+    let err = streaming_state
+        .commit_transaction_streaming(Box::new(stale_updates.into_iter()))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Conflict(_))),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(streaming_state.snapshot().await.unwrap(), after);
+
+    // A valid replacement still goes through.
+    streaming_state
+        .commit_transaction_streaming(Box::new(
+            [
+                CollectionUpdate::new::<SchemaCollection>(
+                    schema_key.clone(),
+                    schema_value.clone(),
+                    -1,
+                ),
+                CollectionUpdate::new::<SchemaCollection>(
+                    schema_key.clone(),
+                    other_value.clone(),
+                    1,
+                ),
+            ]
+            .into_iter(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        streaming_state.snapshot().await.unwrap().schemas[schema_key],
+        other_value
+    );
+
+    Box::new(batch_state).expire().await;
+    Box::new(streaming_state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_delete_comments_for_object() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_delete_comments_for_object(openable_state).await;
+}
+
+async fn test_delete_comments_for_object(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let dropped = CommentObjectId::Table(GlobalId::User(1));
+    let kept = CommentObjectId::Table(GlobalId::User(2));
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.update_comment(dropped, None, Some("table".to_string()))
+        .unwrap();
+    txn.update_comment(dropped, Some(1), Some("column".to_string()))
+        .unwrap();
+    txn.update_comment(kept, None, Some("other table".to_string()))
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_comments_for_object(dropped).count(), 2);
+    let deleted = txn.delete_comments_for_object(dropped).unwrap();
+    assert_eq!(
+        deleted
+            .into_iter()
+            .map(|comment| (comment.sub_component, comment.comment))
+            .collect::<BTreeSet<_>>(),
+        BTreeSet::from([(None, "table".to_string()), (Some(1), "column".to_string())]),
+    );
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_comments_for_object(dropped).count(), 0);
+    let kept_comments: Vec<_> = txn.get_comments_for_object(kept).collect();
+    assert_eq!(kept_comments.len(), 1);
+    assert_eq!(kept_comments[0].comment, "other table");
+    drop(txn);
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_upsert_cluster_replica() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_upsert_cluster_replica(openable_state).await;
+}
+
+async fn test_upsert_cluster_replica(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let existing = txn
+        .get_cluster_replicas()
+        .next()
+        .expect("bootstrap creates replicas");
+    let replica_id = ReplicaId::User(100);
+
+    // Insert a new replica.
+    txn.upsert_cluster_replica(
+        replica_id,
+        existing.cluster_id,
+        "new_replica",
+        existing.config.clone(),
+        RoleId::User(1),
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    // Update the new replica's config.
+    let mut txn = state.transaction().await.unwrap();
+    let mut config = existing.config.clone();
+    config.logging.log_logging = !config.logging.log_logging;
+    txn.upsert_cluster_replica(
+        replica_id,
+        existing.cluster_id,
+        "new_replica",
+        config.clone(),
+        RoleId::User(1),
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let replica = txn
+        .get_cluster_replicas()
+        .find(|replica| replica.replica_id == replica_id)
+        .expect("replica was inserted");
+    assert_eq!(replica.cluster_id, existing.cluster_id);
+    assert_eq!(replica.name, "new_replica");
+    assert_eq!(replica.config, config);
+
+    // Reject a name that is already used by another replica in the cluster.
+    let err = txn
+        .upsert_cluster_replica(
+            ReplicaId::User(101),
+            existing.cluster_id,
+            &existing.name,
+            existing.config.clone(),
+            RoleId::User(1),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::DuplicateName { ref name, .. }) if *name == existing.name
+        ),
+        "{err:?}"
+    );
+
+    // Reject replicas in unknown clusters.
+    let err = txn
+        .upsert_cluster_replica(
+            ReplicaId::User(101),
+            ClusterId::User(1000),
+            "other_replica",
+            existing.config.clone(),
+            RoleId::User(1),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Catalog(SqlCatalogError::UnknownCluster(_))
+        ),
+        "{err:?}"
+    );
+
+    // Reject moving an existing replica to another cluster.
+    let other_cluster = txn
+        .get_clusters()
+        .find(|cluster| cluster.id != existing.cluster_id)
+        .expect("bootstrap creates multiple clusters");
+    let err = txn
+        .upsert_cluster_replica(
+            replica_id,
+            other_cluster.id,
+            "new_replica",
+            config.clone(),
+            RoleId::User(1),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::ReplicaClusterMismatch {
+                replica_id: id,
+                cluster_id,
+                requested_cluster_id,
+            }) if id == replica_id
+                && cluster_id == existing.cluster_id
+                && requested_cluster_id == other_cluster.id
+        ),
+        "{err:?}"
+    );
+    let replica = txn
+        .get_cluster_replicas()
+        .find(|replica| replica.replica_id == replica_id)
+        .expect("replica was inserted");
+    assert_eq!(replica.cluster_id, existing.cluster_id);
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_role_membership_closure() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_role_membership_closure(openable_state).await;
+}
+
+async fn test_role_membership_closure(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Create the chain a -> b -> c, where each role is a member of the next.
+    let mut txn = state.transaction().await.unwrap();
+    let mut insert_role = |name: &str, member_of: Option<RoleId>| {
+        let mut membership = RoleMembership::new();
+        if let Some(member_of) = member_of {
+            membership.map.insert(member_of, RoleId::User(1));
+        }
+        let (id, _oid) = txn
+            .insert_user_role(
+                name.to_string(),
+                RoleAttributes::new(),
+                membership,
+                RoleVars::default(),
+            )
+            .unwrap();
+        id
+    };
+    let c = insert_role("c", None);
+    let b = insert_role("b", Some(c));
+    let a = insert_role("a", Some(b));
+    txn.commit().await.unwrap();
+
+    assert_eq!(
+        state.get_role_membership_closure(a).await.unwrap(),
+        BTreeSet::from([b, c])
+    );
+    assert_eq!(
+        state.get_role_membership_closure(b).await.unwrap(),
+        BTreeSet::from([c])
+    );
+    assert_eq!(
+        state.get_role_membership_closure(c).await.unwrap(),
+        BTreeSet::new()
+    );
+
+    // Close the cycle by making c a member of a.
+    let mut txn = state.transaction().await.unwrap();
+    let mut role_c = txn
+        .get_roles()
+        .find(|role| role.id == c)
+        .expect("role c exists");
+    role_c.membership.map.insert(a, RoleId::User(1));
+    txn.update_role(c, role_c).unwrap();
+    txn.commit().await.unwrap();
+
+    for role in [a, b, c] {
+        assert_eq!(
+            state.get_role_membership_closure(role).await.unwrap(),
+            BTreeSet::from([a, b, c])
+        );
+    }
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_export() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_export(openable_state).await;
+}
+
+async fn test_export(openable_state: Box<dyn OpenableDurableCatalogState>) {
+    fn sorted<T, K: Ord>(mut objects: Vec<T>, key: impl Fn(&T) -> K) -> Vec<T> {
+        objects.sort_by_key(key);
+        objects
+    }
+
+    let deploy_generation = 0;
+    let mut state = openable_state
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let export = state.export().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert!(!export.roles.is_empty());
+    assert!(!export.clusters.is_empty());
+    assert_eq!(
+        sorted(export.clusters, |cluster| cluster.id),
+        sorted(txn.get_clusters().collect(), |cluster| cluster.id),
+    );
+    assert_eq!(
+        sorted(export.cluster_replicas, |replica| replica.replica_id),
+        sorted(txn.get_cluster_replicas().collect(), |replica| replica
+            .replica_id),
+    );
+    assert_eq!(
+        sorted(export.roles, |role| role.id),
+        sorted(txn.get_roles().collect(), |role| role.id),
+    );
+    assert_eq!(
+        sorted(export.schemas, |schema| schema.id),
+        sorted(txn.get_schemas().collect(), |schema| schema.id),
+    );
+    assert_eq!(
+        sorted(export.items, |item| item.id),
+        sorted(txn.get_items().collect(), |item| item.id),
+    );
+    assert_eq!(
+        sorted(export.system_object_mappings, |mapping| mapping
+            .unique_identifier
+            .id),
+        sorted(txn.get_system_object_mappings().collect(), |mapping| {
+            mapping.unique_identifier.id
+        }),
+    );
+    drop(txn);
+
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_name_resolution_context() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let openable_state2 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_name_resolution_context(openable_state1, openable_state2).await;
+}
+
+async fn test_name_resolution_context(
+    openable_state1: Box<dyn OpenableDurableCatalogState>,
+    openable_state2: Box<dyn OpenableDurableCatalogState>,
+) {
+    let deploy_generation = 0;
+    let mut state = openable_state1
+        .open(
+            SYSTEM_TIME(),
+            &test_bootstrap_args(),
+            Some(deploy_generation),
+            None,
+        )
+        .await
+        .unwrap();
+    // A second handle on the same catalog, which observes the commit below concurrently.
+    let mut reader = openable_state2
+        .open_read_only(&test_bootstrap_args())
+        .await
+        .unwrap();
+
+    let before = reader.get_name_resolution_context().await.unwrap();
+    let materialize = before.database_ids["materialize"];
+    assert!(before
+        .schema_ids
+        .contains_key(&(Some(materialize), "public".to_string())));
+    assert!(before
+        .schema_ids
+        .contains_key(&(None, "mz_catalog".to_string())));
+    assert!(before.role_ids.contains_key("mz_system"));
+
+    // Commit a new database, schema, and role together.
+    let mut txn = state.transaction().await.unwrap();
+    let (db_id, _) = txn
+        .insert_user_database("db", RoleId::User(1), Vec::new())
+        .unwrap();
+    let (schema_id, _) = txn
+        .insert_user_schema(db_id, "sc", RoleId::User(1), Vec::new())
+        .unwrap();
+    let (role_id, _) = txn
+        .insert_user_role(
+            "r".to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    // The commit wasn't visible to the read before it.
+    assert!(!before.database_ids.contains_key("db"));
+    assert!(!before
+        .schema_ids
+        .contains_key(&(Some(db_id), "sc".to_string())));
+    assert!(!before.role_ids.contains_key("r"));
+
+    // A fresh read from the other handle observes the entire commit.
+    let after = reader.get_name_resolution_context().await.unwrap();
+    assert_eq!(after.database_ids.get("db"), Some(&db_id));
+    assert_eq!(
+        after.schema_ids.get(&(Some(db_id), "sc".to_string())),
+        Some(&schema_id)
+    );
+    assert_eq!(after.role_ids.get("r"), Some(&role_id));
+    assert_eq!(after.databases.len(), before.databases.len() + 1);
+    assert_eq!(after.schemas.len(), before.schemas.len() + 1);
+    assert_eq!(after.roles.len(), before.roles.len() + 1);
+
+    // The maps agree with the objects.
+    for database in &after.databases {
+        assert_eq!(after.database_ids[&database.name], database.id);
+    }
+    for schema in &after.schemas {
+        assert_eq!(
+            after.schema_ids[&(schema.database_id, schema.name.clone())],
+            schema.id
+        );
+    }
+    for role in &after.roles {
+        assert_eq!(after.role_ids[&role.name], role.id);
+    }
+
+    Box::new(reader).expire().await;
+    Box::new(state).expire().await;
+}
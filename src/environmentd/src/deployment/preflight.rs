@@ -68,7 +68,7 @@ pub async fn preflight_legacy(
                     default_cluster_replica_size: bootstrap_default_cluster_replica_size,
                     bootstrap_role,
                 },
-                deploy_generation,
+                Some(deploy_generation),
                 None,
             )
             .await
@@ -176,7 +176,7 @@ pub async fn preflight_0dt(
                         default_cluster_replica_size: bootstrap_default_cluster_replica_size,
                         bootstrap_role,
                     },
-                    deploy_generation,
+                    Some(deploy_generation),
                     None,
                 )
                 .await
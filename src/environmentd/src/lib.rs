@@ -427,7 +427,7 @@ impl Listeners {
                 .open_savepoint(
                     boot_ts,
                     &bootstrap_args,
-                    config.controller.deploy_generation,
+                    Some(config.controller.deploy_generation),
                     None,
                 )
                 .await?
@@ -436,7 +436,7 @@ impl Listeners {
                 .open(
                     boot_ts,
                     &bootstrap_args,
-                    config.controller.deploy_generation,
+                    Some(config.controller.deploy_generation),
                     None,
                 )
                 .await?
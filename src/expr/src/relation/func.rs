@@ -45,7 +45,7 @@ use crate::relation::{
     compare_columns, proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc,
     WindowFrame, WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::scalar::func::{add_timestamp_months, build_regex, jsonb_stringify};
 use crate::EvalError;
 use crate::WindowFrameBound::{
     CurrentRow, OffsetFollowing, OffsetPreceding, UnboundedFollowing, UnboundedPreceding,
@@ -2161,6 +2161,19 @@ fn regexp_extract(a: Datum, r: &AnalyzedRegex) -> Option<(Row, Diff)> {
     Some((Row::pack(datums), 1))
 }
 
+fn regexp_split_to_table<'a>(
+    text: Datum<'a>,
+    regexp: Datum<'a>,
+    flags: Datum<'a>,
+) -> Result<impl Iterator<Item = (Row, Diff)> + 'a, EvalError> {
+    let text = text.unwrap_str();
+    let regexp = build_regex(regexp.unwrap_str(), flags.unwrap_str())?;
+    let found = mz_regexp::regexp_split_to_array(text, &regexp);
+    Ok(found
+        .into_iter()
+        .map(move |s| (Row::pack_slice(&[Datum::String(s)]), 1)))
+}
+
 fn generate_series<N>(
     start: N,
     stop: N,
@@ -2181,7 +2194,7 @@ where
 
 /// Like
 /// [`num::range_step_inclusive`](https://github.com/rust-num/num-iter/blob/ddb14c1e796d401014c6c7a727de61d8109ad986/src/lib.rs#L279),
-/// but for our timestamp types using [`Interval`] for `step`.xwxw
+/// but for our timestamp types using [`Interval`] for `step`.
 #[derive(Clone)]
 pub struct TimestampRangeStepInclusive<T> {
     state: CheckedTimestamp<T>,
@@ -2626,6 +2639,10 @@ pub enum TableFunc {
         stringify: bool,
     },
     RegexpExtract(AnalyzedRegex),
+    /// Splits a string using a POSIX regular expression as the delimiter,
+    /// producing one row per piece. Accepts an optional third argument of
+    /// regex flags.
+    RegexpSplitToTable,
     CsvExtract(usize),
     GenerateSeriesInt32,
     GenerateSeriesInt64,
@@ -2670,6 +2687,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
                 TableFunc::JsonbObjectKeys => Kind::JsonbObjectKeys(()),
                 TableFunc::JsonbArrayElements { stringify } => Kind::JsonbArrayElements(*stringify),
                 TableFunc::RegexpExtract(x) => Kind::RegexpExtract(x.into_proto()),
+                TableFunc::RegexpSplitToTable => Kind::RegexpSplitToTable(()),
                 TableFunc::CsvExtract(x) => Kind::CsvExtract(x.into_proto()),
                 TableFunc::GenerateSeriesInt32 => Kind::GenerateSeriesInt32(()),
                 TableFunc::GenerateSeriesInt64 => Kind::GenerateSeriesInt64(()),
@@ -2708,6 +2726,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
             Kind::JsonbObjectKeys(()) => TableFunc::JsonbObjectKeys,
             Kind::JsonbArrayElements(stringify) => TableFunc::JsonbArrayElements { stringify },
             Kind::RegexpExtract(x) => TableFunc::RegexpExtract(x.into_rust()?),
+            Kind::RegexpSplitToTable(()) => TableFunc::RegexpSplitToTable,
             Kind::CsvExtract(x) => TableFunc::CsvExtract(x.into_rust()?),
             Kind::GenerateSeriesInt32(()) => TableFunc::GenerateSeriesInt32,
             Kind::GenerateSeriesInt64(()) => TableFunc::GenerateSeriesInt64,
@@ -2760,6 +2779,15 @@ impl TableFunc {
                 *stringify,
             ))),
             TableFunc::RegexpExtract(a) => Ok(Box::new(regexp_extract(datums[0], a).into_iter())),
+            TableFunc::RegexpSplitToTable => {
+                let flags = if datums.len() == 2 {
+                    Datum::String("")
+                } else {
+                    datums[2]
+                };
+                let res = regexp_split_to_table(datums[0], datums[1], flags)?;
+                Ok(Box::new(res))
+            }
             TableFunc::CsvExtract(n_cols) => Ok(Box::new(csv_extract(datums[0], *n_cols))),
             TableFunc::GenerateSeriesInt32 => {
                 let res = generate_series(
@@ -2877,6 +2905,11 @@ impl TableFunc {
                 let keys = vec![];
                 (column_types, keys)
             }
+            TableFunc::RegexpSplitToTable => {
+                let column_types = vec![ScalarType::String.nullable(false)];
+                let keys = vec![];
+                (column_types, keys)
+            }
             TableFunc::CsvExtract(n_cols) => {
                 let column_types = iter::repeat(ScalarType::String.nullable(false))
                     .take(*n_cols)
@@ -2958,6 +2991,7 @@ impl TableFunc {
             TableFunc::JsonbObjectKeys => 1,
             TableFunc::JsonbArrayElements { .. } => 1,
             TableFunc::RegexpExtract(a) => a.capture_groups_len(),
+            TableFunc::RegexpSplitToTable => 1,
             TableFunc::CsvExtract(n_cols) => *n_cols,
             TableFunc::GenerateSeriesInt32 => 1,
             TableFunc::GenerateSeriesInt64 => 1,
@@ -2986,6 +3020,7 @@ impl TableFunc {
             | TableFunc::GenerateSeriesTimestampTz
             | TableFunc::GenerateSubscriptsArray
             | TableFunc::RegexpExtract(_)
+            | TableFunc::RegexpSplitToTable
             | TableFunc::CsvExtract(_)
             | TableFunc::Repeat
             | TableFunc::UnnestArray { .. }
@@ -3007,6 +3042,7 @@ impl TableFunc {
             TableFunc::JsonbObjectKeys => true,
             TableFunc::JsonbArrayElements { .. } => true,
             TableFunc::RegexpExtract(_) => true,
+            TableFunc::RegexpSplitToTable => true,
             TableFunc::CsvExtract(_) => true,
             TableFunc::GenerateSeriesInt32 => true,
             TableFunc::GenerateSeriesInt64 => true,
@@ -3032,6 +3068,7 @@ impl fmt::Display for TableFunc {
             TableFunc::JsonbObjectKeys => f.write_str("jsonb_object_keys"),
             TableFunc::JsonbArrayElements { .. } => f.write_str("jsonb_array_elements"),
             TableFunc::RegexpExtract(a) => write!(f, "regexp_extract({:?}, _)", a.0),
+            TableFunc::RegexpSplitToTable => f.write_str("regexp_split_to_table"),
             TableFunc::CsvExtract(n_cols) => write!(f, "csv_extract({}, _)", n_cols),
             TableFunc::GenerateSeriesInt32 => f.write_str("generate_series"),
             TableFunc::GenerateSeriesInt64 => f.write_str("generate_series"),
@@ -3050,10 +3087,15 @@ impl fmt::Display for TableFunc {
 
 #[cfg(test)]
 mod tests {
+    use chrono::NaiveDate;
     use mz_proto::protobuf_roundtrip;
+    use mz_repr::adt::interval::Interval;
+    use mz_repr::adt::timestamp::CheckedTimestamp;
+    use mz_repr::{Datum, RowArena, ScalarType};
     use proptest::prelude::*;
 
     use super::{AggregateFunc, ProtoAggregateFunc, ProtoTableFunc, TableFunc};
+    use crate::{EvalError, MirScalarExpr, VariadicFunc};
 
     proptest! {
        #[mz_ore::test]
@@ -3074,4 +3116,127 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
     }
+
+    /// Asserts that `regexp_split_to_table` produces exactly the elements of
+    /// `regexp_split_to_array`, in the same order.
+    fn assert_split_to_table_matches_array(text: &str, pattern: &str, expected: &[&str]) {
+        let arena = RowArena::new();
+        let datums = [Datum::String(text), Datum::String(pattern)];
+
+        let table_func = TableFunc::RegexpSplitToTable;
+        let rows: Vec<_> = table_func.eval(&datums, &arena).unwrap().collect();
+        let table: Vec<_> = rows
+            .iter()
+            .map(|(row, diff)| {
+                assert_eq!(*diff, 1);
+                row.unpack_first().unwrap_str().to_string()
+            })
+            .collect();
+
+        let exprs = [
+            MirScalarExpr::literal_ok(datums[0], ScalarType::String),
+            MirScalarExpr::literal_ok(datums[1], ScalarType::String),
+        ];
+        let array_func = VariadicFunc::RegexpSplitToArray;
+        let array = array_func.eval(&datums, &arena, &exprs).unwrap();
+        let array: Vec<_> = array
+            .unwrap_array()
+            .elements()
+            .iter()
+            .map(|d| d.unwrap_str().to_string())
+            .collect();
+
+        assert_eq!(table, array);
+        assert_eq!(table, expected);
+    }
+
+    #[mz_ore::test]
+    fn regexp_split_to_table_multi_match() {
+        assert_split_to_table_matches_array(
+            "the quick  brown fox",
+            "\\s+",
+            &["the", "quick", "brown", "fox"],
+        );
+        assert_split_to_table_matches_array("no match", "nope", &["no match"]);
+    }
+
+    #[mz_ore::test]
+    fn regexp_split_to_table_empty_pattern() {
+        assert_split_to_table_matches_array("hello", "", &["h", "e", "l", "l", "o"]);
+    }
+
+    /// Evaluates `func` on `datums`, returning the first column of each output
+    /// row rendered as a string.
+    fn eval_table_func(func: &TableFunc, datums: &[Datum]) -> Result<Vec<String>, EvalError> {
+        let arena = RowArena::new();
+        let rows = func.eval(datums, &arena)?;
+        Ok(rows
+            .map(|(row, diff)| {
+                assert_eq!(diff, 1);
+                row.unpack_first().to_string()
+            })
+            .collect())
+    }
+
+    #[mz_ore::test]
+    fn generate_series_int64() {
+        let func = TableFunc::GenerateSeriesInt64;
+        let series = |start, stop, step| {
+            eval_table_func(
+                &func,
+                &[Datum::Int64(start), Datum::Int64(stop), Datum::Int64(step)],
+            )
+        };
+
+        assert_eq!(series(1, 7, 3).unwrap(), ["1", "4", "7"]);
+        assert_eq!(series(3, 1, -1).unwrap(), ["3", "2", "1"]);
+        // A step pointing away from `stop` produces no rows.
+        assert_eq!(series(1, 3, -1).unwrap(), Vec::<String>::new());
+        assert_eq!(series(3, 1, 1).unwrap(), Vec::<String>::new());
+        assert!(matches!(
+            series(1, 3, 0),
+            Err(EvalError::InvalidParameterValue(_))
+        ));
+    }
+
+    #[mz_ore::test]
+    fn generate_series_timestamp() {
+        let func = TableFunc::GenerateSeriesTimestamp;
+        let ts = |month, day| {
+            let ts = NaiveDate::from_ymd_opt(2021, month, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            Datum::from(CheckedTimestamp::from_timestamplike(ts).unwrap())
+        };
+        let series =
+            |start, stop, step| eval_table_func(&func, &[start, stop, Datum::Interval(step)]);
+
+        assert_eq!(
+            series(ts(1, 31), ts(4, 30), Interval::new(1, 0, 0)).unwrap(),
+            [
+                "2021-01-31 00:00:00",
+                "2021-02-28 00:00:00",
+                "2021-03-28 00:00:00",
+                "2021-04-28 00:00:00"
+            ]
+        );
+        assert_eq!(
+            series(ts(1, 3), ts(1, 1), Interval::new(0, -1, 0)).unwrap(),
+            [
+                "2021-01-03 00:00:00",
+                "2021-01-02 00:00:00",
+                "2021-01-01 00:00:00"
+            ]
+        );
+        // A step pointing away from `stop` produces no rows.
+        assert_eq!(
+            series(ts(1, 3), ts(1, 1), Interval::new(0, 1, 0)).unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(matches!(
+            series(ts(1, 1), ts(1, 3), Interval::new(0, 0, 0)),
+            Err(EvalError::InvalidParameterValue(_))
+        ));
+    }
 }
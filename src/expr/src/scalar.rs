@@ -844,6 +844,31 @@ impl MirScalarExpr {
                                     ),
                                 };
                             }
+                        } else if matches!(
+                            func,
+                            BinaryFunc::JsonbPathMatchExists | BinaryFunc::JsonbPathMatchPredicate
+                        ) && expr2.is_literal()
+                        {
+                            // Parse the path once rather than once per row.
+                            let source = expr2.as_literal_str().unwrap();
+                            *e = match func::jsonpath::JsonPath::parse(source) {
+                                Ok(path) => {
+                                    let func = if *func == BinaryFunc::JsonbPathMatchExists {
+                                        UnaryFunc::JsonbPathMatchExists(func::JsonbPathMatchExists(
+                                            path,
+                                        ))
+                                    } else {
+                                        UnaryFunc::JsonbPathMatchPredicate(
+                                            func::JsonbPathMatchPredicate(path),
+                                        )
+                                    };
+                                    expr1.take().call_unary(func)
+                                }
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
                         } else if *func == BinaryFunc::ExtractInterval && expr1.is_literal() {
                             let units = expr1.as_literal_str().unwrap();
                             *e = match units.parse::<DateTimeUnits>() {
@@ -2412,7 +2437,18 @@ pub enum EvalError {
     },
     InvalidBase64Equals,
     InvalidBase64Symbol(char),
+    InvalidBase64SymbolAt {
+        symbol: char,
+        /// The byte offset of `symbol` in the input.
+        offset: usize,
+    },
     InvalidBase64EndSequence,
+    InvalidBase32Symbol {
+        symbol: char,
+        /// The byte offset of `symbol` in the input.
+        offset: usize,
+    },
+    InvalidBase32EndSequence,
     InvalidTimezone(String),
     InvalidTimezoneInterval,
     InvalidTimezoneConversion,
@@ -2460,6 +2496,9 @@ pub enum EvalError {
         length: usize,
     },
     MultidimensionalArrayRemovalNotSupported,
+    /// Arrays with the same number of dimensions whose elements have
+    /// different dimensions, e.g. `int[1][2] || int[1][3]`.
+    IncompatibleArrayElementDimensions,
     IncompatibleArrayDimensions {
         dims: Option<(usize, usize)>,
     },
@@ -2546,7 +2585,20 @@ impl fmt::Display for EvalError {
                 "invalid symbol \"{}\" found while decoding base64 sequence",
                 c.escape_default()
             ),
+            EvalError::InvalidBase64SymbolAt { symbol, offset } => write!(
+                f,
+                "invalid symbol \"{}\" found while decoding base64 sequence at offset {}",
+                symbol.escape_default(),
+                offset
+            ),
             EvalError::InvalidBase64EndSequence => f.write_str("invalid base64 end sequence"),
+            EvalError::InvalidBase32Symbol { symbol, offset } => write!(
+                f,
+                "invalid symbol \"{}\" found while decoding base32 sequence at offset {}",
+                symbol.escape_default(),
+                offset
+            ),
+            EvalError::InvalidBase32EndSequence => f.write_str("invalid base32 end sequence"),
             EvalError::InvalidJsonbCast { from, to } => {
                 write!(f, "cannot cast jsonb {} to type {}", from, to)
             }
@@ -2648,7 +2700,8 @@ impl fmt::Display for EvalError {
                     "removing elements from multidimensional arrays is not supported"
                 )
             }
-            EvalError::IncompatibleArrayDimensions { dims: _ } => {
+            EvalError::IncompatibleArrayDimensions { dims: _ }
+            | EvalError::IncompatibleArrayElementDimensions => {
                 write!(f, "cannot concatenate incompatible arrays")
             }
             EvalError::TypeFromOid(msg) => write!(f, "{msg}"),
@@ -2694,6 +2747,10 @@ impl fmt::Display for EvalError {
 impl EvalError {
     pub fn detail(&self) -> Option<String> {
         match self {
+            EvalError::IncompatibleArrayElementDimensions => Some(
+                "Arrays with differing element dimensions are not compatible for concatenation."
+                    .to_string(),
+            ),
             EvalError::IncompatibleArrayDimensions { dims: None } => Some(
                 "Arrays with differing dimensions are not compatible for concatenation."
                     .to_string(),
@@ -2714,7 +2771,7 @@ impl EvalError {
 
     pub fn hint(&self) -> Option<String> {
         match self {
-            EvalError::InvalidBase64EndSequence => Some(
+            EvalError::InvalidBase64EndSequence | EvalError::InvalidBase32EndSequence => Some(
                 "Input data is missing padding, is truncated, or is otherwise corrupted.".into(),
             ),
             EvalError::LikeEscapeTooLong => {
@@ -2848,7 +2905,20 @@ impl RustType<ProtoEvalError> for EvalError {
             }),
             EvalError::InvalidBase64Equals => InvalidBase64Equals(()),
             EvalError::InvalidBase64Symbol(sym) => InvalidBase64Symbol(sym.into_proto()),
+            EvalError::InvalidBase64SymbolAt { symbol, offset } => {
+                InvalidBase64SymbolAt(ProtoInvalidBase64Symbol {
+                    symbol: symbol.into_proto(),
+                    offset: offset.into_proto(),
+                })
+            }
             EvalError::InvalidBase64EndSequence => InvalidBase64EndSequence(()),
+            EvalError::InvalidBase32Symbol { symbol, offset } => {
+                InvalidBase32Symbol(ProtoInvalidBase32Symbol {
+                    symbol: symbol.into_proto(),
+                    offset: offset.into_proto(),
+                })
+            }
+            EvalError::InvalidBase32EndSequence => InvalidBase32EndSequence(()),
             EvalError::InvalidTimezone(tz) => InvalidTimezone(tz.clone()),
             EvalError::InvalidTimezoneInterval => InvalidTimezoneInterval(()),
             EvalError::InvalidTimezoneConversion => InvalidTimezoneConversion(()),
@@ -2916,6 +2986,7 @@ impl RustType<ProtoEvalError> for EvalError {
                     dims: dims.into_proto(),
                 })
             }
+            EvalError::IncompatibleArrayElementDimensions => IncompatibleArrayElementDimensions(()),
             EvalError::TypeFromOid(v) => TypeFromOid(v.clone()),
             EvalError::InvalidRange(error) => InvalidRange(error.into_proto()),
             EvalError::InvalidRoleId(v) => InvalidRoleId(v.clone()),
@@ -2984,7 +3055,16 @@ impl RustType<ProtoEvalError> for EvalError {
                 }),
                 InvalidBase64Equals(()) => Ok(EvalError::InvalidBase64Equals),
                 InvalidBase64Symbol(v) => char::from_proto(v).map(EvalError::InvalidBase64Symbol),
+                InvalidBase64SymbolAt(v) => Ok(EvalError::InvalidBase64SymbolAt {
+                    symbol: char::from_proto(v.symbol)?,
+                    offset: usize::from_proto(v.offset)?,
+                }),
                 InvalidBase64EndSequence(()) => Ok(EvalError::InvalidBase64EndSequence),
+                InvalidBase32Symbol(v) => Ok(EvalError::InvalidBase32Symbol {
+                    symbol: char::from_proto(v.symbol)?,
+                    offset: usize::from_proto(v.offset)?,
+                }),
+                InvalidBase32EndSequence(()) => Ok(EvalError::InvalidBase32EndSequence),
                 InvalidTimezone(v) => Ok(EvalError::InvalidTimezone(v)),
                 InvalidTimezoneInterval(()) => Ok(EvalError::InvalidTimezoneInterval),
                 InvalidTimezoneConversion(()) => Ok(EvalError::InvalidTimezoneConversion),
@@ -3040,6 +3120,9 @@ impl RustType<ProtoEvalError> for EvalError {
                 IncompatibleArrayDimensions(v) => Ok(EvalError::IncompatibleArrayDimensions {
                     dims: v.dims.into_rust()?,
                 }),
+                IncompatibleArrayElementDimensions(()) => {
+                    Ok(EvalError::IncompatibleArrayElementDimensions)
+                }
                 TypeFromOid(v) => Ok(EvalError::TypeFromOid(v)),
                 InvalidRange(e) => Ok(EvalError::InvalidRange(e.into_rust()?)),
                 InvalidRoleId(v) => Ok(EvalError::InvalidRoleId(v)),
@@ -3217,6 +3300,33 @@ mod tests {
         }
     }
 
+    /// Errors are persisted, so the encoding of existing variants must not
+    /// change.
+    #[mz_ore::test]
+    fn test_invalid_base64_symbol_encoding() {
+        use proto_eval_error::Kind;
+
+        let err = EvalError::InvalidBase64Symbol('@');
+        assert_eq!(
+            err.into_proto().kind,
+            Some(Kind::InvalidBase64Symbol(u32::from('@')))
+        );
+
+        let err = EvalError::InvalidBase64SymbolAt {
+            symbol: '@',
+            offset: 3,
+        };
+        assert_eq!(
+            err.into_proto().kind,
+            Some(Kind::InvalidBase64SymbolAt(
+                proto_eval_error::ProtoInvalidBase64Symbol {
+                    symbol: u32::from('@'),
+                    offset: 3,
+                }
+            ))
+        );
+    }
+
     proptest! {
         #[mz_ore::test]
         #[cfg_attr(miri, ignore)] // too slow
@@ -21,7 +21,7 @@ use std::{fmt, iter, str};
 use ::encoding::label::encoding_from_whatwg_label;
 use ::encoding::DecoderTrap;
 use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
-use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use chrono_tz::{OffsetComponents, OffsetName};
 use dec::OrderedDecimal;
 use fallible_iterator::FallibleIterator;
 use hmac::{Hmac, Mac};
@@ -31,11 +31,10 @@ use mz_lowertest::MzReflect;
 use mz_ore::cast::{self, CastFrom, ReinterpretCast};
 use mz_ore::fmt::FormatBuffer;
 use mz_ore::lex::LexBuf;
-use mz_ore::option::OptionExt;
 use mz_ore::result::ResultExt;
 use mz_ore::soft_assert_eq_or_log;
+use mz_ore::soft_panic_or_log;
 use mz_ore::str::StrExt;
-use mz_pgrepr::Type;
 use mz_pgtz::timezone::{Timezone, TimezoneSpec};
 use mz_proto::chrono::any_naive_datetime;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
@@ -49,7 +48,9 @@ use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
 use mz_repr::adt::regex::{any_regex, Regex};
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
 use mz_repr::role_id::RoleId;
-use mz_repr::{strconv, ColumnName, ColumnType, Datum, DatumType, Row, RowArena, ScalarType};
+use mz_repr::{
+    strconv, ColumnName, ColumnType, Datum, DatumList, DatumType, Row, RowArena, ScalarType,
+};
 use mz_sql_pretty::pretty_str;
 use num::traits::CheckedNeg;
 use proptest::prelude::*;
@@ -60,7 +61,8 @@ use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512};
 use subtle::ConstantTimeEq;
 
-use crate::scalar::func::format::DateTimeFormat;
+use crate::scalar::func::format::{DateTimeFormat, NumericFormat};
+use crate::scalar::func::jsonpath::{any_json_path, JsonPath};
 use crate::scalar::{
     ProtoBinaryFunc, ProtoUnaryFunc, ProtoUnmaterializableFunc, ProtoVariadicFunc,
 };
@@ -71,6 +73,7 @@ mod macros;
 mod encoding;
 mod format;
 pub(crate) mod impls;
+pub(crate) mod jsonpath;
 
 pub use impls::*;
 use mz_repr::adt::system::Oid;
@@ -92,6 +95,7 @@ pub enum UnmaterializableFunc {
     CurrentUser,
     IsRbacEnabled,
     MzEnvironmentId,
+    MzEnvironmentIdParts,
     MzIsSuperuser,
     MzNow,
     MzRoleOidMemberships,
@@ -102,6 +106,7 @@ pub enum UnmaterializableFunc {
     PgBackendPid,
     PgPostmasterStartTime,
     SessionUser,
+    TimeOfDay,
     Version,
     ViewableVariables,
 }
@@ -130,6 +135,20 @@ impl UnmaterializableFunc {
             UnmaterializableFunc::CurrentUser => ScalarType::String.nullable(false),
             UnmaterializableFunc::IsRbacEnabled => ScalarType::Bool.nullable(false),
             UnmaterializableFunc::MzEnvironmentId => ScalarType::String.nullable(false),
+            UnmaterializableFunc::MzEnvironmentIdParts => ScalarType::Record {
+                fields: vec![
+                    (
+                        ColumnName::from("organization_id"),
+                        ScalarType::Uuid.nullable(false),
+                    ),
+                    (
+                        ColumnName::from("ordinal"),
+                        ScalarType::UInt64.nullable(false),
+                    ),
+                ],
+                custom_id: None,
+            }
+            .nullable(false),
             UnmaterializableFunc::MzIsSuperuser => ScalarType::Bool.nullable(false),
             UnmaterializableFunc::MzNow => ScalarType::MzTimestamp.nullable(false),
             UnmaterializableFunc::MzRoleOidMemberships => ScalarType::Map {
@@ -146,6 +165,7 @@ impl UnmaterializableFunc {
                 ScalarType::TimestampTz { precision: None }.nullable(false)
             }
             UnmaterializableFunc::SessionUser => ScalarType::String.nullable(false),
+            UnmaterializableFunc::TimeOfDay => ScalarType::String.nullable(false),
             UnmaterializableFunc::Version => ScalarType::String.nullable(false),
             UnmaterializableFunc::ViewableVariables => ScalarType::Map {
                 value_type: Box::new(ScalarType::String),
@@ -169,6 +189,7 @@ impl fmt::Display for UnmaterializableFunc {
             UnmaterializableFunc::CurrentUser => f.write_str("current_user"),
             UnmaterializableFunc::IsRbacEnabled => f.write_str("is_rbac_enabled"),
             UnmaterializableFunc::MzEnvironmentId => f.write_str("mz_environment_id"),
+            UnmaterializableFunc::MzEnvironmentIdParts => f.write_str("mz_environment_id_parts"),
             UnmaterializableFunc::MzIsSuperuser => f.write_str("mz_is_superuser"),
             UnmaterializableFunc::MzNow => f.write_str("mz_now"),
             UnmaterializableFunc::MzRoleOidMemberships => f.write_str("mz_role_oid_memberships"),
@@ -179,6 +200,7 @@ impl fmt::Display for UnmaterializableFunc {
             UnmaterializableFunc::PgBackendPid => f.write_str("pg_backend_pid"),
             UnmaterializableFunc::PgPostmasterStartTime => f.write_str("pg_postmaster_start_time"),
             UnmaterializableFunc::SessionUser => f.write_str("session_user"),
+            UnmaterializableFunc::TimeOfDay => f.write_str("timeofday"),
             UnmaterializableFunc::Version => f.write_str("version"),
             UnmaterializableFunc::ViewableVariables => f.write_str("viewable_variables"),
         }
@@ -198,6 +220,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
             UnmaterializableFunc::CurrentUser => CurrentUser(()),
             UnmaterializableFunc::IsRbacEnabled => IsRbacEnabled(()),
             UnmaterializableFunc::MzEnvironmentId => MzEnvironmentId(()),
+            UnmaterializableFunc::MzEnvironmentIdParts => MzEnvironmentIdParts(()),
             UnmaterializableFunc::MzIsSuperuser => MzIsSuperuser(()),
             UnmaterializableFunc::MzNow => MzNow(()),
             UnmaterializableFunc::MzRoleOidMemberships => MzRoleOidMemberships(()),
@@ -208,6 +231,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
             UnmaterializableFunc::PgBackendPid => PgBackendPid(()),
             UnmaterializableFunc::PgPostmasterStartTime => PgPostmasterStartTime(()),
             UnmaterializableFunc::SessionUser => SessionUser(()),
+            UnmaterializableFunc::TimeOfDay => TimeOfDay(()),
             UnmaterializableFunc::Version => Version(()),
         };
         ProtoUnmaterializableFunc { kind: Some(kind) }
@@ -228,6 +252,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
                 CurrentUser(()) => Ok(UnmaterializableFunc::CurrentUser),
                 IsRbacEnabled(()) => Ok(UnmaterializableFunc::IsRbacEnabled),
                 MzEnvironmentId(()) => Ok(UnmaterializableFunc::MzEnvironmentId),
+                MzEnvironmentIdParts(()) => Ok(UnmaterializableFunc::MzEnvironmentIdParts),
                 MzIsSuperuser(()) => Ok(UnmaterializableFunc::MzIsSuperuser),
                 MzNow(()) => Ok(UnmaterializableFunc::MzNow),
                 MzRoleOidMemberships(()) => Ok(UnmaterializableFunc::MzRoleOidMemberships),
@@ -238,6 +263,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
                 PgBackendPid(()) => Ok(UnmaterializableFunc::PgBackendPid),
                 PgPostmasterStartTime(()) => Ok(UnmaterializableFunc::PgPostmasterStartTime),
                 SessionUser(()) => Ok(UnmaterializableFunc::SessionUser),
+                TimeOfDay(()) => Ok(UnmaterializableFunc::TimeOfDay),
                 Version(()) => Ok(UnmaterializableFunc::Version),
             }
         } else {
@@ -738,6 +764,7 @@ fn bit_shift_left_uint64<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(lhs.wrapping_shl(rhs))
 }
 
+/// Shifts right arithmetically, i.e., the sign bit is propagated, as in PostgreSQL.
 // TODO(benesch): remove potentially dangerous usage of `as`.
 #[allow(clippy::as_conversions)]
 fn bit_shift_right_int16<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
@@ -765,11 +792,12 @@ fn bit_shift_right_int64<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(lhs.wrapping_shr(rhs))
 }
 
+/// Shifts right logically, i.e., vacated bits are always filled with zeros.
 // TODO(benesch): remove potentially dangerous usage of `as`.
 #[allow(clippy::as_conversions)]
 fn bit_shift_right_uint16<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     // widen to u32 and then cast back to u16 in order emulate the C promotion rules used in by Postgres
-    // when the rhs in the 16-31 range, e.g. (-32767 >> 17 should evaluate to -1)
+    // when the rhs in the 16-31 range, e.g. (65535 >> 17 should evaluate to 0)
     // see https://github.com/postgres/postgres/blob/REL_14_STABLE/src/backend/utils/adt/int.c#L1460-L1476
     let lhs = a.unwrap_uint16() as u32;
     let rhs = b.unwrap_uint32();
@@ -1333,10 +1361,16 @@ fn power_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError>
             ));
         }
     }
-    if a.is_negative() && b.exponent() < 0 {
-        // Equivalent to PG error:
-        // > a negative number raised to a non-integer power yields a complex result
-        return Err(EvalError::ComplexOutOfRange("pow".to_owned()));
+    if a.is_negative() {
+        // Integers written with trailing zeros (e.g. `3.0`) have a negative exponent, so
+        // only reject `b` if it still has a fractional part once the zeros are stripped.
+        let mut integral_check = b.clone();
+        numeric::cx_datum().reduce(&mut integral_check);
+        if integral_check.exponent() < 0 {
+            // Equivalent to PG error:
+            // > a negative number raised to a non-integer power yields a complex result
+            return Err(EvalError::ComplexOutOfRange("pow".to_owned()));
+        }
     }
     let mut cx = numeric::cx_datum();
     cx.pow(&mut a, &b);
@@ -1364,6 +1398,27 @@ fn get_byte<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     Ok(Datum::from(i32::from(*i)))
 }
 
+/// Returns the `n`th bit of `a`, following PostgreSQL's `get_bit` on `bytea`: bit `n` is bit
+/// `n % 8` of byte `n / 8`, where bit 0 is the least significant bit of its byte. Bits are thus
+/// numbered LSB-first within each byte, but bytes are numbered left-to-right.
+fn get_bit<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let bytes = a.unwrap_bytes();
+    let index = b.unwrap_int32();
+    let err = EvalError::IndexOutOfRange {
+        provided: index,
+        valid_end: last_bit_index(bytes),
+    };
+    let index = usize::try_from(index).map_err(|_| err.clone())?;
+    let byte = bytes.get(index / 8).ok_or(err)?;
+    Ok(Datum::from(i32::from((byte >> (index % 8)) & 1)))
+}
+
+/// Returns the index of the last bit of `bytes`, saturating at `i32::MAX` for byte strings with
+/// more bits than an `i32` can index.
+fn last_bit_index(bytes: &[u8]) -> i32 {
+    i32::try_from(bytes.len().saturating_mul(8)).map_or(i32::MAX, |bits| bits - 1)
+}
+
 pub fn constant_time_eq_bytes<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let a_bytes = a.unwrap_bytes();
     let b_bytes = b.unwrap_bytes();
@@ -1469,6 +1524,30 @@ where
     Datum::String(temp_storage.push_string(fmt.render(ts)))
 }
 
+fn to_char_int64<'a>(
+    n: i64,
+    format: &str,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let fmt = NumericFormat::compile(format)?;
+    Ok(Datum::String(temp_storage.push_string(fmt.render(n)?)))
+}
+
+fn to_number<'a>(s: &str, format: &str) -> Result<Datum<'a>, EvalError> {
+    let fmt = NumericFormat::compile(format)?;
+    Ok(Datum::Numeric(fmt.parse(s)?))
+}
+
+fn to_date<'a>(s: &str, format: &str) -> Result<Datum<'a>, EvalError> {
+    let fmt = DateTimeFormat::compile(format);
+    Ok(Datum::Date(fmt.parse(s)?.date().try_into()?))
+}
+
+fn to_timestamp_format<'a>(s: &str, format: &str) -> Result<Datum<'a>, EvalError> {
+    let fmt = DateTimeFormat::compile(format);
+    Ok(DateTime::from_naive_utc_and_offset(fmt.parse(s)?, Utc).try_into()?)
+}
+
 fn jsonb_get_int64<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
@@ -1477,20 +1556,11 @@ fn jsonb_get_int64<'a>(
 ) -> Datum<'a> {
     let i = b.unwrap_int64();
     match a {
-        Datum::List(list) => {
-            let i = if i >= 0 {
-                usize::cast_from(i.unsigned_abs())
-            } else {
-                // index backwards from the end
-                let i = usize::cast_from(i.unsigned_abs());
-                (list.iter().count()).wrapping_sub(i)
-            };
-            match list.iter().nth(i) {
-                Some(d) if stringify => jsonb_stringify(d, temp_storage),
-                Some(d) => d,
-                None => Datum::Null,
-            }
-        }
+        Datum::List(list) => match jsonb_index_list(list, i) {
+            Some(d) if stringify => jsonb_stringify(d, temp_storage),
+            Some(d) => d,
+            None => Datum::Null,
+        },
         Datum::Map(_) => Datum::Null,
         _ => {
             if i == 0 || i == -1 {
@@ -1507,6 +1577,26 @@ fn jsonb_get_int64<'a>(
     }
 }
 
+/// Returns the element of the JSON array `list` at index `i`, or `None` if `i`
+/// is out of bounds.
+///
+/// Non-negative indices count from the start of the array and negative indices
+/// count backwards from the end, so `-1` refers to the last element. This is
+/// shared by `->` and `#>` so that the two operators can't disagree about how
+/// arrays are indexed.
+fn jsonb_index_list<'a>(list: DatumList<'a>, i: i64) -> Option<Datum<'a>> {
+    let i = if i >= 0 {
+        usize::cast_from(i.unsigned_abs())
+    } else {
+        // Index backwards from the end. Negative indices that reach past the
+        // start of the array wrap around to a huge index, which is then out of
+        // bounds.
+        let i = usize::cast_from(i.unsigned_abs());
+        list.iter().count().wrapping_sub(i)
+    };
+    list.iter().nth(i)
+}
+
 fn jsonb_get_string<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
@@ -1544,19 +1634,10 @@ fn jsonb_get_path<'a>(
                 None => return Datum::Null,
             },
             Datum::List(list) => match strconv::parse_int64(key) {
-                Ok(i) => {
-                    let i = if i >= 0 {
-                        usize::cast_from(i.unsigned_abs())
-                    } else {
-                        // index backwards from the end
-                        let i = usize::cast_from(i.unsigned_abs());
-                        (list.iter().count()).wrapping_sub(i)
-                    };
-                    match list.iter().nth(i) {
-                        Some(e) => e,
-                        None => return Datum::Null,
-                    }
-                }
+                Ok(i) => match jsonb_index_list(list, i) {
+                    Some(e) => e,
+                    None => return Datum::Null,
+                },
                 Err(_) => return Datum::Null,
             },
             _ => return Datum::Null,
@@ -1580,6 +1661,19 @@ fn jsonb_contains_string<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     }
 }
 
+fn jsonb_path_match_exists<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let path = JsonPath::parse(b.unwrap_str())?;
+    Ok(path.exists(a).into())
+}
+
+fn jsonb_path_match_predicate<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let path = JsonPath::parse(b.unwrap_str())?;
+    Ok(match path.predicate_match(a) {
+        Some(matched) => matched.into(),
+        None => Datum::Null,
+    })
+}
+
 fn map_contains_key<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     let map = a.unwrap_map();
     let k = b.unwrap_str(); // Map keys are always text.
@@ -1611,13 +1705,28 @@ fn map_contains_map<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     b.unwrap_map()
         .iter()
         .all(|(b_key, b_val)| {
-            map_a
-                .iter()
-                .any(|(a_key, a_val)| (a_key == b_key) && (a_val == b_val))
+            map_a.iter().any(|(a_key, a_val)| {
+                (a_key == b_key) && datum_eq_ignoring_numeric_scale(a_val, b_val)
+            })
         })
         .into()
 }
 
+/// Reports whether `a` and `b` are equal, treating numerics that differ only in
+/// their scale (e.g. `1.0` and `1.00`) as equal, like PostgreSQL does when
+/// checking containment.
+fn datum_eq_ignoring_numeric_scale(a: Datum, b: Datum) -> bool {
+    match (a, b) {
+        (Datum::Numeric(mut a), Datum::Numeric(mut b)) => {
+            let mut cx = numeric::cx_datum();
+            cx.reduce(&mut a.0);
+            cx.reduce(&mut b.0);
+            a == b
+        }
+        (a, b) => a == b,
+    }
+}
+
 fn map_get_value<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     let target_key = b.unwrap_str();
     match a.unwrap_map().iter().find(|(key, _v)| target_key == *key) {
@@ -1634,7 +1743,7 @@ fn jsonb_contains_jsonb<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
             (Datum::JsonNull, Datum::JsonNull) => true,
             (Datum::False, Datum::False) => true,
             (Datum::True, Datum::True) => true,
-            (Datum::Numeric(a), Datum::Numeric(b)) => a == b,
+            (a @ Datum::Numeric(_), b @ Datum::Numeric(_)) => datum_eq_ignoring_numeric_scale(a, b),
             (Datum::String(a), Datum::String(b)) => a == b,
             (Datum::List(a), Datum::List(b)) => b
                 .iter()
@@ -1676,7 +1785,9 @@ fn jsonb_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> D
             let elems = Some(a).into_iter().chain(list_b.iter());
             temp_storage.make_datum(|packer| packer.push_list(elems))
         }
-        _ => Datum::Null,
+        // Like Postgres, any other combination of values (i.e. scalars and
+        // objects that aren't both objects) is wrapped into a two-element array.
+        (a, b) => temp_storage.make_datum(|packer| packer.push_list([a, b])),
     }
 }
 
@@ -1717,6 +1828,12 @@ fn jsonb_delete_string<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowAren
     }
 }
 
+/// Like `jsonb_pretty`, but uses `b` as the indentation unit.
+fn jsonb_pretty_indent<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    let pretty = JsonbRef::from_datum(a).to_string_pretty_with_indent(b.unwrap_str());
+    Datum::String(temp_storage.push_string(pretty))
+}
+
 fn date_part_interval<'a, D>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError>
 where
     D: DecimalLike + Into<Datum<'static>>,
@@ -1955,22 +2072,37 @@ fn timezone_interval_timestamptz(a: Datum<'_>, b: Datum<'_>) -> Result<Datum<'st
     }
 }
 
+/// Returns the abbreviation, base UTC offset, and DST offset of timezone `a` at the instant `b`.
+///
+/// `a` is parsed like the timezone argument of `timezone`, so fixed offsets follow the POSIX sign
+/// convention and report no DST offset.
 fn timezone_offset<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
     temp_storage: &'a RowArena,
 ) -> Result<Datum<'a>, EvalError> {
-    let tz_str = a.unwrap_str();
-    let tz = match Tz::from_str_insensitive(tz_str) {
-        Ok(tz) => tz,
-        Err(_) => return Err(EvalError::InvalidIanaTimezoneId(tz_str.into())),
+    let tz = parse_timezone(a.unwrap_str(), TimezoneSpec::Posix)?;
+    let (abbreviation, base_utc_offset, dst_offset) = match tz {
+        Timezone::Tz(tz) => {
+            let offset = tz.offset_from_utc_datetime(&b.unwrap_timestamptz().naive_utc());
+            (
+                offset.abbreviation().to_string(),
+                offset.base_utc_offset(),
+                offset.dst_offset(),
+            )
+        }
+        Timezone::FixedOffset(offset) => (
+            offset.to_string(),
+            Duration::try_seconds(offset.local_minus_utc().into())
+                .expect("fixed offsets are less than a day"),
+            Duration::zero(),
+        ),
     };
-    let offset = tz.offset_from_utc_datetime(&b.unwrap_timestamptz().naive_utc());
     Ok(temp_storage.make_datum(|packer| {
         packer.push_list_with(|packer| {
-            packer.push(Datum::from(offset.abbreviation()));
-            packer.push(Datum::from(offset.base_utc_offset()));
-            packer.push(Datum::from(offset.dst_offset()));
+            packer.push(Datum::from(abbreviation.as_str()));
+            packer.push(Datum::from(base_utc_offset));
+            packer.push(Datum::from(dst_offset));
         });
     }))
 }
@@ -1986,6 +2118,62 @@ fn mz_acl_item_contains_privilege(a: Datum<'_>, b: Datum<'_>) -> Result<Datum<'s
     Ok(contains.into())
 }
 
+/// Returns the privileges granted by `a` that are not granted by `b`, formatted the same way as
+/// `mz_aclitem_privileges`.
+fn mz_acl_item_privileges_diff<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    let acl_mode = a
+        .unwrap_mz_acl_item()
+        .acl_mode
+        .difference(b.unwrap_mz_acl_item().acl_mode);
+    Datum::String(temp_storage.push_string(acl_mode.to_string()))
+}
+
+/// Returns the privileges granted by either `a` or `b`, formatted the same way as
+/// `mz_aclitem_privileges`.
+fn mz_acl_item_privileges_union<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    let acl_mode = a
+        .unwrap_mz_acl_item()
+        .acl_mode
+        .union(b.unwrap_mz_acl_item().acl_mode);
+    Datum::String(temp_storage.push_string(acl_mode.to_string()))
+}
+
+/// Returns the privileges granted by `a` that are not granted by `b`, formatted the same way as
+/// `aclitem_privileges`.
+fn acl_item_privileges_diff<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    let acl_mode = a
+        .unwrap_acl_item()
+        .acl_mode
+        .difference(b.unwrap_acl_item().acl_mode);
+    Datum::String(temp_storage.push_string(acl_mode.to_string()))
+}
+
+/// Returns the privileges granted by either `a` or `b`, formatted the same way as
+/// `aclitem_privileges`.
+fn acl_item_privileges_union<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    let acl_mode = a
+        .unwrap_acl_item()
+        .acl_mode
+        .union(b.unwrap_acl_item().acl_mode);
+    Datum::String(temp_storage.push_string(acl_mode.to_string()))
+}
+
 // transliterated from postgres/src/backend/utils/adt/misc.c
 fn parse_ident<'a>(
     a: Datum<'a>,
@@ -2239,6 +2427,10 @@ pub enum BinaryFunc {
     },
     ToCharTimestamp,
     ToCharTimestampTz,
+    ToCharInt64,
+    ToNumber,
+    ToDate,
+    ToTimestampFormat,
     DateBinTimestamp,
     DateBinTimestampTz,
     ExtractInterval,
@@ -2260,6 +2452,7 @@ pub enum BinaryFunc {
     TimezoneIntervalTime,
     TimezoneOffset,
     TextConcat,
+    ConcatBytes,
     JsonbGetInt64 {
         stringify: bool,
     },
@@ -2274,6 +2467,8 @@ pub enum BinaryFunc {
     JsonbContainsJsonb,
     JsonbDeleteInt64,
     JsonbDeleteString,
+    JsonbPathMatchExists,
+    JsonbPathMatchPredicate,
     MapContainsKey,
     MapGetValue,
     MapContainsAllKeys,
@@ -2282,8 +2477,10 @@ pub enum BinaryFunc {
     ConvertFrom,
     Left,
     Position,
+    PositionBytes,
     Right,
     RepeatString,
+    RepeatBytes,
     Trim,
     TrimLeading,
     TrimTrailing,
@@ -2292,6 +2489,8 @@ pub enum BinaryFunc {
         max_layer: usize,
     },
     ArrayContains,
+    ArrayContainsArray,
+    ListContainsList,
     ArrayLength,
     ArrayLower,
     ArrayRemove,
@@ -2305,11 +2504,14 @@ pub enum BinaryFunc {
     DigestBytes,
     MzRenderTypmod,
     Encode,
+    JsonbPrettyIndent,
+    JsonbObjectTwoArray,
     Decode,
     LogNumeric,
     Power,
     PowerNumeric,
     GetByte,
+    GetBit,
     ConstantTimeEqBytes,
     ConstantTimeEqString,
     RangeContainsElem {
@@ -2330,6 +2532,10 @@ pub enum BinaryFunc {
     RangeDifference,
     UuidGenerateV5,
     MzAclItemContainsPrivilege,
+    MzAclItemPrivilegesDiff,
+    MzAclItemPrivilegesUnion,
+    AclItemPrivilegesDiff,
+    AclItemPrivilegesUnion,
     ParseIdent,
     PrettySql,
     RegexpReplace {
@@ -2474,6 +2680,12 @@ impl BinaryFunc {
                 b.unwrap_str(),
                 temp_storage,
             )),
+            BinaryFunc::ToCharInt64 => {
+                to_char_int64(a.unwrap_int64(), b.unwrap_str(), temp_storage)
+            }
+            BinaryFunc::ToNumber => to_number(a.unwrap_str(), b.unwrap_str()),
+            BinaryFunc::ToDate => to_date(a.unwrap_str(), b.unwrap_str()),
+            BinaryFunc::ToTimestampFormat => to_timestamp_format(a.unwrap_str(), b.unwrap_str()),
             BinaryFunc::DateBinTimestamp => date_bin(
                 a.unwrap_interval(),
                 b.unwrap_timestamp(),
@@ -2519,6 +2731,7 @@ impl BinaryFunc {
             BinaryFunc::TimezoneIntervalTime => timezone_interval_time(a, b),
             BinaryFunc::TimezoneOffset => timezone_offset(a, b, temp_storage),
             BinaryFunc::TextConcat => Ok(text_concat_binary(a, b, temp_storage)),
+            BinaryFunc::ConcatBytes => Ok(concat_bytes(a, b, temp_storage)),
             BinaryFunc::JsonbGetInt64 { stringify } => {
                 Ok(jsonb_get_int64(a, b, temp_storage, *stringify))
             }
@@ -2533,6 +2746,8 @@ impl BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => Ok(jsonb_contains_jsonb(a, b)),
             BinaryFunc::JsonbDeleteInt64 => Ok(jsonb_delete_int64(a, b, temp_storage)),
             BinaryFunc::JsonbDeleteString => Ok(jsonb_delete_string(a, b, temp_storage)),
+            BinaryFunc::JsonbPathMatchExists => jsonb_path_match_exists(a, b),
+            BinaryFunc::JsonbPathMatchPredicate => jsonb_path_match_predicate(a, b),
             BinaryFunc::MapContainsKey => Ok(map_contains_key(a, b)),
             BinaryFunc::MapGetValue => Ok(map_get_value(a, b)),
             BinaryFunc::MapContainsAllKeys => Ok(map_contains_all_keys(a, b)),
@@ -2541,9 +2756,12 @@ impl BinaryFunc {
             BinaryFunc::RoundNumeric => round_numeric_binary(a, b),
             BinaryFunc::ConvertFrom => convert_from(a, b),
             BinaryFunc::Encode => encode(a, b, temp_storage),
+            BinaryFunc::JsonbPrettyIndent => Ok(jsonb_pretty_indent(a, b, temp_storage)),
+            BinaryFunc::JsonbObjectTwoArray => jsonb_object_two_array(a, b, temp_storage),
             BinaryFunc::Decode => decode(a, b, temp_storage),
             BinaryFunc::Left => left(a, b),
             BinaryFunc::Position => position(a, b),
+            BinaryFunc::PositionBytes => position_bytes(a, b),
             BinaryFunc::Right => right(a, b),
             BinaryFunc::Trim => Ok(trim(a, b)),
             BinaryFunc::TrimLeading => Ok(trim_leading(a, b)),
@@ -2552,6 +2770,8 @@ impl BinaryFunc {
             BinaryFunc::ListLengthMax { max_layer } => list_length_max(a, b, *max_layer),
             BinaryFunc::ArrayLength => array_length(a, b),
             BinaryFunc::ArrayContains => Ok(array_contains(a, b)),
+            BinaryFunc::ArrayContainsArray => Ok(array_contains_array(a, b)),
+            BinaryFunc::ListContainsList => Ok(list_contains_list(a, b)),
             BinaryFunc::ArrayLower => Ok(array_lower(a, b)),
             BinaryFunc::ArrayRemove => array_remove(a, b, temp_storage),
             BinaryFunc::ArrayUpper => array_upper(a, b),
@@ -2567,7 +2787,9 @@ impl BinaryFunc {
             BinaryFunc::Power => power(a, b),
             BinaryFunc::PowerNumeric => power_numeric(a, b),
             BinaryFunc::RepeatString => repeat_string(a, b, temp_storage),
+            BinaryFunc::RepeatBytes => repeat_bytes(a, b, temp_storage),
             BinaryFunc::GetByte => get_byte(a, b),
+            BinaryFunc::GetBit => get_bit(a, b),
             BinaryFunc::ConstantTimeEqBytes => constant_time_eq_bytes(a, b),
             BinaryFunc::ConstantTimeEqString => constant_time_eq_string(a, b),
             BinaryFunc::RangeContainsElem { elem_type, rev: _ } => Ok(match elem_type {
@@ -2595,6 +2817,14 @@ impl BinaryFunc {
             BinaryFunc::RangeDifference => range_difference(a, b, temp_storage),
             BinaryFunc::UuidGenerateV5 => Ok(uuid_generate_v5(a, b)),
             BinaryFunc::MzAclItemContainsPrivilege => mz_acl_item_contains_privilege(a, b),
+            BinaryFunc::MzAclItemPrivilegesDiff => {
+                Ok(mz_acl_item_privileges_diff(a, b, temp_storage))
+            }
+            BinaryFunc::MzAclItemPrivilegesUnion => {
+                Ok(mz_acl_item_privileges_union(a, b, temp_storage))
+            }
+            BinaryFunc::AclItemPrivilegesDiff => Ok(acl_item_privileges_diff(a, b, temp_storage)),
+            BinaryFunc::AclItemPrivilegesUnion => Ok(acl_item_privileges_union(a, b, temp_storage)),
             BinaryFunc::ParseIdent => parse_ident(a, b, temp_storage),
             BinaryFunc::PrettySql => pretty_sql(a, b, temp_storage),
             BinaryFunc::RegexpReplace { regex } => match regex {
@@ -2615,11 +2845,13 @@ impl BinaryFunc {
             | Gt
             | Gte
             | ArrayContains
+            | ArrayContainsArray
+            | ListContainsList
             // like and regexp produce errors on invalid like-strings or regexes
             | IsLikeMatch { .. }
             | IsRegexpMatch { .. } => ScalarType::Bool.nullable(in_nullable),
 
-            ToCharTimestamp | ToCharTimestampTz | ConvertFrom | Left | Right | Trim
+            ToCharTimestamp | ToCharTimestampTz | ToCharInt64 | ConvertFrom | Left | Right | Trim
             | TrimLeading | TrimTrailing | LikeEscape => ScalarType::String.nullable(in_nullable),
 
             AddInt16 | SubInt16 | MulInt16 | DivInt16 | ModInt16 | BitAndInt16 | BitOrInt16
@@ -2725,12 +2957,17 @@ impl BinaryFunc {
             JsonbGetInt64 { stringify: false }
             | JsonbGetString { stringify: false }
             | JsonbGetPath { stringify: false }
-            | JsonbConcat
             | JsonbDeleteInt64
             | JsonbDeleteString => ScalarType::Jsonb.nullable(true),
 
-            JsonbContainsString | JsonbContainsJsonb | MapContainsKey | MapContainsAllKeys
-            | MapContainsAnyKeys | MapContainsMap => ScalarType::Bool.nullable(in_nullable),
+            JsonbConcat => ScalarType::Jsonb.nullable(in_nullable),
+
+            JsonbContainsString | JsonbContainsJsonb | JsonbPathMatchExists | MapContainsKey
+            | MapContainsAllKeys | MapContainsAnyKeys | MapContainsMap => {
+                ScalarType::Bool.nullable(in_nullable)
+            }
+
+            JsonbPathMatchPredicate => ScalarType::Bool.nullable(true),
 
             MapGetValue => input1_type
                 .scalar_type
@@ -2742,25 +2979,58 @@ impl BinaryFunc {
 
             ListLengthMax { .. } => ScalarType::Int32.nullable(true),
 
-            ArrayArrayConcat | ArrayRemove | ListListConcat | ListElementConcat | ListRemove => {
+            ArrayArrayConcat | ArrayRemove | ListListConcat | ListRemove => {
+                input1_type.scalar_type.without_modifiers().nullable(true)
+            }
+
+            // The planner coerces the element to the list's element type, so a
+            // mismatch here would produce a malformed list at evaluation time.
+            ListElementConcat => {
+                match &input1_type.scalar_type {
+                    ScalarType::List { element_type, .. }
+                        if element_type.base_eq(&input2_type.scalar_type) => {}
+                    _ => soft_panic_or_log!(
+                        "list element concat type mismatch: {:?} || {:?}",
+                        input1_type.scalar_type,
+                        input2_type.scalar_type,
+                    ),
+                }
                 input1_type.scalar_type.without_modifiers().nullable(true)
             }
 
-            ElementListConcat => input2_type.scalar_type.without_modifiers().nullable(true),
+            ElementListConcat => {
+                match &input2_type.scalar_type {
+                    ScalarType::List { element_type, .. }
+                        if element_type.base_eq(&input1_type.scalar_type) => {}
+                    _ => soft_panic_or_log!(
+                        "element list concat type mismatch: {:?} || {:?}",
+                        input1_type.scalar_type,
+                        input2_type.scalar_type,
+                    ),
+                }
+                input2_type.scalar_type.without_modifiers().nullable(true)
+            }
 
             DigestString | DigestBytes => ScalarType::Bytes.nullable(in_nullable),
             Position => ScalarType::Int32.nullable(in_nullable),
+            PositionBytes => ScalarType::Int32.nullable(in_nullable),
             Encode => ScalarType::String.nullable(in_nullable),
+            JsonbPrettyIndent => ScalarType::String.nullable(in_nullable),
+            JsonbObjectTwoArray => ScalarType::Jsonb.nullable(in_nullable),
             Decode => ScalarType::Bytes.nullable(in_nullable),
             Power => ScalarType::Float64.nullable(in_nullable),
             RepeatString => input1_type.scalar_type.nullable(in_nullable),
+            ConcatBytes | RepeatBytes => ScalarType::Bytes.nullable(in_nullable),
 
             AddNumeric | DivNumeric | LogNumeric | ModNumeric | MulNumeric | PowerNumeric
-            | RoundNumeric | SubNumeric => {
+            | RoundNumeric | SubNumeric | ToNumber => {
                 ScalarType::Numeric { max_scale: None }.nullable(in_nullable)
             }
 
-            GetByte => ScalarType::Int32.nullable(in_nullable),
+            ToDate => ScalarType::Date.nullable(in_nullable),
+            ToTimestampFormat => ScalarType::TimestampTz { precision: None }.nullable(in_nullable),
+
+            GetByte | GetBit => ScalarType::Int32.nullable(in_nullable),
 
             ConstantTimeEqBytes | ConstantTimeEqString => {
                 ScalarType::Bool.nullable(in_nullable)
@@ -2786,6 +3056,12 @@ impl BinaryFunc {
             }
 
             MzAclItemContainsPrivilege => ScalarType::Bool.nullable(in_nullable),
+            MzAclItemPrivilegesDiff
+            | MzAclItemPrivilegesUnion
+            | AclItemPrivilegesDiff
+            | AclItemPrivilegesUnion => {
+                ScalarType::String.nullable(in_nullable)
+            }
 
             ParseIdent => ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable),
             PrettySql => ScalarType::String.nullable(in_nullable),
@@ -2922,6 +3198,10 @@ impl BinaryFunc {
             | IsRegexpMatch { .. }
             | ToCharTimestamp
             | ToCharTimestampTz
+            | ToCharInt64
+            | ToNumber
+            | ToDate
+            | ToTimestampFormat
             | ConstantTimeEqBytes
             | ConstantTimeEqString
             | DateBinTimestamp
@@ -2945,8 +3225,10 @@ impl BinaryFunc {
             | TimezoneIntervalTime
             | TimezoneOffset
             | TextConcat
+            | ConcatBytes
             | JsonbContainsString
             | JsonbContainsJsonb
+            | JsonbPathMatchExists
             | MapContainsKey
             | MapContainsAllKeys
             | MapContainsAnyKeys
@@ -2954,13 +3236,17 @@ impl BinaryFunc {
             | ConvertFrom
             | Left
             | Position
+            | PositionBytes
             | Right
             | RepeatString
+            | RepeatBytes
             | Trim
             | TrimLeading
             | TrimTrailing
             | EncodedBytesCharLength
             | ArrayContains
+            | ArrayContainsArray
+            | ListContainsList
             | ArrayRemove
             | ArrayArrayConcat
             | ListListConcat
@@ -2971,11 +3257,14 @@ impl BinaryFunc {
             | DigestBytes
             | MzRenderTypmod
             | Encode
+            | JsonbPrettyIndent
+            | JsonbObjectTwoArray
             | Decode
             | LogNumeric
             | Power
             | PowerNumeric
             | GetByte
+            | GetBit
             | RangeContainsElem { .. }
             | RangeContainsRange { .. }
             | RangeOverlaps
@@ -2989,16 +3278,21 @@ impl BinaryFunc {
             | RangeDifference
             | UuidGenerateV5
             | MzAclItemContainsPrivilege
+            | MzAclItemPrivilegesDiff
+            | MzAclItemPrivilegesUnion
+            | AclItemPrivilegesDiff
+            | AclItemPrivilegesUnion
             | ParseIdent
             | PrettySql
-            | RegexpReplace { .. } => false,
+            | RegexpReplace { .. }
+            | JsonbConcat => false,
 
             JsonbGetInt64 { .. }
             | JsonbGetString { .. }
             | JsonbGetPath { .. }
-            | JsonbConcat
             | JsonbDeleteInt64
             | JsonbDeleteString
+            | JsonbPathMatchPredicate
             | MapGetValue
             | ListLengthMax { .. }
             | ArrayLength
@@ -3116,15 +3410,20 @@ impl BinaryFunc {
             | JsonbContainsString
             | JsonbDeleteInt64
             | JsonbDeleteString
+            | JsonbPathMatchExists
+            | JsonbPathMatchPredicate
             | MapContainsKey
             | MapGetValue
             | MapContainsAllKeys
             | MapContainsAnyKeys
             | MapContainsMap
             | TextConcat
+            | ConcatBytes
             | IsLikeMatch { .. }
             | IsRegexpMatch { .. }
             | ArrayContains
+            | ArrayContainsArray
+            | ListContainsList
             | ArrayLength
             | ArrayLower
             | ArrayUpper
@@ -3145,6 +3444,10 @@ impl BinaryFunc {
             | RangeDifference => true,
             ToCharTimestamp
             | ToCharTimestampTz
+            | ToCharInt64
+            | ToNumber
+            | ToDate
+            | ToTimestampFormat
             | AgeTimestamp
             | AgeTimestampTz
             | DateBinTimestamp
@@ -3171,6 +3474,7 @@ impl BinaryFunc {
             | ConvertFrom
             | Left
             | Position
+            | PositionBytes
             | Right
             | Trim
             | TrimLeading
@@ -3181,17 +3485,25 @@ impl BinaryFunc {
             | DigestBytes
             | MzRenderTypmod
             | Encode
+            | JsonbPrettyIndent
+            | JsonbObjectTwoArray
             | Decode
             | LogNumeric
             | Power
             | PowerNumeric
             | RepeatString
+            | RepeatBytes
             | ArrayRemove
             | ListRemove
             | LikeEscape
             | UuidGenerateV5
             | GetByte
+            | GetBit
             | MzAclItemContainsPrivilege
+            | MzAclItemPrivilegesDiff
+            | MzAclItemPrivilegesUnion
+            | AclItemPrivilegesDiff
+            | AclItemPrivilegesUnion
             | ConstantTimeEqBytes
             | ConstantTimeEqString
             | ParseIdent
@@ -3382,7 +3694,12 @@ impl BinaryFunc {
             BinaryFunc::LikeEscape
             | BinaryFunc::IsLikeMatch { .. }
             | BinaryFunc::IsRegexpMatch { .. } => (false, false),
-            BinaryFunc::ToCharTimestamp | BinaryFunc::ToCharTimestampTz => (false, false),
+            BinaryFunc::ToCharTimestamp
+            | BinaryFunc::ToCharTimestampTz
+            | BinaryFunc::ToCharInt64
+            | BinaryFunc::ToNumber
+            | BinaryFunc::ToDate
+            | BinaryFunc::ToTimestampFormat => (false, false),
             BinaryFunc::DateBinTimestamp | BinaryFunc::DateBinTimestampTz => (true, true),
             BinaryFunc::AgeTimestamp | BinaryFunc::AgeTimestampTz => (true, true),
             // TODO: can these ever be treated as monotone? It's safe to treat the unary versions
@@ -3406,6 +3723,7 @@ impl BinaryFunc {
             | BinaryFunc::TimezoneIntervalTime
             | BinaryFunc::TimezoneOffset => (false, false),
             BinaryFunc::TextConcat
+            | BinaryFunc::ConcatBytes
             | BinaryFunc::JsonbGetInt64 { .. }
             | BinaryFunc::JsonbGetString { .. }
             | BinaryFunc::JsonbGetPath { .. }
@@ -3414,6 +3732,8 @@ impl BinaryFunc {
             | BinaryFunc::JsonbContainsJsonb
             | BinaryFunc::JsonbDeleteInt64
             | BinaryFunc::JsonbDeleteString
+            | BinaryFunc::JsonbPathMatchExists
+            | BinaryFunc::JsonbPathMatchPredicate
             | BinaryFunc::MapContainsKey
             | BinaryFunc::MapGetValue
             | BinaryFunc::MapContainsAllKeys
@@ -3422,14 +3742,18 @@ impl BinaryFunc {
             BinaryFunc::ConvertFrom
             | BinaryFunc::Left
             | BinaryFunc::Position
+            | BinaryFunc::PositionBytes
             | BinaryFunc::Right
             | BinaryFunc::RepeatString
+            | BinaryFunc::RepeatBytes
             | BinaryFunc::Trim
             | BinaryFunc::TrimLeading
             | BinaryFunc::TrimTrailing
             | BinaryFunc::EncodedBytesCharLength
             | BinaryFunc::ListLengthMax { .. }
             | BinaryFunc::ArrayContains
+            | BinaryFunc::ArrayContainsArray
+            | BinaryFunc::ListContainsList
             | BinaryFunc::ArrayLength
             | BinaryFunc::ArrayLower
             | BinaryFunc::ArrayRemove
@@ -3443,10 +3767,13 @@ impl BinaryFunc {
             | BinaryFunc::DigestBytes
             | BinaryFunc::MzRenderTypmod
             | BinaryFunc::Encode
+            | BinaryFunc::JsonbPrettyIndent
+            | BinaryFunc::JsonbObjectTwoArray
             | BinaryFunc::Decode => (false, false),
             // TODO: it may be safe to treat these as monotone.
             BinaryFunc::LogNumeric | BinaryFunc::Power | BinaryFunc::PowerNumeric => (false, false),
             BinaryFunc::GetByte
+            | BinaryFunc::GetBit
             | BinaryFunc::RangeContainsElem { .. }
             | BinaryFunc::RangeContainsRange { .. }
             | BinaryFunc::RangeOverlaps
@@ -3460,6 +3787,10 @@ impl BinaryFunc {
             | BinaryFunc::RangeDifference => (false, false),
             BinaryFunc::UuidGenerateV5 => (false, false),
             BinaryFunc::MzAclItemContainsPrivilege => (false, false),
+            BinaryFunc::MzAclItemPrivilegesDiff => (false, false),
+            BinaryFunc::MzAclItemPrivilegesUnion => (false, false),
+            BinaryFunc::AclItemPrivilegesDiff => (false, false),
+            BinaryFunc::AclItemPrivilegesUnion => (false, false),
             BinaryFunc::ParseIdent => (false, false),
             BinaryFunc::ConstantTimeEqBytes | BinaryFunc::ConstantTimeEqString => (false, false),
             BinaryFunc::PrettySql => (false, false),
@@ -3586,6 +3917,10 @@ impl fmt::Display for BinaryFunc {
             } => f.write_str("~*"),
             BinaryFunc::ToCharTimestamp => f.write_str("tocharts"),
             BinaryFunc::ToCharTimestampTz => f.write_str("tochartstz"),
+            BinaryFunc::ToCharInt64 => f.write_str("tocharint64"),
+            BinaryFunc::ToNumber => f.write_str("to_number"),
+            BinaryFunc::ToDate => f.write_str("to_date"),
+            BinaryFunc::ToTimestampFormat => f.write_str("to_timestamp"),
             BinaryFunc::DateBinTimestamp => f.write_str("bin_unix_epoch_timestamp"),
             BinaryFunc::DateBinTimestampTz => f.write_str("bin_unix_epoch_timestamptz"),
             BinaryFunc::ExtractInterval => f.write_str("extractiv"),
@@ -3607,6 +3942,7 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::TimezoneIntervalTime => f.write_str("timezoneit"),
             BinaryFunc::TimezoneOffset => f.write_str("timezone_offset"),
             BinaryFunc::TextConcat => f.write_str("||"),
+            BinaryFunc::ConcatBytes => f.write_str("||"),
             BinaryFunc::JsonbGetInt64 { stringify: false } => f.write_str("->"),
             BinaryFunc::JsonbGetInt64 { stringify: true } => f.write_str("->>"),
             BinaryFunc::JsonbGetString { stringify: false } => f.write_str("->"),
@@ -3618,6 +3954,8 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb | BinaryFunc::MapContainsMap => f.write_str("@>"),
             BinaryFunc::JsonbDeleteInt64 => f.write_str("-"),
             BinaryFunc::JsonbDeleteString => f.write_str("-"),
+            BinaryFunc::JsonbPathMatchExists => f.write_str("@?"),
+            BinaryFunc::JsonbPathMatchPredicate => f.write_str("@@"),
             BinaryFunc::MapGetValue => f.write_str("->"),
             BinaryFunc::MapContainsAllKeys => f.write_str("?&"),
             BinaryFunc::MapContainsAnyKeys => f.write_str("?|"),
@@ -3625,6 +3963,7 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::ConvertFrom => f.write_str("convert_from"),
             BinaryFunc::Left => f.write_str("left"),
             BinaryFunc::Position => f.write_str("position"),
+            BinaryFunc::PositionBytes => f.write_str("position"),
             BinaryFunc::Right => f.write_str("right"),
             BinaryFunc::Trim => f.write_str("btrim"),
             BinaryFunc::TrimLeading => f.write_str("ltrim"),
@@ -3632,6 +3971,8 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::EncodedBytesCharLength => f.write_str("length"),
             BinaryFunc::ListLengthMax { .. } => f.write_str("list_length_max"),
             BinaryFunc::ArrayContains => f.write_str("array_contains"),
+            BinaryFunc::ArrayContainsArray => f.write_str("@>"),
+            BinaryFunc::ListContainsList => f.write_str("@>"),
             BinaryFunc::ArrayLength => f.write_str("array_length"),
             BinaryFunc::ArrayLower => f.write_str("array_lower"),
             BinaryFunc::ArrayRemove => f.write_str("array_remove"),
@@ -3644,12 +3985,16 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::DigestString | BinaryFunc::DigestBytes => f.write_str("digest"),
             BinaryFunc::MzRenderTypmod => f.write_str("mz_render_typmod"),
             BinaryFunc::Encode => f.write_str("encode"),
+            BinaryFunc::JsonbPrettyIndent => f.write_str("jsonb_pretty"),
+            BinaryFunc::JsonbObjectTwoArray => f.write_str("jsonb_object"),
             BinaryFunc::Decode => f.write_str("decode"),
             BinaryFunc::LogNumeric => f.write_str("log"),
             BinaryFunc::Power => f.write_str("power"),
             BinaryFunc::PowerNumeric => f.write_str("power_numeric"),
             BinaryFunc::RepeatString => f.write_str("repeat"),
+            BinaryFunc::RepeatBytes => f.write_str("repeat"),
             BinaryFunc::GetByte => f.write_str("get_byte"),
+            BinaryFunc::GetBit => f.write_str("get_bit"),
             BinaryFunc::ConstantTimeEqBytes => f.write_str("constant_time_compare_bytes"),
             BinaryFunc::ConstantTimeEqString => f.write_str("constant_time_compare_strings"),
             BinaryFunc::RangeContainsElem { rev, .. } => {
@@ -3669,6 +4014,10 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::RangeDifference => f.write_str("-"),
             BinaryFunc::UuidGenerateV5 => f.write_str("uuid_generate_v5"),
             BinaryFunc::MzAclItemContainsPrivilege => f.write_str("mz_aclitem_contains_privilege"),
+            BinaryFunc::MzAclItemPrivilegesDiff => f.write_str("mz_aclitem_privileges_diff"),
+            BinaryFunc::MzAclItemPrivilegesUnion => f.write_str("mz_aclitem_privileges_union"),
+            BinaryFunc::AclItemPrivilegesDiff => f.write_str("aclitem_privileges_diff"),
+            BinaryFunc::AclItemPrivilegesUnion => f.write_str("aclitem_privileges_union"),
             BinaryFunc::ParseIdent => f.write_str("parse_ident"),
             BinaryFunc::PrettySql => f.write_str("pretty_sql"),
             BinaryFunc::RegexpReplace { regex } => match regex {
@@ -3808,6 +4157,10 @@ impl Arbitrary for BinaryFunc {
                 .boxed(),
             Just(BinaryFunc::ToCharTimestamp).boxed(),
             Just(BinaryFunc::ToCharTimestampTz).boxed(),
+            Just(BinaryFunc::ToCharInt64).boxed(),
+            Just(BinaryFunc::ToNumber).boxed(),
+            Just(BinaryFunc::ToDate).boxed(),
+            Just(BinaryFunc::ToTimestampFormat).boxed(),
             Just(BinaryFunc::DateBinTimestamp).boxed(),
             Just(BinaryFunc::DateBinTimestampTz).boxed(),
             Just(BinaryFunc::ExtractInterval).boxed(),
@@ -3829,6 +4182,7 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::TimezoneIntervalTime).boxed(),
             Just(BinaryFunc::TimezoneOffset).boxed(),
             Just(BinaryFunc::TextConcat).boxed(),
+            Just(BinaryFunc::ConcatBytes).boxed(),
             bool::arbitrary()
                 .prop_map(|stringify| BinaryFunc::JsonbGetInt64 { stringify })
                 .boxed(),
@@ -3843,6 +4197,8 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::JsonbContainsJsonb).boxed(),
             Just(BinaryFunc::JsonbDeleteInt64).boxed(),
             Just(BinaryFunc::JsonbDeleteString).boxed(),
+            Just(BinaryFunc::JsonbPathMatchExists).boxed(),
+            Just(BinaryFunc::JsonbPathMatchPredicate).boxed(),
             Just(BinaryFunc::MapContainsKey).boxed(),
             Just(BinaryFunc::MapGetValue).boxed(),
             Just(BinaryFunc::MapContainsAllKeys).boxed(),
@@ -3851,8 +4207,10 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::ConvertFrom).boxed(),
             Just(BinaryFunc::Left).boxed(),
             Just(BinaryFunc::Position).boxed(),
+            Just(BinaryFunc::PositionBytes).boxed(),
             Just(BinaryFunc::Right).boxed(),
             Just(BinaryFunc::RepeatString).boxed(),
+            Just(BinaryFunc::RepeatBytes).boxed(),
             Just(BinaryFunc::Trim).boxed(),
             Just(BinaryFunc::TrimLeading).boxed(),
             Just(BinaryFunc::TrimTrailing).boxed(),
@@ -3861,6 +4219,8 @@ impl Arbitrary for BinaryFunc {
                 .prop_map(|max_layer| BinaryFunc::ListLengthMax { max_layer })
                 .boxed(),
             Just(BinaryFunc::ArrayContains).boxed(),
+            Just(BinaryFunc::ArrayContainsArray).boxed(),
+            Just(BinaryFunc::ListContainsList).boxed(),
             Just(BinaryFunc::ArrayLength).boxed(),
             Just(BinaryFunc::ArrayLower).boxed(),
             Just(BinaryFunc::ArrayRemove).boxed(),
@@ -3874,6 +4234,8 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::DigestBytes).boxed(),
             Just(BinaryFunc::MzRenderTypmod).boxed(),
             Just(BinaryFunc::Encode).boxed(),
+            Just(BinaryFunc::JsonbPrettyIndent).boxed(),
+            Just(BinaryFunc::JsonbObjectTwoArray).boxed(),
             Just(BinaryFunc::Decode).boxed(),
             Just(BinaryFunc::LogNumeric).boxed(),
             Just(BinaryFunc::Power).boxed(),
@@ -4008,6 +4370,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::IsRegexpMatch { case_insensitive } => IsRegexpMatch(*case_insensitive),
             BinaryFunc::ToCharTimestamp => ToCharTimestamp(()),
             BinaryFunc::ToCharTimestampTz => ToCharTimestampTz(()),
+            BinaryFunc::ToCharInt64 => ToCharInt64(()),
+            BinaryFunc::ToNumber => ToNumber(()),
+            BinaryFunc::ToDate => ToDate(()),
+            BinaryFunc::ToTimestampFormat => ToTimestampFormat(()),
             BinaryFunc::DateBinTimestamp => DateBinTimestamp(()),
             BinaryFunc::DateBinTimestampTz => DateBinTimestampTz(()),
             BinaryFunc::ExtractInterval => ExtractInterval(()),
@@ -4029,6 +4395,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::TimezoneIntervalTime => TimezoneIntervalTime(()),
             BinaryFunc::TimezoneOffset => TimezoneOffset(()),
             BinaryFunc::TextConcat => TextConcat(()),
+            BinaryFunc::ConcatBytes => ConcatBytes(()),
             BinaryFunc::JsonbGetInt64 { stringify } => JsonbGetInt64(*stringify),
             BinaryFunc::JsonbGetString { stringify } => JsonbGetString(*stringify),
             BinaryFunc::JsonbGetPath { stringify } => JsonbGetPath(*stringify),
@@ -4037,6 +4404,8 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => JsonbContainsJsonb(()),
             BinaryFunc::JsonbDeleteInt64 => JsonbDeleteInt64(()),
             BinaryFunc::JsonbDeleteString => JsonbDeleteString(()),
+            BinaryFunc::JsonbPathMatchExists => JsonbPathMatchExists(()),
+            BinaryFunc::JsonbPathMatchPredicate => JsonbPathMatchPredicate(()),
             BinaryFunc::MapContainsKey => MapContainsKey(()),
             BinaryFunc::MapGetValue => MapGetValue(()),
             BinaryFunc::MapContainsAllKeys => MapContainsAllKeys(()),
@@ -4045,14 +4414,18 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::ConvertFrom => ConvertFrom(()),
             BinaryFunc::Left => Left(()),
             BinaryFunc::Position => Position(()),
+            BinaryFunc::PositionBytes => PositionBytes(()),
             BinaryFunc::Right => Right(()),
             BinaryFunc::RepeatString => RepeatString(()),
+            BinaryFunc::RepeatBytes => RepeatBytes(()),
             BinaryFunc::Trim => Trim(()),
             BinaryFunc::TrimLeading => TrimLeading(()),
             BinaryFunc::TrimTrailing => TrimTrailing(()),
             BinaryFunc::EncodedBytesCharLength => EncodedBytesCharLength(()),
             BinaryFunc::ListLengthMax { max_layer } => ListLengthMax(max_layer.into_proto()),
             BinaryFunc::ArrayContains => ArrayContains(()),
+            BinaryFunc::ArrayContainsArray => ArrayContainsArray(()),
+            BinaryFunc::ListContainsList => ListContainsList(()),
             BinaryFunc::ArrayLength => ArrayLength(()),
             BinaryFunc::ArrayLower => ArrayLower(()),
             BinaryFunc::ArrayRemove => ArrayRemove(()),
@@ -4066,11 +4439,14 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::DigestBytes => DigestBytes(()),
             BinaryFunc::MzRenderTypmod => MzRenderTypmod(()),
             BinaryFunc::Encode => Encode(()),
+            BinaryFunc::JsonbPrettyIndent => JsonbPrettyIndent(()),
+            BinaryFunc::JsonbObjectTwoArray => JsonbObjectTwoArray(()),
             BinaryFunc::Decode => Decode(()),
             BinaryFunc::LogNumeric => LogNumeric(()),
             BinaryFunc::Power => Power(()),
             BinaryFunc::PowerNumeric => PowerNumeric(()),
             BinaryFunc::GetByte => GetByte(()),
+            BinaryFunc::GetBit => GetBit(()),
             BinaryFunc::RangeContainsElem { elem_type, rev } => {
                 RangeContainsElem(crate::scalar::proto_binary_func::ProtoRangeContainsInner {
                     elem_type: Some(elem_type.into_proto()),
@@ -4089,6 +4465,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::RangeDifference => RangeDifference(()),
             BinaryFunc::UuidGenerateV5 => UuidGenerateV5(()),
             BinaryFunc::MzAclItemContainsPrivilege => MzAclItemContainsPrivilege(()),
+            BinaryFunc::MzAclItemPrivilegesDiff => MzAclItemPrivilegesDiff(()),
+            BinaryFunc::MzAclItemPrivilegesUnion => MzAclItemPrivilegesUnion(()),
+            BinaryFunc::AclItemPrivilegesDiff => AclItemPrivilegesDiff(()),
+            BinaryFunc::AclItemPrivilegesUnion => AclItemPrivilegesUnion(()),
             BinaryFunc::ParseIdent => ParseIdent(()),
             BinaryFunc::ConstantTimeEqBytes => ConstantTimeEqBytes(()),
             BinaryFunc::ConstantTimeEqString => ConstantTimeEqString(()),
@@ -4223,6 +4603,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 }
                 ToCharTimestamp(()) => Ok(BinaryFunc::ToCharTimestamp),
                 ToCharTimestampTz(()) => Ok(BinaryFunc::ToCharTimestampTz),
+                ToCharInt64(()) => Ok(BinaryFunc::ToCharInt64),
+                ToNumber(()) => Ok(BinaryFunc::ToNumber),
+                ToDate(()) => Ok(BinaryFunc::ToDate),
+                ToTimestampFormat(()) => Ok(BinaryFunc::ToTimestampFormat),
                 DateBinTimestamp(()) => Ok(BinaryFunc::DateBinTimestamp),
                 DateBinTimestampTz(()) => Ok(BinaryFunc::DateBinTimestampTz),
                 ExtractInterval(()) => Ok(BinaryFunc::ExtractInterval),
@@ -4244,6 +4628,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 TimezoneIntervalTime(()) => Ok(BinaryFunc::TimezoneIntervalTime),
                 TimezoneOffset(()) => Ok(BinaryFunc::TimezoneOffset),
                 TextConcat(()) => Ok(BinaryFunc::TextConcat),
+                ConcatBytes(()) => Ok(BinaryFunc::ConcatBytes),
                 JsonbGetInt64(stringify) => Ok(BinaryFunc::JsonbGetInt64 { stringify }),
                 JsonbGetString(stringify) => Ok(BinaryFunc::JsonbGetString { stringify }),
                 JsonbGetPath(stringify) => Ok(BinaryFunc::JsonbGetPath { stringify }),
@@ -4252,6 +4637,8 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 JsonbContainsJsonb(()) => Ok(BinaryFunc::JsonbContainsJsonb),
                 JsonbDeleteInt64(()) => Ok(BinaryFunc::JsonbDeleteInt64),
                 JsonbDeleteString(()) => Ok(BinaryFunc::JsonbDeleteString),
+                JsonbPathMatchExists(()) => Ok(BinaryFunc::JsonbPathMatchExists),
+                JsonbPathMatchPredicate(()) => Ok(BinaryFunc::JsonbPathMatchPredicate),
                 MapContainsKey(()) => Ok(BinaryFunc::MapContainsKey),
                 MapGetValue(()) => Ok(BinaryFunc::MapGetValue),
                 MapContainsAllKeys(()) => Ok(BinaryFunc::MapContainsAllKeys),
@@ -4260,8 +4647,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 ConvertFrom(()) => Ok(BinaryFunc::ConvertFrom),
                 Left(()) => Ok(BinaryFunc::Left),
                 Position(()) => Ok(BinaryFunc::Position),
+                PositionBytes(()) => Ok(BinaryFunc::PositionBytes),
                 Right(()) => Ok(BinaryFunc::Right),
                 RepeatString(()) => Ok(BinaryFunc::RepeatString),
+                RepeatBytes(()) => Ok(BinaryFunc::RepeatBytes),
                 Trim(()) => Ok(BinaryFunc::Trim),
                 TrimLeading(()) => Ok(BinaryFunc::TrimLeading),
                 TrimTrailing(()) => Ok(BinaryFunc::TrimTrailing),
@@ -4270,6 +4659,8 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                     max_layer: max_layer.into_rust()?,
                 }),
                 ArrayContains(()) => Ok(BinaryFunc::ArrayContains),
+                ArrayContainsArray(()) => Ok(BinaryFunc::ArrayContainsArray),
+                ListContainsList(()) => Ok(BinaryFunc::ListContainsList),
                 ArrayLength(()) => Ok(BinaryFunc::ArrayLength),
                 ArrayLower(()) => Ok(BinaryFunc::ArrayLower),
                 ArrayRemove(()) => Ok(BinaryFunc::ArrayRemove),
@@ -4283,11 +4674,14 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 DigestBytes(()) => Ok(BinaryFunc::DigestBytes),
                 MzRenderTypmod(()) => Ok(BinaryFunc::MzRenderTypmod),
                 Encode(()) => Ok(BinaryFunc::Encode),
+                JsonbPrettyIndent(()) => Ok(BinaryFunc::JsonbPrettyIndent),
+                JsonbObjectTwoArray(()) => Ok(BinaryFunc::JsonbObjectTwoArray),
                 Decode(()) => Ok(BinaryFunc::Decode),
                 LogNumeric(()) => Ok(BinaryFunc::LogNumeric),
                 Power(()) => Ok(BinaryFunc::Power),
                 PowerNumeric(()) => Ok(BinaryFunc::PowerNumeric),
                 GetByte(()) => Ok(BinaryFunc::GetByte),
+                GetBit(()) => Ok(BinaryFunc::GetBit),
                 RangeContainsElem(inner) => Ok(BinaryFunc::RangeContainsElem {
                     elem_type: inner
                         .elem_type
@@ -4306,6 +4700,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 RangeDifference(()) => Ok(BinaryFunc::RangeDifference),
                 UuidGenerateV5(()) => Ok(BinaryFunc::UuidGenerateV5),
                 MzAclItemContainsPrivilege(()) => Ok(BinaryFunc::MzAclItemContainsPrivilege),
+                MzAclItemPrivilegesDiff(()) => Ok(BinaryFunc::MzAclItemPrivilegesDiff),
+                MzAclItemPrivilegesUnion(()) => Ok(BinaryFunc::MzAclItemPrivilegesUnion),
+                AclItemPrivilegesDiff(()) => Ok(BinaryFunc::AclItemPrivilegesDiff),
+                AclItemPrivilegesUnion(()) => Ok(BinaryFunc::AclItemPrivilegesUnion),
                 ParseIdent(()) => Ok(BinaryFunc::ParseIdent),
                 ConstantTimeEqBytes(()) => Ok(BinaryFunc::ConstantTimeEqBytes),
                 ConstantTimeEqString(()) => Ok(BinaryFunc::ConstantTimeEqString),
@@ -4533,8 +4931,10 @@ derive_unary!(
     AbsNumeric,
     CastBoolToString,
     CastBoolToStringNonstandard,
+    CastBoolToInt16,
     CastBoolToInt32,
     CastBoolToInt64,
+    CastInt16ToBool,
     CastInt16ToFloat32,
     CastInt16ToFloat64,
     CastInt16ToInt32,
@@ -4694,6 +5094,7 @@ derive_unary!(
     CastPgLegacyCharToChar,
     CastPgLegacyCharToVarChar,
     CastPgLegacyCharToInt32,
+    AsciiPgLegacyChar,
     CastBytesToString,
     CastStringToJsonb,
     CastJsonbToString,
@@ -4704,6 +5105,8 @@ derive_unary!(
     CastJsonbToFloat32,
     CastJsonbToFloat64,
     CastJsonbToNumeric,
+    JsonbPathMatchExists,
+    JsonbPathMatchPredicate,
     CastJsonbToBool,
     CastUuidToString,
     CastRecordToString,
@@ -4725,6 +5128,7 @@ derive_unary!(
     FloorFloat64,
     FloorNumeric,
     Ascii,
+    LastCodePoint,
     BitLengthBytes,
     BitLengthString,
     ByteLengthBytes,
@@ -4752,10 +5156,12 @@ derive_unary!(
     JustifyDays,
     JustifyHours,
     JustifyInterval,
+    IntervalToHumanText,
     JsonbArrayLength,
     JsonbTypeof,
     JsonbStripNulls,
     JsonbPretty,
+    JsonbObjectFlatArray,
     RoundFloat32,
     RoundFloat64,
     RoundNumeric,
@@ -4787,6 +5193,8 @@ derive_unary!(
     Cot,
     Degrees,
     Radians,
+    DegreesNumeric,
+    RadiansNumeric,
     Log10,
     Log10Numeric,
     Ln,
@@ -4878,8 +5286,10 @@ impl Arbitrary for UnaryFunc {
             CastBoolToStringNonstandard::arbitrary()
                 .prop_map_into()
                 .boxed(),
+            CastBoolToInt16::arbitrary().prop_map_into().boxed(),
             CastBoolToInt32::arbitrary().prop_map_into().boxed(),
             CastBoolToInt64::arbitrary().prop_map_into().boxed(),
+            CastInt16ToBool::arbitrary().prop_map_into().boxed(),
             CastInt16ToFloat32::arbitrary().prop_map_into().boxed(),
             CastInt16ToFloat64::arbitrary().prop_map_into().boxed(),
             CastInt16ToInt32::arbitrary().prop_map_into().boxed(),
@@ -5079,6 +5489,7 @@ impl Arbitrary for UnaryFunc {
                 .prop_map_into()
                 .boxed(),
             CastPgLegacyCharToInt32::arbitrary().prop_map_into().boxed(),
+            AsciiPgLegacyChar::arbitrary().prop_map_into().boxed(),
             CastBytesToString::arbitrary().prop_map_into().boxed(),
             CastStringToJsonb::arbitrary().prop_map_into().boxed(),
             CastJsonbToString::arbitrary().prop_map_into().boxed(),
@@ -5089,6 +5500,12 @@ impl Arbitrary for UnaryFunc {
             CastJsonbToFloat32::arbitrary().prop_map_into().boxed(),
             CastJsonbToFloat64::arbitrary().prop_map_into().boxed(),
             CastJsonbToNumeric::arbitrary().prop_map_into().boxed(),
+            any_json_path()
+                .prop_map(|path| UnaryFunc::JsonbPathMatchExists(JsonbPathMatchExists(path)))
+                .boxed(),
+            any_json_path()
+                .prop_map(|path| UnaryFunc::JsonbPathMatchPredicate(JsonbPathMatchPredicate(path)))
+                .boxed(),
             CastJsonbToBool::arbitrary().prop_map_into().boxed(),
             CastUuidToString::arbitrary().prop_map_into().boxed(),
             CastRecordToString::arbitrary().prop_map_into().boxed(),
@@ -5126,6 +5543,7 @@ impl Arbitrary for UnaryFunc {
             FloorFloat64::arbitrary().prop_map_into().boxed(),
             FloorNumeric::arbitrary().prop_map_into().boxed(),
             Ascii::arbitrary().prop_map_into().boxed(),
+            LastCodePoint::arbitrary().prop_map_into().boxed(),
             BitLengthBytes::arbitrary().prop_map_into().boxed(),
             BitLengthString::arbitrary().prop_map_into().boxed(),
             ByteLengthBytes::arbitrary().prop_map_into().boxed(),
@@ -5162,10 +5580,12 @@ impl Arbitrary for UnaryFunc {
             JustifyDays::arbitrary().prop_map_into().boxed(),
             JustifyHours::arbitrary().prop_map_into().boxed(),
             JustifyInterval::arbitrary().prop_map_into().boxed(),
+            IntervalToHumanText::arbitrary().prop_map_into().boxed(),
             JsonbArrayLength::arbitrary().prop_map_into().boxed(),
             JsonbTypeof::arbitrary().prop_map_into().boxed(),
             JsonbStripNulls::arbitrary().prop_map_into().boxed(),
             JsonbPretty::arbitrary().prop_map_into().boxed(),
+            JsonbObjectFlatArray::arbitrary().prop_map_into().boxed(),
             RoundFloat32::arbitrary().prop_map_into().boxed(),
             RoundFloat64::arbitrary().prop_map_into().boxed(),
             RoundNumeric::arbitrary().prop_map_into().boxed(),
@@ -5200,6 +5620,8 @@ impl Arbitrary for UnaryFunc {
             Cot::arbitrary().prop_map_into().boxed(),
             Degrees::arbitrary().prop_map_into().boxed(),
             Radians::arbitrary().prop_map_into().boxed(),
+            DegreesNumeric::arbitrary().prop_map_into().boxed(),
+            RadiansNumeric::arbitrary().prop_map_into().boxed(),
             Log10::arbitrary().prop_map_into().boxed(),
             Log10Numeric::arbitrary().prop_map_into().boxed(),
             Ln::arbitrary().prop_map_into().boxed(),
@@ -5266,8 +5688,10 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::AbsNumeric(_) => AbsNumeric(()),
             UnaryFunc::CastBoolToString(_) => CastBoolToString(()),
             UnaryFunc::CastBoolToStringNonstandard(_) => CastBoolToStringNonstandard(()),
+            UnaryFunc::CastBoolToInt16(_) => CastBoolToInt16(()),
             UnaryFunc::CastBoolToInt32(_) => CastBoolToInt32(()),
             UnaryFunc::CastBoolToInt64(_) => CastBoolToInt64(()),
+            UnaryFunc::CastInt16ToBool(_) => CastInt16ToBool(()),
             UnaryFunc::CastInt16ToFloat32(_) => CastInt16ToFloat32(()),
             UnaryFunc::CastInt16ToFloat64(_) => CastInt16ToFloat64(()),
             UnaryFunc::CastInt16ToInt32(_) => CastInt16ToInt32(()),
@@ -5467,6 +5891,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastPgLegacyCharToChar(_) => CastPgLegacyCharToChar(()),
             UnaryFunc::CastPgLegacyCharToVarChar(_) => CastPgLegacyCharToVarChar(()),
             UnaryFunc::CastPgLegacyCharToInt32(_) => CastPgLegacyCharToInt32(()),
+            UnaryFunc::AsciiPgLegacyChar(_) => AsciiPgLegacyChar(()),
             UnaryFunc::CastBytesToString(_) => CastBytesToString(()),
             UnaryFunc::CastStringToJsonb(_) => CastStringToJsonb(()),
             UnaryFunc::CastJsonbToString(_) => CastJsonbToString(()),
@@ -5477,6 +5902,10 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastJsonbToFloat32(_) => CastJsonbToFloat32(()),
             UnaryFunc::CastJsonbToFloat64(_) => CastJsonbToFloat64(()),
             UnaryFunc::CastJsonbToNumeric(func) => CastJsonbToNumeric(func.0.into_proto()),
+            UnaryFunc::JsonbPathMatchExists(func) => JsonbPathMatchExists(func.0.source.clone()),
+            UnaryFunc::JsonbPathMatchPredicate(func) => {
+                JsonbPathMatchPredicate(func.0.source.clone())
+            }
             UnaryFunc::CastJsonbToBool(_) => CastJsonbToBool(()),
             UnaryFunc::CastUuidToString(_) => CastUuidToString(()),
             UnaryFunc::CastRecordToString(func) => CastRecordToString(func.ty.into_proto()),
@@ -5513,6 +5942,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::FloorFloat64(_) => FloorFloat64(()),
             UnaryFunc::FloorNumeric(_) => FloorNumeric(()),
             UnaryFunc::Ascii(_) => Ascii(()),
+            UnaryFunc::LastCodePoint(_) => LastCodePoint(()),
             UnaryFunc::BitLengthBytes(_) => BitLengthBytes(()),
             UnaryFunc::BitLengthString(_) => BitLengthString(()),
             UnaryFunc::ByteLengthBytes(_) => ByteLengthBytes(()),
@@ -5544,10 +5974,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::JustifyDays(_) => JustifyDays(()),
             UnaryFunc::JustifyHours(_) => JustifyHours(()),
             UnaryFunc::JustifyInterval(_) => JustifyInterval(()),
+            UnaryFunc::IntervalToHumanText(_) => IntervalToHumanText(()),
             UnaryFunc::JsonbArrayLength(_) => JsonbArrayLength(()),
             UnaryFunc::JsonbTypeof(_) => JsonbTypeof(()),
             UnaryFunc::JsonbStripNulls(_) => JsonbStripNulls(()),
             UnaryFunc::JsonbPretty(_) => JsonbPretty(()),
+            UnaryFunc::JsonbObjectFlatArray(_) => JsonbObjectFlatArray(()),
             UnaryFunc::RoundFloat32(_) => RoundFloat32(()),
             UnaryFunc::RoundFloat64(_) => RoundFloat64(()),
             UnaryFunc::RoundNumeric(_) => RoundNumeric(()),
@@ -5581,6 +6013,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::Cot(_) => Cot(()),
             UnaryFunc::Degrees(_) => Degrees(()),
             UnaryFunc::Radians(_) => Radians(()),
+            UnaryFunc::DegreesNumeric(_) => DegreesNumeric(()),
+            UnaryFunc::RadiansNumeric(_) => RadiansNumeric(()),
             UnaryFunc::Log10(_) => Log10(()),
             UnaryFunc::Log10Numeric(_) => Log10Numeric(()),
             UnaryFunc::Ln(_) => Ln(()),
@@ -5662,8 +6096,10 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 AbsNumeric(()) => Ok(impls::AbsNumeric.into()),
                 CastBoolToString(()) => Ok(impls::CastBoolToString.into()),
                 CastBoolToStringNonstandard(()) => Ok(impls::CastBoolToStringNonstandard.into()),
+                CastBoolToInt16(()) => Ok(impls::CastBoolToInt16.into()),
                 CastBoolToInt32(()) => Ok(impls::CastBoolToInt32.into()),
                 CastBoolToInt64(()) => Ok(impls::CastBoolToInt64.into()),
+                CastInt16ToBool(()) => Ok(impls::CastInt16ToBool.into()),
                 CastInt16ToFloat32(()) => Ok(impls::CastInt16ToFloat32.into()),
                 CastInt16ToFloat64(()) => Ok(impls::CastInt16ToFloat64.into()),
                 CastInt16ToInt32(()) => Ok(impls::CastInt16ToInt32.into()),
@@ -5896,6 +6332,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 CastPgLegacyCharToChar(()) => Ok(impls::CastPgLegacyCharToChar.into()),
                 CastPgLegacyCharToVarChar(()) => Ok(impls::CastPgLegacyCharToVarChar.into()),
                 CastPgLegacyCharToInt32(()) => Ok(impls::CastPgLegacyCharToInt32.into()),
+                AsciiPgLegacyChar(()) => Ok(impls::AsciiPgLegacyChar.into()),
                 CastBytesToString(()) => Ok(impls::CastBytesToString.into()),
                 CastStringToJsonb(()) => Ok(impls::CastStringToJsonb.into()),
                 CastJsonbToString(()) => Ok(impls::CastJsonbToString.into()),
@@ -5908,6 +6345,16 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 CastJsonbToNumeric(max_scale) => {
                     Ok(impls::CastJsonbToNumeric(max_scale.into_rust()?).into())
                 }
+                JsonbPathMatchExists(source) => {
+                    let path = JsonPath::parse(&source)
+                        .map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))?;
+                    Ok(impls::JsonbPathMatchExists(path).into())
+                }
+                JsonbPathMatchPredicate(source) => {
+                    let path = JsonPath::parse(&source)
+                        .map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))?;
+                    Ok(impls::JsonbPathMatchPredicate(path).into())
+                }
                 CastJsonbToBool(()) => Ok(impls::CastJsonbToBool.into()),
                 CastUuidToString(()) => Ok(impls::CastUuidToString.into()),
                 CastRecordToString(ty) => Ok(impls::CastRecordToString {
@@ -5972,6 +6419,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 FloorFloat64(_) => Ok(impls::FloorFloat64.into()),
                 FloorNumeric(_) => Ok(impls::FloorNumeric.into()),
                 Ascii(_) => Ok(impls::Ascii.into()),
+                LastCodePoint(()) => Ok(impls::LastCodePoint.into()),
                 BitLengthBytes(_) => Ok(impls::BitLengthBytes.into()),
                 BitLengthString(_) => Ok(impls::BitLengthString.into()),
                 ByteLengthBytes(_) => Ok(impls::ByteLengthBytes.into()),
@@ -6016,10 +6464,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 JustifyDays(()) => Ok(impls::JustifyDays.into()),
                 JustifyHours(()) => Ok(impls::JustifyHours.into()),
                 JustifyInterval(()) => Ok(impls::JustifyInterval.into()),
+                IntervalToHumanText(()) => Ok(impls::IntervalToHumanText.into()),
                 JsonbArrayLength(()) => Ok(impls::JsonbArrayLength.into()),
                 JsonbTypeof(()) => Ok(impls::JsonbTypeof.into()),
                 JsonbStripNulls(()) => Ok(impls::JsonbStripNulls.into()),
                 JsonbPretty(()) => Ok(impls::JsonbPretty.into()),
+                JsonbObjectFlatArray(()) => Ok(impls::JsonbObjectFlatArray.into()),
                 RoundFloat32(()) => Ok(impls::RoundFloat32.into()),
                 RoundFloat64(()) => Ok(impls::RoundFloat64.into()),
                 RoundNumeric(()) => Ok(impls::RoundNumeric.into()),
@@ -6054,6 +6504,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 Cot(()) => Ok(impls::Cot.into()),
                 Degrees(()) => Ok(impls::Degrees.into()),
                 Radians(()) => Ok(impls::Radians.into()),
+                DegreesNumeric(()) => Ok(impls::DegreesNumeric.into()),
+                RadiansNumeric(()) => Ok(impls::RadiansNumeric.into()),
                 Log10(()) => Ok(impls::Log10.into()),
                 Log10Numeric(()) => Ok(impls::Log10Numeric.into()),
                 Ln(()) => Ok(impls::Ln.into()),
@@ -6130,6 +6582,11 @@ fn coalesce<'a>(
     Ok(Datum::Null)
 }
 
+/// Returns the largest non-null argument, or null if all arguments are null.
+///
+/// Arguments are compared with the [`Datum`] ordering, which is the same total order used by
+/// `<`. In particular, floating point `NaN` sorts above every other value, including
+/// `Infinity`, matching PostgreSQL.
 fn greatest<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
@@ -6142,6 +6599,9 @@ fn greatest<'a>(
         .unwrap_or(Datum::Null))
 }
 
+/// Returns the smallest non-null argument, or null if all arguments are null.
+///
+/// See [`greatest`] for the ordering used to compare arguments.
 fn least<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
@@ -6186,6 +6646,12 @@ fn text_concat_binary<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena
     Datum::String(temp_storage.push_string(buf))
 }
 
+fn concat_bytes<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    let mut buf = a.unwrap_bytes().to_vec();
+    buf.extend_from_slice(b.unwrap_bytes());
+    Datum::Bytes(temp_storage.push_bytes(buf))
+}
+
 fn text_concat_variadic<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Datum<'a> {
     let mut buf = String::new();
     for d in datums {
@@ -6213,23 +6679,15 @@ fn text_concat_ws<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Datum
     Datum::String(temp_storage.push_string(buf))
 }
 
+/// Implements `lpad`. There is no `rpad`, so this has no trailing counterpart.
 fn pad_leading<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
 ) -> Result<Datum<'a>, EvalError> {
     let string = datums[0].unwrap_str();
 
-    let len = match usize::try_from(datums[1].unwrap_int32()) {
-        Ok(len) => len,
-        Err(_) => {
-            return Err(EvalError::InvalidParameterValue(
-                "length must be nonnegative".to_owned(),
-            ))
-        }
-    };
-    if len > MAX_STRING_BYTES {
-        return Err(EvalError::LengthTooLarge);
-    }
+    // Like PostgreSQL, treat negative lengths as zero.
+    let len = usize::try_from(datums[1].unwrap_int32()).unwrap_or(0);
 
     let pad_string = if datums.len() == 3 {
         datums[2].unwrap_str()
@@ -6242,13 +6700,17 @@ fn pad_leading<'a>(
         .take(len)
         .fold((0, 0), |acc, char| (acc.0 + 1, acc.1 + char.len_utf8()));
 
-    let mut buf = String::with_capacity(len);
-    if len == end_char {
-        buf.push_str(&string[0..end_char_byte_offset]);
-    } else {
-        buf.extend(pad_string.chars().cycle().take(len - end_char));
-        buf.push_str(string);
+    // Nothing needs to be (or can be) padded, so the result is at most `string`.
+    if len == end_char || pad_string.is_empty() {
+        return Ok(Datum::String(&string[0..end_char_byte_offset]));
     }
+    if len > MAX_STRING_BYTES {
+        return Err(EvalError::LengthTooLarge);
+    }
+
+    let mut buf = String::with_capacity(len);
+    buf.extend(pad_string.chars().cycle().take(len - end_char));
+    buf.push_str(string);
 
     Ok(Datum::String(temp_storage.push_string(buf)))
 }
@@ -6335,6 +6797,9 @@ fn split_part<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
     ))
 }
 
+/// Rewrites `a` to use the default LIKE escape character instead of the escape string `b`.
+///
+/// Like PostgreSQL, `b` must be empty (which disables escaping) or a single character.
 fn like_escape<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
@@ -6553,12 +7018,31 @@ fn repeat_string<'a>(
 ) -> Result<Datum<'a>, EvalError> {
     let len = usize::try_from(count.unwrap_int32()).unwrap_or(0);
     let string = string.unwrap_str();
-    if (len * string.len()) > MAX_STRING_BYTES {
+    if len
+        .checked_mul(string.len())
+        .map_or(true, |total| total > MAX_STRING_BYTES)
+    {
         return Err(EvalError::LengthTooLarge);
     }
     Ok(Datum::String(temp_storage.push_string(string.repeat(len))))
 }
 
+fn repeat_bytes<'a>(
+    bytes: Datum<'a>,
+    count: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let len = usize::try_from(count.unwrap_int32()).unwrap_or(0);
+    let bytes = bytes.unwrap_bytes();
+    if len
+        .checked_mul(bytes.len())
+        .map_or(true, |total| total > MAX_STRING_BYTES)
+    {
+        return Err(EvalError::LengthTooLarge);
+    }
+    Ok(Datum::Bytes(temp_storage.push_bytes(bytes.repeat(len))))
+}
+
 fn replace<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Datum<'a> {
     Datum::String(
         temp_storage.push_string(
@@ -6600,7 +7084,10 @@ fn jsonb_build_object<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
 ) -> Result<Datum<'a>, EvalError> {
-    let mut kvs = datums.chunks(2).collect::<Vec<_>>();
+    // Like PostgreSQL, later keys overwrite earlier ones. Keys are stored sorted, as in every
+    // other `jsonb` object, e.g. those produced by `jsonb_concat`. The sort is stable, so
+    // reversing first means dedup keeps the last value for each key.
+    let mut kvs = datums.chunks(2).rev().collect::<Vec<_>>();
     kvs.sort_by(|kv1, kv2| kv1[0].cmp(&kv2[0]));
     kvs.dedup_by(|kv1, kv2| kv1[0] == kv2[0]);
     temp_storage.try_make_datum(|packer| {
@@ -6622,6 +7109,55 @@ fn jsonb_build_object<'a>(
     })
 }
 
+/// Builds a `jsonb` object from the given key/value pairs, as done by PostgreSQL's `jsonb_object`.
+///
+/// Keys must be non-null strings; later keys overwrite earlier ones. Values are strings, and
+/// `NULL` values become JSON nulls.
+pub(crate) fn jsonb_object_from_pairs<'a>(
+    pairs: impl Iterator<Item = (Datum<'a>, Datum<'a>)>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let mut map = std::collections::BTreeMap::new();
+    for (k, v) in pairs {
+        if k.is_null() {
+            return Err(EvalError::InvalidParameterValue(
+                "null value not allowed for object key".into(),
+            ));
+        }
+        let v = match v {
+            Datum::Null => Datum::JsonNull,
+            d => d,
+        };
+        map.insert(k.unwrap_str(), v);
+    }
+    Ok(temp_storage.make_datum(|packer| {
+        packer.push_dict(map);
+    }))
+}
+
+fn jsonb_object_two_array<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let keys = a.unwrap_array();
+    let values = b.unwrap_array();
+    if keys.dims().len() > 1 || values.dims().len() > 1 {
+        return Err(EvalError::InvalidParameterValue(
+            "wrong number of array subscripts".into(),
+        ));
+    }
+    if keys.elements().iter().count() != values.elements().iter().count() {
+        return Err(EvalError::InvalidParameterValue(
+            "mismatched array dimensions".into(),
+        ));
+    }
+    jsonb_object_from_pairs(
+        keys.elements().iter().zip(values.elements().iter()),
+        temp_storage,
+    )
+}
+
 fn map_build<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Datum<'a> {
     // Collect into a `BTreeMap` to provide the same semantics as it.
     let map: std::collections::BTreeMap<&str, _> = datums
@@ -7044,6 +7580,8 @@ fn make_timestamp<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
     Ok(timestamp.try_into()?)
 }
 
+/// Returns the 1-based *character* index of the first occurrence of `a` in
+/// `b`, or 0 if `a` does not occur in `b`.
 fn position<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let substring: &'a str = a.unwrap_str();
     let string = b.unwrap_str();
@@ -7063,6 +7601,29 @@ fn position<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
+/// Returns the 1-based *byte* index of the first occurrence of `a` in `b`, or 0
+/// if `a` does not occur in `b`.
+fn position_bytes<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let substring = a.unwrap_bytes();
+    let bytes = b.unwrap_bytes();
+    let byte_index = if substring.is_empty() {
+        Some(0)
+    } else {
+        bytes
+            .windows(substring.len())
+            .position(|window| window == substring)
+    };
+
+    match byte_index {
+        Some(byte_index) => {
+            let byte_index = i32::try_from(byte_index)
+                .map_err(|_| EvalError::Int32OutOfRange(byte_index.to_string()))?;
+            Ok(Datum::Int32(byte_index + 1))
+        }
+        None => Ok(Datum::Int32(0)),
+    }
+}
+
 fn left<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let string: &'a str = a.unwrap_str();
     let n = i64::from(b.unwrap_int32());
@@ -7089,6 +7650,9 @@ fn left<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     Ok(Datum::String(&string[..end_in_bytes]))
 }
 
+/// Returns the last `n` characters of `a`. A negative `n` instead returns all
+/// but the first `|n|` characters, matching PostgreSQL. Because of this, the
+/// result is not monotone in `n`, and `right` is not marked as monotone.
 fn right<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let string: &'a str = a.unwrap_str();
     let n = b.unwrap_int32();
@@ -7187,7 +7751,15 @@ fn array_remove<'a>(
         return Err(EvalError::MultidimensionalArrayRemovalNotSupported);
     }
 
+    // Like PostgreSQL, a `NULL` target removes `NULL` elements.
     let elems: Vec<_> = arr.elements().iter().filter(|v| v != &b).collect();
+
+    // Removing every element produces the zero-dimensional empty array, as in
+    // PostgreSQL.
+    if elems.is_empty() {
+        return Ok(temp_storage.try_make_datum(|packer| packer.push_array(&[], elems))?);
+    }
+
     let mut dims = arr.dims().into_iter().collect::<Vec<_>>();
     // This access is safe because `dims` is guaranteed to be non-empty
     dims[0] = ArrayDimension {
@@ -7217,26 +7789,27 @@ fn array_upper<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     )
 }
 
-// TODO(benesch): remove potentially dangerous usage of `as`.
-#[allow(clippy::as_conversions)]
+/// Returns the maximum length of any list found at nesting depth `b` of the list
+/// `a`, where layer 1 is `a` itself.
+///
+/// Layers outside of `[1, max_layer]`, where `max_layer` is the nesting depth of
+/// `a`'s type, are rejected with [`EvalError::InvalidLayer`]. If no list exists at
+/// the requested layer, e.g. because every list on the layer above it is `NULL`
+/// or empty, the result is `NULL`.
 fn list_length_max<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
     max_layer: usize,
 ) -> Result<Datum<'a>, EvalError> {
-    fn max_len_on_layer<'a>(d: Datum<'a>, on_layer: i64) -> Option<usize> {
+    fn max_len_on_layer<'a>(d: Datum<'a>, on_layer: usize) -> Option<usize> {
         match d {
             Datum::List(i) => {
-                let mut i = i.iter();
                 if on_layer > 1 {
-                    let mut max_len = None;
-                    while let Some(Datum::List(i)) = i.next() {
-                        max_len =
-                            std::cmp::max(max_len_on_layer(Datum::List(i), on_layer - 1), max_len);
-                    }
-                    max_len
+                    i.iter()
+                        .filter_map(|d| max_len_on_layer(d, on_layer - 1))
+                        .max()
                 } else {
-                    Some(i.count())
+                    Some(i.iter().count())
                 }
             }
             Datum::Null => None,
@@ -7246,16 +7819,15 @@ fn list_length_max<'a>(
 
     let b = b.unwrap_int64();
 
-    if b as usize > max_layer || b < 1 {
-        Err(EvalError::InvalidLayer { max_layer, val: b })
-    } else {
-        match max_len_on_layer(a, b) {
+    match usize::try_from(b) {
+        Ok(layer) if (1..=max_layer).contains(&layer) => match max_len_on_layer(a, layer) {
             Some(l) => match l.try_into() {
                 Ok(c) => Ok(Datum::Int32(c)),
                 Err(_) => Err(EvalError::Int32OutOfRange(l.to_string())),
             },
             None => Ok(Datum::Null),
-        }
+        },
+        _ => Err(EvalError::InvalidLayer { max_layer, val: b }),
     }
 }
 
@@ -7264,6 +7836,22 @@ fn array_contains<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(array.elements().iter().any(|e| e == a))
 }
 
+/// Reports whether every element of `b` is equal to some element of `a`. Like
+/// PostgreSQL, the arrays' dimensions are ignored, and because NULL is never
+/// equal to anything, `a` never contains an array that has a NULL element.
+fn array_contains_array<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let a = a.unwrap_array().elements();
+    b.unwrap_array()
+        .elements()
+        .iter()
+        .all(|b_elem| {
+            !b_elem.is_null()
+                && a.iter()
+                    .any(|a_elem| datum_eq_ignoring_numeric_scale(a_elem, b_elem))
+        })
+        .into()
+}
+
 fn array_array_concat<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
@@ -7321,7 +7909,7 @@ fn array_array_concat<'a>(
         // arrays.
         Ordering::Equal => {
             if &a_dims[1..] != &b_dims[1..] {
-                return Err(EvalError::IncompatibleArrayDimensions { dims: None });
+                return Err(EvalError::IncompatibleArrayElementDimensions);
             }
             dims = vec![ArrayDimension {
                 lower_bound: a_dims[0].lower_bound,
@@ -7381,6 +7969,23 @@ fn list_list_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena)
     temp_storage.make_datum(|packer| packer.push_list(a.chain(b)))
 }
 
+/// Reports whether every element of `b` is equal to some element of `a`. NULL
+/// elements are treated like they are in [`array_contains_array`], i.e. `a` never
+/// contains a list that has a NULL element. Unlike arrays, which are compared
+/// element-wise regardless of their dimensions, only the top-level elements of
+/// lists are compared, so nested lists must be equal in their entirety.
+fn list_contains_list<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let a = a.unwrap_list();
+    b.unwrap_list()
+        .iter()
+        .all(|b_elem| {
+            !b_elem.is_null()
+                && a.iter()
+                    .any(|a_elem| datum_eq_ignoring_numeric_scale(a_elem, b_elem))
+        })
+        .into()
+}
+
 fn list_element_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
     temp_storage.make_datum(|packer| {
         packer.push_list_with(|packer| {
@@ -7463,15 +8068,7 @@ fn mz_render_typmod<'a>(
     typmod: Datum<'a>,
     temp_storage: &'a RowArena,
 ) -> Result<Datum<'a>, EvalError> {
-    let oid = oid.unwrap_uint32();
-    let typmod = typmod.unwrap_int32();
-    let s = match Type::from_oid_and_typmod(oid, typmod) {
-        Ok(typ) => typ.constraint().display_or("").to_string(),
-        // Match dubious PostgreSQL behavior of outputting the unmodified
-        // `typmod` when positive if the type OID/typmod is invalid.
-        Err(_) if typmod >= 0 => format!("({typmod})"),
-        Err(_) => "".into(),
-    };
+    let s = mz_pgrepr::parse_typmod(oid.unwrap_uint32(), typmod.unwrap_int32());
     Ok(Datum::String(temp_storage.push_string(s)))
 }
 
@@ -7613,6 +8210,102 @@ fn array_fill<'a>(
         .try_make_datum(|packer| packer.push_array(&array_dimensions, vec![fill; fill_count]))?)
 }
 
+/// Extracts `length` bits of `bytes` starting at bit `offset` into a new byte string.
+///
+/// Bits are numbered as in [`get_bit`], so bit `i` of the result is bit `offset + i` of the
+/// input. Unused high bits of the final byte of the result are zero.
+fn bit_substring<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let bytes = datums[0].unwrap_bytes();
+    let offset = datums[1].unwrap_int32();
+    let length = datums[2].unwrap_int32();
+    let bit_len = bytes.len().saturating_mul(8);
+    let err = |provided| EvalError::IndexOutOfRange {
+        provided,
+        valid_end: last_bit_index(bytes),
+    };
+    let start = usize::try_from(offset)
+        .ok()
+        .filter(|start| *start <= bit_len)
+        .ok_or_else(|| err(offset))?;
+    let length = usize::try_from(length).map_err(|_| {
+        EvalError::InvalidParameterValue("negative substring length not allowed".to_owned())
+    })?;
+    if length > bit_len - start {
+        return Err(err(offset.saturating_add(datums[2].unwrap_int32()) - 1));
+    }
+
+    let first = start / 8;
+    let shift = start % 8;
+    let mut out = vec![0u8; (length + 7) / 8];
+    for (i, byte) in out.iter_mut().enumerate() {
+        let lo = bytes[first + i] >> shift;
+        let hi = match bytes.get(first + i + 1) {
+            Some(next) if shift != 0 => next << (8 - shift),
+            _ => 0,
+        };
+        *byte = lo | hi;
+    }
+    if length % 8 != 0 {
+        if let Some(last) = out.last_mut() {
+            *last &= (1 << (length % 8)) - 1;
+        }
+    }
+    Ok(Datum::Bytes(temp_storage.push_bytes(out)))
+}
+
+fn array_slice<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let array = match datums[0] {
+        Datum::Null => return Ok(Datum::Null),
+        o => o.unwrap_array(),
+    };
+
+    let mut dims = array.dims().into_iter().collect::<Vec<_>>();
+    // Zero-dimensional arrays are empty by definition
+    let Some(outer) = dims.first().copied() else {
+        return Ok(datums[0]);
+    };
+
+    // Like PostgreSQL's slice syntax, a `NULL` bound extends the slice to the start or end of the
+    // array, and bounds outside of the array are clamped to it.
+    let (array_lower, array_upper) = outer.dimension_bounds();
+    let lower = match datums[1] {
+        Datum::Null => array_lower,
+        d => std::cmp::max(isize::cast_from(d.unwrap_int32()), array_lower),
+    };
+    let upper = match datums[2] {
+        Datum::Null => array_upper,
+        d => std::cmp::min(isize::cast_from(d.unwrap_int32()), array_upper),
+    };
+
+    if lower > upper {
+        return Ok(temp_storage.try_make_datum(|packer| packer.push_array(&[], None::<Datum>))?);
+    }
+
+    // Slicing the outermost dimension keeps every element of the inner dimensions. As in
+    // PostgreSQL, the result's dimensions all start at 1.
+    let stride: usize = dims[1..].iter().map(|d| d.length).product();
+    let skip = usize::try_from(lower - outer.lower_bound)
+        .expect("lower bound is clamped to the array's lower bound");
+    let length = usize::try_from(upper - lower + 1).expect("lower bound is at most upper bound");
+    for dim in dims.iter_mut() {
+        dim.lower_bound = 1;
+    }
+    dims[0].length = length;
+
+    let elems = array
+        .elements()
+        .iter()
+        .skip(skip * stride)
+        .take(length * stride);
+    Ok(temp_storage.try_make_datum(|packer| packer.push_array(&dims, elems))?)
+}
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub enum VariadicFunc {
     Coalesce,
@@ -7673,6 +8366,8 @@ pub enum VariadicFunc {
     ArrayFill {
         elem_type: ScalarType,
     },
+    ArraySlice,
+    BitSubstring,
     TimezoneTime,
     RegexpSplitToArray,
     RegexpReplace,
@@ -7761,6 +8456,8 @@ impl VariadicFunc {
             VariadicFunc::MakeMzAclItem => make_mz_acl_item(&ds),
             VariadicFunc::ArrayPosition => array_position(&ds),
             VariadicFunc::ArrayFill { .. } => array_fill(&ds, temp_storage),
+            VariadicFunc::ArraySlice => array_slice(&ds, temp_storage),
+            VariadicFunc::BitSubstring => bit_substring(&ds, temp_storage),
             VariadicFunc::TimezoneTime => parse_timezone(ds[0].unwrap_str(), TimezoneSpec::Posix)
                 .map(|tz| {
                     timezone_time(
@@ -7823,6 +8520,8 @@ impl VariadicFunc {
             | VariadicFunc::MakeMzAclItem
             | VariadicFunc::ArrayPosition
             | VariadicFunc::ArrayFill { .. }
+            | VariadicFunc::ArraySlice
+            | VariadicFunc::BitSubstring
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
             | VariadicFunc::RegexpReplace => false,
@@ -7925,6 +8624,11 @@ impl VariadicFunc {
             ArrayFill { elem_type } => {
                 ScalarType::Array(Box::new(elem_type.clone())).nullable(false)
             }
+            ArraySlice => input_types[0]
+                .scalar_type
+                .clone()
+                .nullable(input_types[0].nullable),
+            BitSubstring => ScalarType::Bytes.nullable(in_nullable),
             TimezoneTime => ScalarType::Time.nullable(in_nullable),
             RegexpSplitToArray => {
                 ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable)
@@ -7957,6 +8661,7 @@ impl VariadicFunc {
                 | VariadicFunc::RangeCreate { .. }
                 | VariadicFunc::ArrayPosition
                 | VariadicFunc::ArrayFill { .. }
+                | VariadicFunc::ArraySlice
         )
     }
 
@@ -7999,6 +8704,8 @@ impl VariadicFunc {
             | MakeMzAclItem
             | ArrayPosition
             | ArrayFill { .. }
+            | ArraySlice
+            | BitSubstring
             | TimezoneTime
             | RegexpSplitToArray
             | RegexpReplace => false,
@@ -8108,6 +8815,8 @@ impl VariadicFunc {
             | VariadicFunc::Translate
             | VariadicFunc::ArrayPosition
             | VariadicFunc::ArrayFill { .. }
+            | VariadicFunc::ArraySlice
+            | VariadicFunc::BitSubstring
             | VariadicFunc::DateDiffTimestamp
             | VariadicFunc::DateDiffTimestampTz
             | VariadicFunc::DateDiffDate
@@ -8169,6 +8878,8 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::MakeMzAclItem => f.write_str("make_mz_aclitem"),
             VariadicFunc::ArrayPosition => f.write_str("array_position"),
             VariadicFunc::ArrayFill { .. } => f.write_str("array_fill"),
+            VariadicFunc::ArraySlice => f.write_str("array_slice"),
+            VariadicFunc::BitSubstring => f.write_str("bit_substring"),
             VariadicFunc::TimezoneTime => f.write_str("timezonet"),
             VariadicFunc::RegexpSplitToArray => f.write_str("regexp_split_to_array"),
             VariadicFunc::RegexpReplace => f.write_str("regexp_replace"),
@@ -8242,6 +8953,8 @@ impl Arbitrary for VariadicFunc {
             ScalarType::arbitrary()
                 .prop_map(|elem_type| VariadicFunc::ArrayFill { elem_type })
                 .boxed(),
+            Just(VariadicFunc::ArraySlice).boxed(),
+            Just(VariadicFunc::BitSubstring).boxed(),
         ])
     }
 }
@@ -8291,6 +9004,8 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::MakeMzAclItem => MakeMzAclItem(()),
             VariadicFunc::ArrayPosition => ArrayPosition(()),
             VariadicFunc::ArrayFill { elem_type } => ArrayFill(elem_type.into_proto()),
+            VariadicFunc::ArraySlice => ArraySlice(()),
+            VariadicFunc::BitSubstring => BitSubstring(()),
             VariadicFunc::TimezoneTime => TimezoneTime(()),
             VariadicFunc::RegexpSplitToArray => RegexpSplitToArray(()),
             VariadicFunc::RegexpReplace => RegexpReplace(()),
@@ -8357,6 +9072,8 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 ArrayFill(elem_type) => Ok(VariadicFunc::ArrayFill {
                     elem_type: elem_type.into_rust()?,
                 }),
+                ArraySlice(()) => Ok(VariadicFunc::ArraySlice),
+                BitSubstring(()) => Ok(VariadicFunc::BitSubstring),
                 TimezoneTime(()) => Ok(VariadicFunc::TimezoneTime),
                 RegexpSplitToArray(()) => Ok(VariadicFunc::RegexpSplitToArray),
                 RegexpReplace(()) => Ok(VariadicFunc::RegexpReplace),
@@ -8433,6 +9150,58 @@ mod test {
             .unwrap()
     }
 
+    /// Generates dividends that are biased towards the extremes of `$t`.
+    macro_rules! div_mod_dividend {
+        ($t:ty) => {
+            proptest::strategy::Union::new(vec![
+                Just(<$t>::MIN).boxed(),
+                Just(<$t>::MAX).boxed(),
+                any::<$t>().boxed(),
+            ])
+        };
+    }
+
+    /// Generates non-zero divisors, biased towards `1`, `MAX` and, for signed
+    /// types, `-1`, which overflows when dividing `MIN`.
+    macro_rules! div_mod_divisor {
+        ($t:ty) => {
+            proptest::strategy::Union::new(vec![
+                Just(<$t>::try_from(-1_i8).unwrap_or(<$t>::MAX)).boxed(),
+                Just(<$t>::MAX).boxed(),
+                Just(1).boxed(),
+                any::<$t>()
+                    .prop_filter("divisor must be non-zero", |b| *b != 0)
+                    .boxed(),
+            ])
+        };
+    }
+
+    /// Asserts that `div(a, b) * b + mod(a, b) == a` whenever both `div` and
+    /// `mod` succeed, and that `div` only fails when the quotient overflows.
+    macro_rules! check_div_mod {
+        ($a:expr, $b:expr, $div:ident, $mod:ident, $unwrap:ident) => {{
+            let (a, b) = ($a, $b);
+            let r = $mod(Datum::from(a), Datum::from(b)).unwrap().$unwrap();
+            match $div(Datum::from(a), Datum::from(b)) {
+                Ok(q) => {
+                    let q = q.$unwrap();
+                    assert_eq!(
+                        q.checked_mul(b).and_then(|qb| qb.checked_add(r)),
+                        Some(a),
+                        "{}({a}, {b}) = {q}, {}({a}, {b}) = {r}",
+                        stringify!($div),
+                        stringify!($mod),
+                    );
+                }
+                Err(e) => assert!(
+                    a.checked_div(b).is_none(),
+                    "{}({a}, {b}) failed: {e}",
+                    stringify!($div),
+                ),
+            }
+        }};
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(4096))]
 
@@ -8467,6 +9236,256 @@ mod test {
             assert!(actual.is_ok());
             assert_eq!(actual.unwrap(), expect);
         }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn div_mod_identity(
+            (a_i16, b_i16) in (div_mod_dividend!(i16), div_mod_divisor!(i16)),
+            (a_i32, b_i32) in (div_mod_dividend!(i32), div_mod_divisor!(i32)),
+            (a_i64, b_i64) in (div_mod_dividend!(i64), div_mod_divisor!(i64)),
+            (a_u16, b_u16) in (div_mod_dividend!(u16), div_mod_divisor!(u16)),
+            (a_u32, b_u32) in (div_mod_dividend!(u32), div_mod_divisor!(u32)),
+            (a_u64, b_u64) in (div_mod_dividend!(u64), div_mod_divisor!(u64)),
+        ) {
+            check_div_mod!(a_i16, b_i16, div_int16, mod_int16, unwrap_int16);
+            check_div_mod!(a_i32, b_i32, div_int32, mod_int32, unwrap_int32);
+            check_div_mod!(a_i64, b_i64, div_int64, mod_int64, unwrap_int64);
+            check_div_mod!(a_u16, b_u16, div_uint16, mod_uint16, unwrap_uint16);
+            check_div_mod!(a_u32, b_u32, div_uint32, mod_uint32, unwrap_uint32);
+            check_div_mod!(a_u64, b_u64, div_uint64, mod_uint64, unwrap_uint64);
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn degrees_radians_roundtrip(x in -1e9f64..1e9f64) {
+            let actual = degrees(radians(x));
+            assert!(
+                (actual - x).abs() <= 2.0 * f64::EPSILON * x.abs(),
+                "degrees(radians({x})) = {actual}",
+            );
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn string_slicing_matches_chars(
+            s in "[aé€😀𝄞]{0,12}",
+            n in -16i32..16,
+            m in -16i32..16,
+            fill in "[aé€😀]{0,3}",
+        ) {
+            // Every function must slice on character boundaries, so it must agree with a
+            // reference implementation that operates on the characters of `s`.
+            //
+            // `overlay` and `rpad` don't exist as separate functions, so only `left`, `right`,
+            // `substr`, and `lpad` are covered.
+            let chars: Vec<char> = s.chars().collect();
+            let len = i64::try_from(chars.len()).unwrap();
+            let n64 = i64::from(n);
+            let slice = |start: i64, end: i64| -> String {
+                let start = usize::try_from(start.clamp(0, len)).unwrap();
+                let end = usize::try_from(end.clamp(0, len)).unwrap();
+                chars[start..end.max(start)].iter().collect()
+            };
+            let string = Datum::String(&s);
+
+            let expected = if n >= 0 { slice(0, n64) } else { slice(0, len + n64) };
+            let actual = left(string, Datum::Int32(n)).unwrap();
+            prop_assert_eq!(actual.unwrap_str(), expected);
+
+            let expected = if n >= 0 { slice(len - n64, len) } else { slice(-n64, len) };
+            let actual = right(string, Datum::Int32(n)).unwrap();
+            prop_assert_eq!(actual.unwrap_str(), expected);
+
+            // Positions are 1-based, and the substring ends before position `n + m`.
+            let expected = slice(n64 - 1, len);
+            let actual = substr(&[string, Datum::Int32(n)]).unwrap();
+            prop_assert_eq!(actual.unwrap_str(), expected);
+            let actual = substr(&[string, Datum::Int32(n), Datum::Int32(m)]);
+            if m < 0 {
+                prop_assert!(actual.is_err());
+            } else {
+                let expected = slice(n64 - 1, n64 - 1 + i64::from(m));
+                prop_assert_eq!(actual.unwrap().unwrap_str(), expected);
+            }
+
+            let arena = RowArena::new();
+            let actual = pad_leading(&[string, Datum::Int32(n), Datum::String(&fill)], &arena);
+            if n < 0 {
+                prop_assert_eq!(actual.unwrap().unwrap_str(), "");
+            } else if n64 <= len {
+                prop_assert_eq!(actual.unwrap().unwrap_str(), slice(0, n64));
+            } else {
+                let pad_len = usize::try_from(n64 - len).unwrap();
+                let expected: String = fill.chars().cycle().take(pad_len).chain(chars.iter().copied()).collect();
+                prop_assert_eq!(actual.unwrap().unwrap_str(), expected);
+            }
+        }
+    }
+
+    #[mz_ore::test]
+    fn degrees_radians_cardinal_values() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(radians(0.0), 0.0);
+        assert_eq!(radians(90.0), FRAC_PI_2);
+        assert_eq!(radians(180.0), PI);
+        assert_eq!(radians(-180.0), -PI);
+        assert_eq!(radians(360.0), 2.0 * PI);
+        assert_eq!(degrees(0.0), 0.0);
+        assert_eq!(degrees(FRAC_PI_2), 90.0);
+        assert_eq!(degrees(PI), 180.0);
+        assert_eq!(degrees(-PI), -180.0);
+        assert_eq!(degrees(2.0 * PI), 360.0);
+        for x in [-720.0, -360.0, -270.0, -90.0, 45.0, 270.0, 720.0] {
+            assert_eq!(degrees(radians(x)), x);
+        }
+        // Matches PostgreSQL, which divides by the radians per degree.
+        assert_eq!(degrees(-0.5), -28.64788975654116);
+        assert_eq!(degrees(-499.375), -28612.079894345483);
+    }
+
+    #[mz_ore::test]
+    fn jsonb_contains_numeric_ignores_scale() {
+        let mut cx = numeric::cx_datum();
+        let one_point_oh = Datum::from(cx.parse("1.0").unwrap());
+        let one_point_oh_oh = Datum::from(cx.parse("1.00").unwrap());
+        let one_point_oh_one = Datum::from(cx.parse("1.01").unwrap());
+
+        assert_eq!(
+            jsonb_contains_jsonb(one_point_oh, one_point_oh_oh),
+            Datum::True
+        );
+        assert_eq!(
+            jsonb_contains_jsonb(one_point_oh_oh, one_point_oh),
+            Datum::True
+        );
+        assert_eq!(
+            jsonb_contains_jsonb(one_point_oh, one_point_oh_one),
+            Datum::False
+        );
+
+        let arena = RowArena::new();
+        let list = arena.make_datum(|packer| packer.push_list([one_point_oh]));
+        assert_eq!(jsonb_contains_jsonb(list, one_point_oh_oh), Datum::True);
+
+        let map_a = arena.make_datum(|packer| packer.push_dict([("a", one_point_oh)]));
+        let map_b = arena.make_datum(|packer| packer.push_dict([("a", one_point_oh_oh)]));
+        assert_eq!(map_contains_map(map_a, map_b), Datum::True);
+    }
+
+    #[mz_ore::test]
+    fn jsonb_get_int64_matches_get_path() {
+        let arena = RowArena::new();
+        let list = arena.make_datum(|packer| {
+            packer.push_list([Datum::String("a"), Datum::String("b"), Datum::String("c")])
+        });
+
+        for i in [0, 1, 2, 3, -1, -2, -3, -4, i64::MAX, i64::MIN] {
+            let path = arena.make_datum(|packer| {
+                packer
+                    .push_array(
+                        &[ArrayDimension {
+                            lower_bound: 1,
+                            length: 1,
+                        }],
+                        [Datum::String(&i.to_string())],
+                    )
+                    .unwrap()
+            });
+            for stringify in [false, true] {
+                assert_eq!(
+                    jsonb_get_int64(list, Datum::Int64(i), &arena, stringify),
+                    jsonb_get_path(list, path, &arena, stringify),
+                    "index {i}, stringify {stringify}",
+                );
+            }
+        }
+
+        let list = list.unwrap_list();
+        assert_eq!(jsonb_index_list(list, 0), Some(Datum::String("a")));
+        assert_eq!(jsonb_index_list(list, 2), Some(Datum::String("c")));
+        assert_eq!(jsonb_index_list(list, 3), None);
+        assert_eq!(jsonb_index_list(list, -1), Some(Datum::String("c")));
+        assert_eq!(jsonb_index_list(list, -3), Some(Datum::String("a")));
+        // Indexing past the start of the array wraps around and is out of
+        // bounds rather than panicking.
+        assert_eq!(jsonb_index_list(list, -4), None);
+        assert_eq!(jsonb_index_list(list, i64::MIN), None);
+    }
+
+    #[mz_ore::test]
+    fn test_position() {
+        // Text positions are character indices, not byte offsets.
+        let text_cases = [
+            ("b", "abc", 2),
+            ("z", "abc", 0),
+            ("ü", "aüb", 2),
+            ("b", "aüb", 3),
+            ("ß", "日本ßx", 3),
+            ("", "abc", 1),
+            ("", "", 1),
+            ("", "日本", 1),
+            ("a", "", 0),
+            ("abcd", "abc", 0),
+        ];
+        for (needle, haystack, expected) in text_cases {
+            assert_eq!(
+                position(Datum::String(needle), Datum::String(haystack)).unwrap(),
+                Datum::Int32(expected),
+                "position({needle:?} in {haystack:?})",
+            );
+        }
+
+        // Bytea positions are byte indices.
+        let bytes_cases: [(&[u8], &[u8], i32); 5] = [
+            (b"b", b"abc", 2),
+            (b"z", b"abc", 0),
+            (b"", b"abc", 1),
+            (b"bcd", b"abc", 0),
+            (b"b", "\u{fc}b".as_bytes(), 3),
+        ];
+        for (needle, haystack, expected) in bytes_cases {
+            assert_eq!(
+                position_bytes(Datum::Bytes(needle), Datum::Bytes(haystack)).unwrap(),
+                Datum::Int32(expected),
+                "position({needle:?} in {haystack:?})",
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_adjust_numeric_scale_not_invertible() {
+        // Rescales round, so neither narrowing nor widening rescales may be
+        // inverted when pushing casts onto indexed columns.
+        for scale in [0i64, 2, 4, 10] {
+            let func = UnaryFunc::AdjustNumericScale(AdjustNumericScale(
+                NumericMaxScale::try_from(scale).unwrap(),
+            ));
+            assert!(!func.preserves_uniqueness());
+            assert_eq!(func.inverse(), None);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_mz_timestamp_casts_are_monotone() {
+        // Temporal filters compare `mz_now()` against (casts of) timestamp columns, so these casts
+        // must be monotone for the filters to be pushed down.
+        for func in [
+            UnaryFunc::CastMzTimestampToTimestamp(CastMzTimestampToTimestamp),
+            UnaryFunc::CastMzTimestampToTimestampTz(CastMzTimestampToTimestampTz),
+            UnaryFunc::CastTimestampToMzTimestamp(CastTimestampToMzTimestamp),
+            UnaryFunc::CastTimestampTzToMzTimestamp(CastTimestampTzToMzTimestamp),
+        ] {
+            assert!(func.is_monotone(), "{func} should be monotone");
+        }
+        assert_eq!(
+            BinaryFunc::SubTimestampTzInterval.is_monotone(),
+            (true, true)
+        );
+        assert_eq!(
+            BinaryFunc::AddTimestampTzInterval.is_monotone(),
+            (true, true)
+        );
     }
 
     #[mz_ore::test]
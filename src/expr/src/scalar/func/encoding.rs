@@ -10,6 +10,7 @@
 //! Encoding and decoding support for various formats that represent binary data
 //! as text data.
 
+use mz_ore::cast::CastFrom;
 use mz_ore::fmt::FormatBuffer;
 use mz_repr::strconv;
 use uncased::UncasedStr;
@@ -43,14 +44,18 @@ impl Base64Format {
         char::from(Self::CHARSET[usize::from(v)])
     }
 
-    fn decode_sextet(b: u8) -> Result<u8, EvalError> {
+    /// Decodes the byte `b`, found at byte offset `offset` in the input.
+    fn decode_sextet((offset, b): (usize, u8)) -> Result<u8, EvalError> {
         match b {
             b'A'..=b'Z' => Ok(b - b'A'),
             b'a'..=b'z' => Ok(b - b'a' + 26),
             b'0'..=b'9' => Ok(b + 4),
             b'+' => Ok(62),
             b'/' => Ok(63),
-            _ => Err(EvalError::InvalidBase64Symbol(char::from(b))),
+            _ => Err(EvalError::InvalidBase64SymbolAt {
+                symbol: char::from(b),
+                offset,
+            }),
         }
     }
 }
@@ -128,28 +133,31 @@ impl Format for Base64Format {
         // a chunk. It is also an error if a chunk is incomplete.
 
         let mut buf = vec![];
+        // Track the offset of each byte in the original input, so that errors
+        // can point at the offending symbol.
         let mut bytes = s
             .as_bytes()
             .iter()
             .copied()
-            .filter(|ch| !matches!(ch, b' ' | b'\t' | b'\n' | b'\r'));
+            .enumerate()
+            .filter(|(_, ch)| !matches!(ch, b' ' | b'\t' | b'\n' | b'\r'));
         loop {
             match (bytes.next(), bytes.next(), bytes.next(), bytes.next()) {
-                (Some(c1), Some(c2), Some(b'='), Some(b'=')) => {
+                (Some(c1), Some(c2), Some((_, b'=')), Some((_, b'='))) => {
                     let s1 = Self::decode_sextet(c1)?;
                     let s2 = Self::decode_sextet(c2)?;
                     buf.push(s1 << 2 | (s2 & 0b110000) >> 4);
                 }
-                (Some(c1), Some(c2), Some(c3), Some(b'=')) => {
+                (Some(c1), Some(c2), Some(c3), Some((_, b'='))) => {
                     let s1 = Self::decode_sextet(c1)?;
                     let s2 = Self::decode_sextet(c2)?;
                     let s3 = Self::decode_sextet(c3)?;
                     buf.push(s1 << 2 | (s2 & 0b110000) >> 4);
                     buf.push((s2 & 0b001111) << 4 | (s3 & 0b111100) >> 2);
                 }
-                (Some(b'='), _, _, _) | (_, Some(b'='), _, _) | (_, _, Some(b'='), _) => {
-                    return Err(EvalError::InvalidBase64Equals)
-                }
+                (Some((_, b'=')), _, _, _)
+                | (_, Some((_, b'=')), _, _)
+                | (_, _, Some((_, b'=')), _) => return Err(EvalError::InvalidBase64Equals),
                 (Some(c1), Some(c2), Some(c3), Some(c4)) => {
                     let s1 = Self::decode_sextet(c1)?;
                     let s2 = Self::decode_sextet(c2)?;
@@ -166,6 +174,118 @@ impl Format for Base64Format {
     }
 }
 
+/// RFC 4648 Base32 encoding, parameterized by alphabet.
+///
+/// Encoding always emits `=` padding. Decoding accepts input with or without
+/// padding, accepts lowercase letters, and ignores whitespace. See
+/// <http://materialize.com/docs/sql/functions/encode> for details.
+struct Base32Format {
+    charset: &'static [u8; 32],
+}
+
+impl Base32Format {
+    /// The alphabet from Section 6 of RFC 4648.
+    const STANDARD: Base32Format = Base32Format {
+        charset: b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+    };
+
+    /// The "extended hex" alphabet from Section 7 of RFC 4648.
+    const HEX: Base32Format = Base32Format {
+        charset: b"0123456789ABCDEFGHIJKLMNOPQRSTUV",
+    };
+
+    /// Decodes the byte `b`, found at byte offset `offset` in the input.
+    fn decode_quintet(&self, (offset, b): (usize, u8)) -> Result<u8, EvalError> {
+        let upper = b.to_ascii_uppercase();
+        match self.charset.iter().position(|c| *c == upper) {
+            Some(v) => Ok(u8::try_from(v).expect("charset has 32 symbols")),
+            None => Err(EvalError::InvalidBase32Symbol {
+                symbol: char::from(b),
+                offset,
+            }),
+        }
+    }
+}
+
+impl Format for Base32Format {
+    fn encode(&self, bytes: &[u8]) -> String {
+        // Process input in chunks of five octets. Each chunk is converted to
+        // eight quintets, each of which is encoded as a printable ASCII
+        // character via `charset`.
+        //
+        // When the input length is not divisible by five, the last chunk is
+        // partial. Quintets that are entirely determined by missing octets are
+        // encoded as `=`. Quintets that are partially determined by a missing
+        // octet assume the octet was zero.
+
+        let mut buf = String::new();
+        for chunk in bytes.chunks(5) {
+            let mut acc = 0u64;
+            for (i, o) in chunk.iter().enumerate() {
+                acc |= u64::from(*o) << (32 - 8 * i);
+            }
+            let quintets = (chunk.len() * 8 + 4) / 5;
+            for i in 0..8 {
+                if i < quintets {
+                    let q = (acc >> (35 - 5 * i)) & 0b11111;
+                    buf.push(char::from(self.charset[usize::cast_from(q)]));
+                } else {
+                    buf.push('=');
+                }
+            }
+        }
+        buf
+    }
+
+    fn decode(&self, s: &str) -> Result<Vec<u8>, EvalError> {
+        // Decode each non-whitespace byte into a quintet, then convert each
+        // group of eight quintets into five octets. A trailing partial group
+        // must contain 2, 4, 5, or 7 quintets, which determine 1, 2, 3, or 4
+        // octets, respectively; any leftover bits are discarded.
+        //
+        // Padding is optional, but if present it must only appear at the end
+        // of the input and must complete the final group.
+
+        let mut quintets = vec![];
+        let mut padding = 0;
+        let bytes = s
+            .as_bytes()
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, ch)| !matches!(ch, b' ' | b'\t' | b'\n' | b'\r'));
+        for (offset, b) in bytes {
+            if b == b'=' {
+                padding += 1;
+            } else if padding > 0 {
+                return Err(EvalError::InvalidBase32EndSequence);
+            } else {
+                quintets.push(self.decode_quintet((offset, b))?);
+            }
+        }
+
+        let partial = quintets.len() % 8;
+        if !matches!(partial, 0 | 2 | 4 | 5 | 7)
+            || (padding > 0 && (partial == 0 || partial + padding != 8))
+        {
+            return Err(EvalError::InvalidBase32EndSequence);
+        }
+
+        let mut buf = vec![];
+        for chunk in quintets.chunks(8) {
+            let mut acc = 0u64;
+            for (i, q) in chunk.iter().enumerate() {
+                acc |= u64::from(*q) << (35 - 5 * i);
+            }
+            for i in 0..chunk.len() * 5 / 8 {
+                let o = (acc >> (32 - 8 * i)) & 0xff;
+                buf.push(u8::try_from(o).expect("masked to one octet"));
+            }
+        }
+        Ok(buf)
+    }
+}
+
 struct EscapeFormat;
 
 impl Format for EscapeFormat {
@@ -205,7 +325,11 @@ impl Format for HexFormat {
 
 pub fn lookup_format(s: &str) -> Result<&'static dyn Format, EvalError> {
     let s = UncasedStr::new(s);
-    if s == "base64" {
+    if s == "base32" {
+        Ok(&Base32Format::STANDARD)
+    } else if s == "base32hex" {
+        Ok(&Base32Format::HEX)
+    } else if s == "base64" {
         Ok(&Base64Format)
     } else if s == "escape" {
         Ok(&EscapeFormat)
@@ -215,3 +339,114 @@ pub fn lookup_format(s: &str) -> Result<&'static dyn Format, EvalError> {
         Err(EvalError::InvalidEncodingName(s.as_str().into()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_escape_roundtrip() {
+        let format = lookup_format("escape").unwrap();
+        for b in 0..=u8::MAX {
+            let encoded = format.encode(&[b]);
+            let expected = match b {
+                b'\0' | b'\x80'..=b'\xff' => format!("\\{:03o}", b),
+                b'\\' => "\\\\".into(),
+                _ => char::from(b).to_string(),
+            };
+            assert_eq!(encoded, expected, "encoding byte {b:#04x}");
+            assert_eq!(
+                format.decode(&encoded).unwrap(),
+                [b],
+                "decoding byte {b:#04x}"
+            );
+        }
+
+        let all: Vec<u8> = (0..=u8::MAX).collect();
+        assert_eq!(format.decode(&format.encode(&all)).unwrap(), all);
+    }
+
+    #[mz_ore::test]
+    fn test_base32_rfc4648_vectors() {
+        // Test vectors from Section 10 of RFC 4648.
+        let vectors = [
+            ("", "", ""),
+            ("f", "MY======", "CO======"),
+            ("fo", "MZXQ====", "CPNG===="),
+            ("foo", "MZXW6===", "CPNMU==="),
+            ("foob", "MZXW6YQ=", "CPNMUOG="),
+            ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+            ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+        ];
+        let base32 = lookup_format("base32").unwrap();
+        let base32hex = lookup_format("base32hex").unwrap();
+        for (plain, encoded, encoded_hex) in vectors {
+            for (format, encoded) in [(base32, encoded), (base32hex, encoded_hex)] {
+                assert_eq!(format.encode(plain.as_bytes()), encoded);
+                assert_eq!(format.decode(encoded).unwrap(), plain.as_bytes());
+                let unpadded = encoded.trim_end_matches('=');
+                assert_eq!(format.decode(unpadded).unwrap(), plain.as_bytes());
+                let lower = encoded.to_ascii_lowercase();
+                assert_eq!(format.decode(&lower).unwrap(), plain.as_bytes());
+            }
+        }
+
+        let all: Vec<u8> = (0..=u8::MAX).collect();
+        for format in [base32, base32hex] {
+            assert_eq!(format.decode(&format.encode(&all)).unwrap(), all);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_base32_malformed() {
+        let base32 = lookup_format("base32").unwrap();
+        assert_eq!(
+            base32.decode("MZXW 6Y1B").unwrap_err(),
+            EvalError::InvalidBase32Symbol {
+                symbol: '1',
+                offset: 7,
+            }
+        );
+        // `W` is outside of the extended hex alphabet.
+        assert_eq!(
+            lookup_format("base32hex")
+                .unwrap()
+                .decode("CPNW")
+                .unwrap_err(),
+            EvalError::InvalidBase32Symbol {
+                symbol: 'W',
+                offset: 3,
+            }
+        );
+        for s in [
+            "M", "MZX", "MZXW6Y", "MY=", "MY=====", "MY==MY==", "========",
+        ] {
+            assert_eq!(
+                base32.decode(s).unwrap_err(),
+                EvalError::InvalidBase32EndSequence,
+                "decoding {s:?}"
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_decode_error_offsets() {
+        let base64 = lookup_format("base64").unwrap();
+        assert_eq!(
+            base64.decode("aGVs\nbG@g").unwrap_err(),
+            EvalError::InvalidBase64SymbolAt {
+                symbol: '@',
+                offset: 7,
+            }
+        );
+
+        let hex = lookup_format("hex").unwrap();
+        assert_eq!(
+            hex.decode("0102 0x04").unwrap_err(),
+            EvalError::ParseHex(strconv::ParseHexError::InvalidHexDigit {
+                digit: 'x',
+                offset: 6,
+            })
+        );
+    }
+}
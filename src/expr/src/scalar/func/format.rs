@@ -16,11 +16,16 @@
 use std::fmt;
 
 use aho_corasick::AhoCorasickBuilder;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use dec::OrderedDecimal;
 use enum_iterator::Sequence;
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::numeric::Numeric;
+use mz_repr::strconv::{self, ParseError};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::scalar::func::TimestampLike;
+use crate::EvalError;
 
 /// The raw tokens that can appear in a format string. Many of these tokens
 /// overlap, in which case the longest matching token should be selected.
@@ -53,6 +58,18 @@ enum DateTimeToken {
     dy,
     Dy,
     DY,
+    ff1,
+    FF1,
+    ff2,
+    FF2,
+    ff3,
+    FF3,
+    ff4,
+    FF4,
+    ff5,
+    FF5,
+    ff6,
+    FF6,
     fm,
     FM,
     fx,
@@ -100,6 +117,8 @@ enum DateTimeToken {
     Q,
     rm,
     RM,
+    RR,
+    RRRR,
     ss,
     SS,
     ssss,
@@ -163,6 +182,18 @@ impl DateTimeToken {
             DateTimeToken::DY => "DY",
             DateTimeToken::Dy => "Dy",
             DateTimeToken::dy => "dy",
+            DateTimeToken::FF1 => "FF1",
+            DateTimeToken::ff1 => "ff1",
+            DateTimeToken::FF2 => "FF2",
+            DateTimeToken::ff2 => "ff2",
+            DateTimeToken::FF3 => "FF3",
+            DateTimeToken::ff3 => "ff3",
+            DateTimeToken::FF4 => "FF4",
+            DateTimeToken::ff4 => "ff4",
+            DateTimeToken::FF5 => "FF5",
+            DateTimeToken::ff5 => "ff5",
+            DateTimeToken::FF6 => "FF6",
+            DateTimeToken::ff6 => "ff6",
             DateTimeToken::FM => "FM",
             DateTimeToken::fm => "fm",
             DateTimeToken::FX => "FX",
@@ -210,6 +241,8 @@ impl DateTimeToken {
             DateTimeToken::q => "q",
             DateTimeToken::rm => "rm",
             DateTimeToken::RM => "RM",
+            DateTimeToken::RR => "RR",
+            DateTimeToken::RRRR => "RRRR",
             DateTimeToken::SS => "ss",
             DateTimeToken::ss => "SS",
             DateTimeToken::SSSS => "SSSS",
@@ -317,6 +350,12 @@ impl DateTimeToken {
             }),
             dd | DD => Some(DateTimeField::DayOfMonth),
             ddd | DDD => Some(DateTimeField::DayOfYear),
+            ff1 | FF1 => Some(DateTimeField::FractionalSecond { digits: 1 }),
+            ff2 | FF2 => Some(DateTimeField::FractionalSecond { digits: 2 }),
+            ff3 | FF3 => Some(DateTimeField::FractionalSecond { digits: 3 }),
+            ff4 | FF4 => Some(DateTimeField::FractionalSecond { digits: 4 }),
+            ff5 | FF5 => Some(DateTimeField::FractionalSecond { digits: 5 }),
+            ff6 | FF6 => Some(DateTimeField::FractionalSecond { digits: 6 }),
             fm | FM | fx | FX | th | TH | Quote | EscQuote => None,
             hh | HH | hh12 | HH12 => Some(DateTimeField::Hour12),
             hh24 | HH24 => Some(DateTimeField::Hour24),
@@ -355,6 +394,8 @@ impl DateTimeToken {
             q | Q => Some(DateTimeField::Quarter),
             rm => Some(DateTimeField::MonthInRomanNumerals { caps: false }),
             RM => Some(DateTimeField::MonthInRomanNumerals { caps: true }),
+            RR => Some(DateTimeField::RoundYear2),
+            RRRR => Some(DateTimeField::RoundYear4),
             ss | SS => Some(DateTimeField::Second),
             ssss | SSSS | sssss | SSSSS => Some(DateTimeField::SecondsPastMidnight),
             tz => Some(DateTimeField::Timezone { caps: false }),
@@ -455,7 +496,10 @@ enum WordCaps {
 /// A date-time field.
 ///
 /// The variants are largely self-evident, but are described in detail in the
-/// PostgreSQL documentation if necessary.
+/// PostgreSQL documentation if necessary. The exceptions are `RoundYear2` and
+/// `RoundYear4`, which come from Oracle's `RR` and `RRRR` patterns. They render
+/// like `Year2` and `Year4`, but when parsing, the century of a two-digit year
+/// is inferred using a fixed pivot.
 #[derive(Debug, Eq, PartialEq, Clone)]
 enum DateTimeField {
     Hour12,
@@ -464,20 +508,41 @@ enum DateTimeField {
     Second,
     Millisecond,
     Microsecond,
+    /// The first `digits` digits of the fractional second, from `FF1` through
+    /// `FF6`.
+    FractionalSecond {
+        digits: u32,
+    },
     SecondsPastMidnight,
-    Meridiem { dots: bool, caps: bool },
+    Meridiem {
+        dots: bool,
+        caps: bool,
+    },
     Year1,
     Year2,
     Year3,
-    Year4 { separator: bool },
+    Year4 {
+        separator: bool,
+    },
+    RoundYear2,
+    RoundYear4,
     IsoYear1,
     IsoYear2,
     IsoYear3,
     IsoYear4,
-    Era { dots: bool, caps: bool },
-    MonthName { abbrev: bool, caps: WordCaps },
+    Era {
+        dots: bool,
+        caps: bool,
+    },
+    MonthName {
+        abbrev: bool,
+        caps: WordCaps,
+    },
     MonthOfYear,
-    DayName { abbrev: bool, caps: WordCaps },
+    DayName {
+        abbrev: bool,
+        caps: WordCaps,
+    },
     DayOfWeek,
     IsoDayOfWeek,
     DayOfMonth,
@@ -489,13 +554,99 @@ enum DateTimeField {
     Century,
     JulianDay,
     Quarter,
-    MonthInRomanNumerals { caps: bool },
-    Timezone { caps: bool },
+    MonthInRomanNumerals {
+        caps: bool,
+    },
+    Timezone {
+        caps: bool,
+    },
     TimezoneHours,
     TimezoneMinutes,
     TimezoneOffset,
 }
 
+impl DateTimeField {
+    /// Returns the canonical pattern for this field, for use in error messages.
+    fn pattern(&self) -> &'static str {
+        match self {
+            DateTimeField::Hour12 => "HH12",
+            DateTimeField::Hour24 => "HH24",
+            DateTimeField::Minute => "MI",
+            DateTimeField::Second => "SS",
+            DateTimeField::Millisecond => "MS",
+            DateTimeField::Microsecond => "US",
+            DateTimeField::FractionalSecond { digits: 1 } => "FF1",
+            DateTimeField::FractionalSecond { digits: 2 } => "FF2",
+            DateTimeField::FractionalSecond { digits: 3 } => "FF3",
+            DateTimeField::FractionalSecond { digits: 4 } => "FF4",
+            DateTimeField::FractionalSecond { digits: 5 } => "FF5",
+            DateTimeField::FractionalSecond { digits: 6 } => "FF6",
+            DateTimeField::FractionalSecond { .. } => unreachable!("FF1 through FF6 only"),
+            DateTimeField::SecondsPastMidnight => "SSSS",
+            DateTimeField::Meridiem { dots: false, .. } => "AM",
+            DateTimeField::Meridiem { dots: true, .. } => "A.M.",
+            DateTimeField::Year1 => "Y",
+            DateTimeField::Year2 => "YY",
+            DateTimeField::Year3 => "YYY",
+            DateTimeField::Year4 { separator: false } => "YYYY",
+            DateTimeField::Year4 { separator: true } => "Y,YYY",
+            DateTimeField::RoundYear2 => "RR",
+            DateTimeField::RoundYear4 => "RRRR",
+            DateTimeField::IsoYear1 => "I",
+            DateTimeField::IsoYear2 => "IY",
+            DateTimeField::IsoYear3 => "IYY",
+            DateTimeField::IsoYear4 => "IYYY",
+            DateTimeField::Era { dots: false, .. } => "AD",
+            DateTimeField::Era { dots: true, .. } => "A.D.",
+            DateTimeField::MonthName { abbrev: false, .. } => "Month",
+            DateTimeField::MonthName { abbrev: true, .. } => "Mon",
+            DateTimeField::MonthOfYear => "MM",
+            DateTimeField::DayName { abbrev: false, .. } => "Day",
+            DateTimeField::DayName { abbrev: true, .. } => "Dy",
+            DateTimeField::DayOfWeek => "D",
+            DateTimeField::IsoDayOfWeek => "ID",
+            DateTimeField::DayOfMonth => "DD",
+            DateTimeField::DayOfYear => "DDD",
+            DateTimeField::IsoDayOfYear => "IDDD",
+            DateTimeField::WeekOfMonth => "W",
+            DateTimeField::WeekOfYear => "WW",
+            DateTimeField::IsoWeekOfYear => "IW",
+            DateTimeField::Century => "CC",
+            DateTimeField::JulianDay => "J",
+            DateTimeField::Quarter => "Q",
+            DateTimeField::MonthInRomanNumerals { .. } => "RM",
+            DateTimeField::Timezone { .. } => "TZ",
+            DateTimeField::TimezoneHours => "TZH",
+            DateTimeField::TimezoneMinutes => "TZM",
+            DateTimeField::TimezoneOffset => "OF",
+        }
+    }
+
+    /// Returns the number of digits this field consumes when parsing, if it is
+    /// numeric and immediately followed by another field.
+    fn parse_width(&self) -> Option<usize> {
+        match self {
+            DateTimeField::Year1 => Some(1),
+            DateTimeField::Year2 | DateTimeField::RoundYear2 => Some(2),
+            DateTimeField::Year3 => Some(3),
+            DateTimeField::Year4 { .. } | DateTimeField::RoundYear4 => Some(4),
+            DateTimeField::Hour12
+            | DateTimeField::Hour24
+            | DateTimeField::Minute
+            | DateTimeField::Second
+            | DateTimeField::MonthOfYear
+            | DateTimeField::DayOfMonth
+            | DateTimeField::Century
+            | DateTimeField::TimezoneHours
+            | DateTimeField::TimezoneMinutes => Some(2),
+            DateTimeField::DayOfYear => Some(3),
+            DateTimeField::SecondsPastMidnight => Some(5),
+            DateTimeField::DayOfWeek | DateTimeField::Quarter => Some(1),
+            _ => None,
+        }
+    }
+}
+
 /// An element of a date-time format string.
 #[derive(Debug)]
 enum DateTimeFormatNode {
@@ -641,6 +792,16 @@ impl DateTimeFormatNode {
                     };
                 }
 
+                // Unlike other numeric fields, fractional seconds are zero
+                // padded even in fill mode, as their leading zeros are
+                // significant.
+                macro_rules! write_frac {
+                    ($n:expr, $width:expr) => {{
+                        write!(buf, "{:0width$}", $n, width = $width)?;
+                        ordinal.render(buf, $n)
+                    }};
+                }
+
                 macro_rules! write_str {
                     ($s:expr, $width:expr) => {{
                         write!(buf, "{:width$}", $s, width = if *fill { $width } else { 0 })
@@ -757,7 +918,7 @@ impl DateTimeFormatNode {
                         abbrev: false,
                         caps: NoCaps,
                     } => write_str!(MONTHS_NO_CAPS[usize::cast_from(ts.month0())], 9),
-                    DateTimeField::Millisecond => write_num!(ts.nanosecond() / 1_000_000, 3),
+                    DateTimeField::Millisecond => write_frac!(ts.nanosecond() / 1_000_000, 3),
                     DateTimeField::Quarter => write_num!(ts.month0() / 3 + 1),
                     DateTimeField::MonthInRomanNumerals { caps: true } => {
                         write_str!(MONTHS_ROMAN_CAPS[usize::cast_from(ts.month0())], 4)
@@ -773,13 +934,21 @@ impl DateTimeFormatNode {
                     DateTimeField::TimezoneOffset => write_str!(ts.timezone_offset()),
                     DateTimeField::TimezoneHours => write_str!(ts.timezone_hours()),
                     DateTimeField::TimezoneMinutes => write_str!(ts.timezone_minutes()),
-                    DateTimeField::Microsecond => write_num!(ts.nanosecond() / 1_000, 6),
+                    DateTimeField::Microsecond => write_frac!(ts.nanosecond() / 1_000, 6),
+                    DateTimeField::FractionalSecond { digits } => write_frac!(
+                        ts.nanosecond() / 10u32.pow(9 - *digits),
+                        usize::cast_from(*digits)
+                    ),
                     DateTimeField::WeekOfMonth => write_num!(ts.day0() / 7 + 1, 1),
                     DateTimeField::WeekOfYear => write_num!(ts.ordinal0() / 7 + 1, 2),
                     DateTimeField::Year1 => write_num!(ts.year_ce().1 % 10, 1),
-                    DateTimeField::Year2 => write_num!(ts.year_ce().1 % 100, 2),
                     DateTimeField::Year3 => write_num!(ts.year_ce().1 % 1000, 3),
-                    DateTimeField::Year4 { separator: false } => write_num!(ts.year_ce().1, 4),
+                    DateTimeField::Year2 | DateTimeField::RoundYear2 => {
+                        write_num!(ts.year_ce().1 % 100, 2)
+                    }
+                    DateTimeField::Year4 { separator: false } | DateTimeField::RoundYear4 => {
+                        write_num!(ts.year_ce().1, 4)
+                    }
                     DateTimeField::Year4 { separator: true } => {
                         let n = ts.year_ce().1;
                         write!(buf, "{},{:03}", n / 1000, n % 1000)?;
@@ -792,7 +961,12 @@ impl DateTimeFormatNode {
 }
 
 /// A compiled date-time format string.
-pub struct DateTimeFormat(Vec<DateTimeFormatNode>);
+pub struct DateTimeFormat {
+    nodes: Vec<DateTimeFormatNode>,
+    /// Whether the format string starts with the fixed format modifier `FX`,
+    /// which makes parsing strict.
+    fixed: bool,
+}
 
 impl DateTimeFormat {
     /// Compiles a new `DateTimeFormat` from the input string `s`.
@@ -871,7 +1045,17 @@ impl DateTimeFormat {
         for c in s[pos..].chars() {
             out.push(DateTimeFormatNode::Literal(c));
         }
-        DateTimeFormat(out)
+        // Like PostgreSQL, `FX` is only recognized as the first item of the
+        // format string.
+        let fixed = matches!(
+            matches.first(),
+            Some(Match {
+                start: 0,
+                token: DateTimeToken::fx | DateTimeToken::FX,
+                ..
+            })
+        );
+        DateTimeFormat { nodes: out, fixed }
     }
 
     /// Renders the format string using the timestamp `ts` as the input. The
@@ -879,10 +1063,790 @@ impl DateTimeFormat {
     /// according to the value of `ts`.
     pub fn render(&self, ts: &impl TimestampLike) -> String {
         let mut out = String::new();
-        for node in &self.0 {
+        for node in &self.nodes {
             node.render(&mut out, ts)
                 .expect("rendering to string cannot fail");
         }
         out
     }
+
+    /// Parses `s` as a timestamp according to the format string, as done by
+    /// PostgreSQL's `to_timestamp` and `to_date`.
+    ///
+    /// Fields that are absent from the format take their earliest value, i.e.
+    /// January 1 of year 1 at midnight. Like PostgreSQL, weekday and quarter
+    /// fields are accepted but ignored. If the format contains a time zone
+    /// offset, the returned timestamp is adjusted to UTC.
+    ///
+    /// Parsing is lenient: blanks before fields are skipped, a separator in the
+    /// format matches any single separator in the input, and numeric fields
+    /// consume as many digits as are available, unless they are immediately
+    /// followed by another field.
+    ///
+    /// If the format starts with `FX`, parsing is strict instead: blanks are
+    /// not skipped, every separator in the format consumes exactly one
+    /// character of input, and numeric fields not in fill mode (`FM`) must
+    /// consume exactly their full width, e.g. two digits for `MM`.
+    pub fn parse(&self, s: &str) -> Result<NaiveDateTime, EvalError> {
+        let mut input = s;
+        let mut parsed = ParsedDateTime::default();
+        for (i, node) in self.nodes.iter().enumerate() {
+            match node {
+                DateTimeFormatNode::Literal(_) if self.fixed => {
+                    let mut chars = input.chars();
+                    chars.next();
+                    input = chars.as_str();
+                }
+                DateTimeFormatNode::Literal(c) if c.is_whitespace() => {
+                    input = input.trim_start();
+                }
+                DateTimeFormatNode::Literal(c) if c.is_alphanumeric() => {
+                    // Other literal characters skip one character of input.
+                    let mut chars = input.chars();
+                    chars.next();
+                    input = chars.as_str();
+                }
+                DateTimeFormatNode::Literal(_) => {
+                    input = input.trim_start();
+                    if let Some(c) = input.chars().next() {
+                        if !c.is_alphanumeric() {
+                            input = &input[c.len_utf8()..];
+                        }
+                    }
+                }
+                DateTimeFormatNode::Field {
+                    field,
+                    fill,
+                    ordinal,
+                } => {
+                    let width = if self.fixed {
+                        field.parse_width().filter(|_| *fill)
+                    } else {
+                        input = input.trim_start();
+                        match self.nodes.get(i + 1) {
+                            Some(DateTimeFormatNode::Field { .. }) if *fill => field.parse_width(),
+                            _ => None,
+                        }
+                    };
+                    let len = input.len();
+                    parsed.parse_field(field, width, &mut input, s)?;
+                    if let Some(width) = width.filter(|_| self.fixed) {
+                        let used = len - input.len();
+                        if used < width {
+                            return Err(EvalError::InvalidParameterValue(format!(
+                                "source string too short for \"{}\" formatting field: \
+                                 field requires {width} characters, but only {used} could be parsed",
+                                field.pattern()
+                            )));
+                        }
+                    }
+                    if *ordinal != OrdinalMode::None {
+                        take_word(&mut input, &["st", "nd", "rd", "th"]);
+                    }
+                }
+            }
+        }
+        parsed.into_timestamp(s)
+    }
+}
+
+/// The field values collected by [`DateTimeFormat::parse`].
+#[derive(Debug, Default)]
+struct ParsedDateTime {
+    year: Option<i64>,
+    century: Option<i64>,
+    bc: bool,
+    month: Option<i64>,
+    day: Option<i64>,
+    day_of_year: Option<i64>,
+    julian_day: Option<i64>,
+    hour: Option<i64>,
+    hour12: bool,
+    pm: bool,
+    minute: Option<i64>,
+    second: Option<i64>,
+    seconds_past_midnight: Option<i64>,
+    nanosecond: i64,
+    tz_negative: bool,
+    tz_hours: i64,
+    tz_minutes: i64,
+}
+
+impl ParsedDateTime {
+    /// Consumes the value of `field` from the start of `input`.
+    ///
+    /// If `width` is specified, numeric fields consume at most that many
+    /// digits. `s` is the whole parsed input, for use in error messages.
+    fn parse_field(
+        &mut self,
+        field: &DateTimeField,
+        width: Option<usize>,
+        input: &mut &str,
+        s: &str,
+    ) -> Result<(), EvalError> {
+        let mut number = |max_width| take_number(input, field, max_width);
+        match field {
+            DateTimeField::Year1 | DateTimeField::Year2 | DateTimeField::Year3 => {
+                let (n, digits) = number(width)?;
+                self.year = Some(if digits < 4 {
+                    adjust_partial_year_to_2020(n)
+                } else {
+                    n
+                });
+            }
+            DateTimeField::Year4 { separator: false } => self.year = Some(number(width)?.0),
+            DateTimeField::Year4 { separator: true } => {
+                let (mut year, _) = number(None)?;
+                if let Some(rest) = input.strip_prefix(',') {
+                    *input = rest;
+                    let (rest, _) = take_number(input, field, Some(3))?;
+                    year = year
+                        .checked_mul(1000)
+                        .and_then(|year| year.checked_add(rest))
+                        .ok_or_else(|| field_value_out_of_range(s))?;
+                }
+                self.year = Some(year);
+            }
+            DateTimeField::RoundYear2 | DateTimeField::RoundYear4 => {
+                // Two-digit years from 00 to 49 are in the 2000s, and those
+                // from 50 to 99 are in the 1900s.
+                let (n, digits) = number(width)?;
+                self.year = Some(match (digits, n) {
+                    (..=2, 0..=49) => 2000 + n,
+                    (..=2, _) => 1900 + n,
+                    _ => n,
+                });
+            }
+            DateTimeField::Century => self.century = Some(number(width)?.0),
+            DateTimeField::Era { .. } => {
+                self.bc = take_word(input, &["AD", "A.D.", "BC", "B.C."])
+                    .ok_or_else(|| invalid_value(input, field))?
+                    >= 2;
+            }
+            DateTimeField::Meridiem { .. } => {
+                self.pm = take_word(input, &["AM", "A.M.", "PM", "P.M."])
+                    .ok_or_else(|| invalid_value(input, field))?
+                    >= 2;
+            }
+            DateTimeField::MonthName { abbrev, .. } => {
+                let names = if *abbrev {
+                    &MONTHS_ABBREV_NO_CAPS
+                } else {
+                    &MONTHS_NO_CAPS
+                };
+                let month = take_word(input, names).ok_or_else(|| invalid_value(input, field))?;
+                self.month = Some(i64::try_from(month + 1).expect("month fits in i64"));
+            }
+            DateTimeField::MonthInRomanNumerals { .. } => {
+                let month = take_word(input, &MONTHS_ROMAN_NO_CAPS)
+                    .ok_or_else(|| invalid_value(input, field))?;
+                self.month = Some(i64::try_from(month + 1).expect("month fits in i64"));
+            }
+            DateTimeField::MonthOfYear => self.month = Some(number(width)?.0),
+            DateTimeField::DayName { abbrev, .. } => {
+                let names = if *abbrev {
+                    &WEEKDAYS_ABBREV_NO_CAPS
+                } else {
+                    &WEEKDAYS_NO_CAPS
+                };
+                take_word(input, names).ok_or_else(|| invalid_value(input, field))?;
+            }
+            DateTimeField::DayOfWeek | DateTimeField::Quarter => {
+                number(width)?;
+            }
+            DateTimeField::DayOfMonth => self.day = Some(number(width)?.0),
+            DateTimeField::DayOfYear => self.day_of_year = Some(number(width)?.0),
+            DateTimeField::JulianDay => self.julian_day = Some(number(width)?.0),
+            DateTimeField::Hour12 => {
+                self.hour = Some(number(width)?.0);
+                self.hour12 = true;
+            }
+            DateTimeField::Hour24 => self.hour = Some(number(width)?.0),
+            DateTimeField::Minute => self.minute = Some(number(width)?.0),
+            DateTimeField::Second => self.second = Some(number(width)?.0),
+            DateTimeField::SecondsPastMidnight => {
+                self.seconds_past_midnight = Some(number(width)?.0)
+            }
+            DateTimeField::Millisecond => {
+                // The digits are a fraction of a second, so e.g. ".5" is 500
+                // milliseconds.
+                let (mut n, digits) = number(Some(3))?;
+                for _ in digits..3 {
+                    n *= 10;
+                }
+                self.nanosecond += n * 1_000_000;
+            }
+            DateTimeField::Microsecond => {
+                let (mut n, digits) = number(Some(6))?;
+                for _ in digits..6 {
+                    n *= 10;
+                }
+                self.nanosecond += n * 1_000;
+            }
+            DateTimeField::FractionalSecond { digits: max_digits } => {
+                let max_digits = usize::cast_from(*max_digits);
+                let (mut n, digits) = number(Some(max_digits))?;
+                for _ in digits..9 {
+                    n *= 10;
+                }
+                self.nanosecond += n;
+            }
+            DateTimeField::TimezoneHours => {
+                self.tz_negative = input.starts_with('-');
+                let (n, _) = take_signed_number(input, field, width)?;
+                self.tz_hours = n.abs();
+            }
+            DateTimeField::TimezoneMinutes => self.tz_minutes = number(width)?.0,
+            DateTimeField::IsoYear1
+            | DateTimeField::IsoYear2
+            | DateTimeField::IsoYear3
+            | DateTimeField::IsoYear4
+            | DateTimeField::IsoDayOfWeek
+            | DateTimeField::IsoDayOfYear
+            | DateTimeField::IsoWeekOfYear
+            | DateTimeField::WeekOfMonth
+            | DateTimeField::WeekOfYear
+            | DateTimeField::Timezone { .. }
+            | DateTimeField::TimezoneOffset => {
+                return Err(EvalError::Unsupported {
+                    feature: format!(
+                        "formatting field \"{}\" in to_date and to_timestamp",
+                        field.pattern()
+                    ),
+                    issue_no: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Assembles the parsed fields into a timestamp in UTC.
+    ///
+    /// `s` is the parsed input, for use in error messages.
+    fn into_timestamp(self, s: &str) -> Result<NaiveDateTime, EvalError> {
+        let out_of_range = || field_value_out_of_range(s);
+        let to_u32 = |n: i64| u32::try_from(n).map_err(|_| out_of_range());
+
+        let mut year = match (self.year, self.century) {
+            (Some(year), _) => year,
+            (None, Some(century)) => century
+                .checked_sub(1)
+                .and_then(|century| century.checked_mul(100))
+                .and_then(|year| year.checked_add(1))
+                .ok_or_else(out_of_range)?,
+            (None, None) => 1,
+        };
+        if self.bc {
+            if year <= 0 {
+                return Err(out_of_range());
+            }
+            // There is no year 0, so 1 BC is year 0 in the proleptic Gregorian
+            // calendar.
+            year = 1 - year;
+        }
+        let year = i32::try_from(year).map_err(|_| out_of_range())?;
+        let date = match (self.julian_day, self.day_of_year, self.month, self.day) {
+            (Some(julian_day), ..) => {
+                let days = i32::try_from(julian_day - 1_721_425).map_err(|_| out_of_range())?;
+                NaiveDate::from_num_days_from_ce_opt(days)
+            }
+            (None, Some(day_of_year), None, None) => {
+                NaiveDate::from_yo_opt(year, to_u32(day_of_year)?)
+            }
+            (None, _, month, day) => NaiveDate::from_ymd_opt(
+                year,
+                to_u32(month.unwrap_or(1))?,
+                to_u32(day.unwrap_or(1))?,
+            ),
+        }
+        .ok_or_else(out_of_range)?;
+
+        let (mut hour, mut minute, mut second) = match self.seconds_past_midnight {
+            Some(secs) => (secs / 3600, secs / 60 % 60, secs % 60),
+            None => (0, 0, 0),
+        };
+        hour = self.hour.unwrap_or(hour);
+        minute = self.minute.unwrap_or(minute);
+        second = self.second.unwrap_or(second);
+        if self.hour12 {
+            if !(1..=12).contains(&hour) {
+                return Err(EvalError::InvalidParameterValue(format!(
+                    "hour \"{hour}\" is invalid for the 12-hour clock"
+                )));
+            }
+            hour %= 12;
+            if self.pm {
+                hour += 12;
+            }
+        }
+        let time = NaiveTime::from_hms_nano_opt(
+            to_u32(hour)?,
+            to_u32(minute)?,
+            to_u32(second)?,
+            to_u32(self.nanosecond)?,
+        )
+        .ok_or_else(out_of_range)?;
+
+        let mut offset = self
+            .tz_hours
+            .checked_mul(3600)
+            .zip(self.tz_minutes.checked_mul(60))
+            .and_then(|(hours, minutes)| hours.checked_add(minutes))
+            .ok_or_else(out_of_range)?;
+        if self.tz_negative {
+            offset = -offset;
+        }
+        Duration::try_seconds(offset)
+            .and_then(|offset| date.and_time(time).checked_sub_signed(offset))
+            .ok_or_else(out_of_range)
+    }
+}
+
+/// Adjusts a year with fewer than four digits to the year nearest to 2020 that
+/// ends in those digits, like PostgreSQL.
+fn adjust_partial_year_to_2020(year: i64) -> i64 {
+    match year {
+        // 70 through 99 are in the 1900s, and 0 through 69 in the 2000s.
+        0..=69 => year + 2000,
+        70..=99 => year + 1900,
+        // 520 through 999 are in the 1000s, and 100 through 519 in the 2000s.
+        100..=519 => year + 2000,
+        520..=999 => year + 1000,
+        _ => year,
+    }
+}
+
+/// Returns an error describing that the fields parsed from `s` do not form a
+/// valid timestamp.
+fn field_value_out_of_range(s: &str) -> EvalError {
+    EvalError::InvalidParameterValue(format!("date/time field value out of range: \"{s}\""))
+}
+
+/// Returns an error describing that `input` is not a valid value for `field`.
+fn invalid_value(input: &str, field: &DateTimeField) -> EvalError {
+    let msg = match input.split_whitespace().next() {
+        None => format!(
+            "source string too short for \"{}\" formatting field",
+            field.pattern()
+        ),
+        Some(value) => format!("invalid value \"{value}\" for \"{}\"", field.pattern()),
+    };
+    EvalError::InvalidParameterValue(msg)
+}
+
+/// Consumes an unsigned number of at most `max_width` digits from the start of
+/// `input`, and returns it along with the number of digits consumed.
+fn take_number(
+    input: &mut &str,
+    field: &DateTimeField,
+    max_width: Option<usize>,
+) -> Result<(i64, usize), EvalError> {
+    let digits = input
+        .bytes()
+        .take(max_width.unwrap_or(usize::MAX))
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    let n = match input[..digits].parse() {
+        Ok(n) if digits > 0 => n,
+        _ => return Err(invalid_value(input, field)),
+    };
+    *input = &input[digits..];
+    Ok((n, digits))
+}
+
+/// Like [`take_number`], but also accepts a leading sign.
+fn take_signed_number(
+    input: &mut &str,
+    field: &DateTimeField,
+    max_width: Option<usize>,
+) -> Result<(i64, usize), EvalError> {
+    let negative = match input.as_bytes().first() {
+        Some(b'-') => true,
+        Some(b'+') => false,
+        _ => return take_number(input, field, max_width),
+    };
+    let mut rest = &input[1..];
+    let (n, digits) =
+        take_number(&mut rest, field, max_width).map_err(|_| invalid_value(input, field))?;
+    *input = rest;
+    Ok((if negative { -n } else { n }, digits))
+}
+
+/// Consumes the longest of `words` that `input` starts with, ignoring case, and
+/// returns its index.
+fn take_word(input: &mut &str, words: &[&str]) -> Option<usize> {
+    let (i, len) = words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| {
+            input
+                .get(..word.len())
+                .map_or(false, |prefix| prefix.eq_ignore_ascii_case(word))
+        })
+        .map(|(i, word)| (i, word.len()))
+        .max_by_key(|(_, len)| *len)?;
+    *input = &input[len..];
+    Some(i)
+}
+
+/// Numeric format patterns that are recognized by PostgreSQL but are not yet
+/// supported by [`NumericFormat`].
+const UNSUPPORTED_NUMERIC_PATTERNS: &[&str] = &[
+    "EEEE", "eeee", "SG", "sg", "PR", "pr", "TH", "th", "B", "b", "L", "l", "V", "v",
+];
+
+/// An element of a numeric format string.
+enum NumericFormatNode {
+    /// A literal character.
+    Literal(char),
+    /// A digit position (`9` or `0`).
+    Digit {
+        /// Whether the position is padded with a leading zero (`0`) rather
+        /// than a blank (`9`).
+        zero: bool,
+    },
+    /// The decimal point (`.` or `D`).
+    DecimalPoint,
+    /// The group separator (`,` or `G`).
+    GroupSeparator,
+    /// A plus or minus sign (`S`).
+    Sign,
+    /// A minus sign if the value is negative (`MI`).
+    Minus,
+    /// A plus sign if the value is positive (`PL`).
+    Plus,
+    /// The value rendered as a Roman numeral (`RN` or `rn`).
+    RomanNumeral {
+        /// Whether to use uppercase letters.
+        caps: bool,
+    },
+}
+
+/// A compiled numeric format string, as used by `to_char` and `to_number`.
+///
+/// Supports the digit patterns `9` and `0`, the decimal point (`.` or `D`),
+/// the group separator (`,` or `G`), the sign patterns `S`, `MI`, and `PL`,
+/// the Roman numeral patterns `RN` and `rn`, and the fill mode modifier `FM`.
+/// Double-quoted text is taken literally, as are characters that are not part
+/// of any pattern. The decimal point and group separator are always `.` and
+/// `,`, respectively.
+pub struct NumericFormat {
+    nodes: Vec<NumericFormatNode>,
+    /// Whether the output should be padded with blanks to its full width.
+    fill: bool,
+}
+
+impl NumericFormat {
+    /// Compiles a new `NumericFormat` from the input string `s`, returning an
+    /// error if `s` contains an unsupported or invalid combination of
+    /// patterns.
+    pub fn compile(s: &str) -> Result<NumericFormat, EvalError> {
+        let mut nodes = Vec::new();
+        let mut fill = true;
+        let mut in_quotes = false;
+        let mut rest = s;
+        while let Some(c) = rest.chars().next() {
+            let (node, len) = if c == '"' {
+                in_quotes = !in_quotes;
+                (None, 1)
+            } else if in_quotes {
+                (Some(NumericFormatNode::Literal(c)), c.len_utf8())
+            } else if let Some(pattern) = UNSUPPORTED_NUMERIC_PATTERNS
+                .iter()
+                .find(|p| rest.starts_with(*p))
+            {
+                return Err(EvalError::Unsupported {
+                    feature: format!("numeric format pattern \"{}\"", pattern),
+                    issue_no: None,
+                });
+            } else if rest.starts_with("FM") || rest.starts_with("fm") {
+                fill = false;
+                (None, 2)
+            } else if rest.starts_with("RN") || rest.starts_with("rn") {
+                (Some(NumericFormatNode::RomanNumeral { caps: c == 'R' }), 2)
+            } else if rest.starts_with("MI") || rest.starts_with("mi") {
+                (Some(NumericFormatNode::Minus), 2)
+            } else if rest.starts_with("PL") || rest.starts_with("pl") {
+                (Some(NumericFormatNode::Plus), 2)
+            } else {
+                let node = match c {
+                    '9' => NumericFormatNode::Digit { zero: false },
+                    '0' => NumericFormatNode::Digit { zero: true },
+                    '.' | 'D' | 'd' => NumericFormatNode::DecimalPoint,
+                    ',' | 'G' | 'g' => NumericFormatNode::GroupSeparator,
+                    'S' | 's' => NumericFormatNode::Sign,
+                    _ => NumericFormatNode::Literal(c),
+                };
+                (Some(node), c.len_utf8())
+            };
+            nodes.extend(node);
+            rest = &rest[len..];
+        }
+
+        let count = |f: fn(&NumericFormatNode) -> bool| nodes.iter().filter(|n| f(n)).count();
+        if count(|n| matches!(n, NumericFormatNode::DecimalPoint)) > 1 {
+            return Err(EvalError::InvalidParameterValue(
+                "multiple decimal points".into(),
+            ));
+        }
+        if count(|n| {
+            matches!(
+                n,
+                NumericFormatNode::Sign | NumericFormatNode::Minus | NumericFormatNode::Plus
+            )
+        }) > 1
+        {
+            return Err(EvalError::InvalidParameterValue(
+                "cannot use multiple sign patterns together".into(),
+            ));
+        }
+        let roman = count(|n| matches!(n, NumericFormatNode::RomanNumeral { .. }));
+        let other = count(|n| !matches!(n, NumericFormatNode::Literal(_)));
+        if roman > 0 && other > roman {
+            return Err(EvalError::InvalidParameterValue(
+                "\"RN\" cannot be combined with other numeric format patterns".into(),
+            ));
+        }
+
+        Ok(NumericFormat { nodes, fill })
+    }
+
+    /// Returns whether the format contains an explicit sign pattern. If it
+    /// does not, a sign is rendered immediately before the first digit.
+    fn has_sign(&self) -> bool {
+        self.nodes.iter().any(|n| {
+            matches!(
+                n,
+                NumericFormatNode::Sign | NumericFormatNode::Minus | NumericFormatNode::Plus
+            )
+        })
+    }
+
+    /// Renders the format string using `n` as the input.
+    ///
+    /// Like PostgreSQL, every digit position is rendered as `#` if `n` has
+    /// more integral digits than the format has integral digit positions.
+    pub fn render(&self, n: i64) -> Result<String, EvalError> {
+        let negative = n < 0;
+        let int_positions = self
+            .nodes
+            .iter()
+            .take_while(|n| !matches!(n, NumericFormatNode::DecimalPoint))
+            .filter(|n| matches!(n, NumericFormatNode::Digit { .. }))
+            .count();
+        let digits: Vec<char> = if n == 0 && int_positions == 0 {
+            vec![]
+        } else {
+            n.unsigned_abs().to_string().chars().collect()
+        };
+        let overflow = digits.len() > int_positions;
+        let leading = int_positions.saturating_sub(digits.len());
+
+        let mut out = String::new();
+        // Whether the format lacks an explicit sign pattern and the implicit
+        // sign has yet to be rendered.
+        let mut sign_pending = !self.has_sign();
+        // Whether any part of the number itself has been rendered.
+        let mut started = false;
+        let mut zero_fill = false;
+        let mut in_fraction = false;
+        let mut int_pos = 0;
+        let mut start = |out: &mut String, started: &mut bool| {
+            if !*started {
+                *started = true;
+                if sign_pending {
+                    sign_pending = false;
+                    if negative {
+                        out.push('-');
+                    } else if self.fill {
+                        out.push(' ');
+                    }
+                }
+            }
+        };
+        for node in &self.nodes {
+            match node {
+                NumericFormatNode::Literal(c) => out.push(*c),
+                NumericFormatNode::Digit { zero } if in_fraction => {
+                    // The value is integral, so all fractional digits are
+                    // zero. Fill mode suppresses trailing zeros in `9`
+                    // positions.
+                    if overflow {
+                        out.push('#');
+                    } else if *zero || self.fill {
+                        out.push('0');
+                    }
+                }
+                NumericFormatNode::Digit { zero } => {
+                    zero_fill |= *zero;
+                    let c = if overflow {
+                        Some('#')
+                    } else if int_pos >= leading {
+                        Some(digits[int_pos - leading])
+                    } else if zero_fill {
+                        Some('0')
+                    } else {
+                        None
+                    };
+                    int_pos += 1;
+                    match c {
+                        Some(c) => {
+                            start(&mut out, &mut started);
+                            out.push(c);
+                        }
+                        None if self.fill => out.push(' '),
+                        None => (),
+                    }
+                }
+                NumericFormatNode::DecimalPoint => {
+                    start(&mut out, &mut started);
+                    in_fraction = true;
+                    out.push('.');
+                }
+                NumericFormatNode::GroupSeparator => {
+                    if started {
+                        out.push(',');
+                    } else if self.fill {
+                        out.push(' ');
+                    }
+                }
+                NumericFormatNode::Sign => out.push(if negative { '-' } else { '+' }),
+                NumericFormatNode::Minus => {
+                    if negative {
+                        out.push('-');
+                    } else if self.fill {
+                        out.push(' ');
+                    }
+                }
+                NumericFormatNode::Plus => {
+                    if !negative {
+                        out.push('+');
+                    } else if self.fill {
+                        out.push(' ');
+                    }
+                }
+                NumericFormatNode::RomanNumeral { caps } => {
+                    let roman = roman_numeral(n, *caps)?;
+                    let width = if self.fill { 15 } else { 0 };
+                    out.push_str(&format!("{:>width$}", roman));
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Parses `s` as a number according to the format string.
+    ///
+    /// Like PostgreSQL, parsing is lenient: each literal character in the
+    /// format skips one character of input, blanks before digits are ignored,
+    /// a sign may precede the digits if the format has no sign pattern, and
+    /// any input remaining after the format is exhausted is ignored.
+    pub fn parse(&self, s: &str) -> Result<OrderedDecimal<Numeric>, EvalError> {
+        let has_sign = self.has_sign();
+        let mut input = s.chars().peekable();
+        let mut negative = false;
+        let mut int_digits = String::new();
+        let mut frac_digits = String::new();
+        let mut in_fraction = false;
+        for node in &self.nodes {
+            match node {
+                NumericFormatNode::Literal(_) => {
+                    input.next();
+                }
+                NumericFormatNode::Digit { .. } => {
+                    while let Some(c) = input.peek() {
+                        match c {
+                            ' ' => {}
+                            '-' if !has_sign => negative = true,
+                            '+' if !has_sign => (),
+                            _ => break,
+                        }
+                        input.next();
+                    }
+                    if let Some(c) = input.next_if(|c| c.is_ascii_digit()) {
+                        if in_fraction {
+                            frac_digits.push(c);
+                        } else {
+                            int_digits.push(c);
+                        }
+                    }
+                }
+                NumericFormatNode::DecimalPoint => {
+                    input.next_if_eq(&'.');
+                    in_fraction = true;
+                }
+                NumericFormatNode::GroupSeparator => {
+                    input.next_if_eq(&',');
+                }
+                NumericFormatNode::Sign | NumericFormatNode::Minus | NumericFormatNode::Plus => {
+                    while input.next_if_eq(&' ').is_some() {}
+                    match input.peek() {
+                        Some('-') => negative = true,
+                        Some('+') => (),
+                        _ => continue,
+                    }
+                    input.next();
+                }
+                NumericFormatNode::RomanNumeral { .. } => {
+                    return Err(EvalError::Unsupported {
+                        feature: "to_number with Roman numerals".into(),
+                        issue_no: None,
+                    });
+                }
+            }
+        }
+
+        if int_digits.is_empty() && frac_digits.is_empty() {
+            return Err(ParseError::invalid_input_syntax("numeric", s).into());
+        }
+        let mut number = String::new();
+        if negative {
+            number.push('-');
+        }
+        number.push_str(&int_digits);
+        if !frac_digits.is_empty() {
+            number.push('.');
+            number.push_str(&frac_digits);
+        }
+        Ok(strconv::parse_numeric(&number)?)
+    }
+}
+
+/// Returns `n` as a Roman numeral. Only values between 1 and 3999 can be
+/// represented.
+fn roman_numeral(n: i64, caps: bool) -> Result<String, EvalError> {
+    const NUMERALS: [(i64, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if !(1..=3999).contains(&n) {
+        return Err(EvalError::InvalidParameterValue(format!(
+            "value {} out of range for roman numerals; must be between 1 and 3999",
+            n
+        )));
+    }
+    let mut remaining = n;
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while remaining >= value {
+            out.push_str(numeral);
+            remaining -= value;
+        }
+    }
+    if !caps {
+        out.make_ascii_lowercase();
+    }
+    Ok(out)
 }
@@ -9,13 +9,14 @@
 
 use std::fmt;
 
+use itertools::Itertools;
 use mz_lowertest::MzReflect;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::{ColumnType, Datum, Row, RowArena, RowPacker, ScalarType};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
-use crate::scalar::func::{stringify_datum, LazyUnaryFunc};
+use crate::scalar::func::{jsonb_object_from_pairs, stringify_datum, LazyUnaryFunc};
 use crate::{EvalError, MirScalarExpr};
 
 #[derive(
@@ -239,6 +240,82 @@ impl fmt::Display for CastArrayToJsonb {
     }
 }
 
+/// Builds a `jsonb` object from a flat array of alternating keys and values, or from a
+/// two-dimensional array of key/value pairs.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct JsonbObjectFlatArray;
+
+impl LazyUnaryFunc for JsonbObjectFlatArray {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+
+        let arr = a.unwrap_array();
+        let dims = arr.dims().into_iter().collect::<Vec<_>>();
+        match dims.as_slice() {
+            [] => {}
+            [dim] if dim.length % 2 != 0 => {
+                return Err(EvalError::InvalidParameterValue(
+                    "array must have even number of elements".into(),
+                ));
+            }
+            [_] => {}
+            [_, inner] if inner.length != 2 => {
+                return Err(EvalError::InvalidParameterValue(
+                    "array must have two columns".into(),
+                ));
+            }
+            [_, _] => {}
+            _ => {
+                return Err(EvalError::InvalidParameterValue(
+                    "wrong number of array subscripts".into(),
+                ));
+            }
+        }
+
+        jsonb_object_from_pairs(arr.elements().iter().tuples(), temp_storage)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Jsonb.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for JsonbObjectFlatArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("jsonb_object")
+    }
+}
+
 /// Casts an array of one type to an array of another type. Does so by casting
 /// each element of the first array to the desired inner type and collecting
 /// the results into a new array.
@@ -46,6 +46,19 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "boolean_to_smallint"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastInt16ToBool)]
+    #[is_monotone = true]
+    fn cast_bool_to_int16(a: bool) -> i16 {
+        match a {
+            true => 1,
+            false => 0,
+        }
+    }
+);
+
 sqlfunc!(
     #[sqlname = "boolean_to_integer"]
     #[preserves_uniqueness = true]
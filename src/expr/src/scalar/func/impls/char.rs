@@ -50,7 +50,9 @@ impl fmt::Display for PadChar {
 }
 
 // This function simply allows the expression of changing a's type from char to
-// string
+// string. Because char data is stored without its blank padding, the result has
+// no trailing blanks, which matches PostgreSQL's char-to-text cast. Casts from
+// char to varchar go through the same trimmed representation.
 sqlfunc!(
     #[sqlname = "char_to_text"]
     #[preserves_uniqueness = true]
@@ -129,6 +129,7 @@ pub fn extract_date_inner(units: DateTimeUnits, date: NaiveDate) -> Result<Numer
         DateTimeUnits::DayOfWeek => Ok(Numeric::from(date.day_of_week())),
         DateTimeUnits::DayOfYear => Ok(Numeric::from(date.ordinal())),
         DateTimeUnits::IsoDayOfWeek => Ok(Numeric::from(date.iso_day_of_week())),
+        DateTimeUnits::IsoYear => Ok(Numeric::from(date.iso_year())),
         DateTimeUnits::Hour
         | DateTimeUnits::Minute
         | DateTimeUnits::Second
@@ -393,7 +393,9 @@ sqlfunc!(
 
 sqlfunc!(
     fn degrees(a: f64) -> f64 {
-        a.to_degrees()
+        // Divide by the radians per degree rather than multiplying by the degrees per radian
+        // (as `f64::to_degrees` does), which matches PostgreSQL's results exactly.
+        a / (std::f64::consts::PI / 180.0)
     }
 );
 
@@ -45,6 +45,15 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "smallint_to_boolean"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastBoolToInt16)]
+    fn cast_int16_to_bool(a: i16) -> bool {
+        a != 0
+    }
+);
+
 sqlfunc!(
     #[sqlname = "smallint_to_real"]
     #[preserves_uniqueness = true]
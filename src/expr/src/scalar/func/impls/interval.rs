@@ -26,6 +26,56 @@ sqlfunc!(
     }
 );
 
+// Renders an interval as a compact English duration, like `2 days 3 hours`.
+//
+// The output is the nonzero components of the interval, from largest to
+// smallest, separated by single spaces. Each component is written as
+// `<n> <unit>`, where the unit is one of `year`, `month`, `day`, `hour`,
+// `minute`, or `second`, pluralized unless `<n>` is 1 or -1. Seconds have
+// up to six fractional digits, with trailing zeros removed. The components
+// of an interval are signed independently, so each carries its own sign,
+// e.g. `1 month -2 days`. The zero interval is written as `0 seconds`.
+sqlfunc!(
+    #[sqlname = "interval_to_human_text"]
+    fn interval_to_human_text(i: Interval) -> String {
+        const MICROS_PER_SECOND: i64 = 1_000_000;
+        const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+        const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+
+        let mut parts = Vec::new();
+        let months = i64::from(i.months);
+        let month_per_year = i64::from(Interval::MONTH_PER_YEAR);
+        let components = [
+            (months / month_per_year, "year"),
+            (months % month_per_year, "month"),
+            (i64::from(i.days), "day"),
+            (i.micros / MICROS_PER_HOUR, "hour"),
+            (i.micros % MICROS_PER_HOUR / MICROS_PER_MINUTE, "minute"),
+        ];
+        for (n, unit) in components {
+            if n != 0 {
+                let plural = if n.unsigned_abs() == 1 { "" } else { "s" };
+                parts.push(format!("{n} {unit}{plural}"));
+            }
+        }
+
+        let micros = i.micros % MICROS_PER_MINUTE;
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let mut seconds = format!("{sign}{}", micros / 1_000_000);
+            let fraction = micros % 1_000_000;
+            if fraction != 0 {
+                seconds.push('.');
+                seconds.push_str(format!("{fraction:06}").trim_end_matches('0'));
+            }
+            let plural = if micros == 1_000_000 { "" } else { "s" };
+            parts.push(format!("{seconds} second{plural}"));
+        }
+        parts.join(" ")
+    }
+);
+
 sqlfunc!(
     #[sqlname = "interval_to_time"]
     #[preserves_uniqueness = false]
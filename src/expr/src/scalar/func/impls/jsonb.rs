@@ -10,6 +10,7 @@
 use std::fmt;
 
 use mz_lowertest::MzReflect;
+use mz_ore::str::StrExt;
 use mz_repr::adt::jsonb::{Jsonb, JsonbRef};
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::{strconv, ColumnType, Datum, Row, RowPacker, ScalarType};
@@ -17,6 +18,7 @@ use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
 use crate::scalar::func::impls::numeric::*;
+use crate::scalar::func::jsonpath::JsonPath;
 use crate::scalar::func::EagerUnaryFunc;
 use crate::EvalError;
 
@@ -143,6 +145,52 @@ impl fmt::Display for CastJsonbToNumeric {
     }
 }
 
+/// The `@?` operator with a path that was parsed at planning time.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct JsonbPathMatchExists(pub JsonPath);
+
+impl<'a> EagerUnaryFunc<'a> for JsonbPathMatchExists {
+    type Input = JsonbRef<'a>;
+    type Output = bool;
+
+    fn call(&self, a: JsonbRef<'a>) -> bool {
+        self.0.exists(a.into_datum())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Bool.nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for JsonbPathMatchExists {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "jsonb_path_match_exists[{}]", self.0.source.quoted())
+    }
+}
+
+/// The `@@` operator with a path that was parsed at planning time.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct JsonbPathMatchPredicate(pub JsonPath);
+
+impl<'a> EagerUnaryFunc<'a> for JsonbPathMatchPredicate {
+    type Input = JsonbRef<'a>;
+    type Output = Option<bool>;
+
+    fn call(&self, a: JsonbRef<'a>) -> Option<bool> {
+        self.0.predicate_match(a.into_datum())
+    }
+
+    fn output_type(&self, _input: ColumnType) -> ColumnType {
+        ScalarType::Bool.nullable(true)
+    }
+}
+
+impl fmt::Display for JsonbPathMatchPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "jsonb_path_match_predicate[{}]", self.0.source.quoted())
+    }
+}
+
 sqlfunc!(
     #[sqlname = "jsonb_to_boolean"]
     #[is_monotone = true]
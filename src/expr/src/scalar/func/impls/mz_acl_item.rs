@@ -86,14 +86,18 @@ sqlfunc!(
 sqlfunc!(
     #[sqlname = "mz_validate_role_privilege"]
     fn mz_validate_role_privilege(privilege: String) -> Result<bool, EvalError> {
-        let privilege_upper = privilege.to_uppercase();
-        if privilege_upper != "MEMBER" && privilege_upper != "USAGE" {
-            Err(EvalError::InvalidPrivileges(format!(
-                "{}",
-                privilege.quoted()
-            )))
-        } else {
-            Ok(true)
+        // Like PostgreSQL, accept a comma separated list of privileges and report the first
+        // unrecognized one.
+        for privilege in privilege.split(',') {
+            let privilege = privilege.trim();
+            let privilege_upper = privilege.to_uppercase();
+            if privilege_upper != "MEMBER" && privilege_upper != "USAGE" {
+                return Err(EvalError::InvalidPrivileges(format!(
+                    "{}",
+                    privilege.quoted()
+                )));
+            }
         }
+        Ok(true)
     }
 );
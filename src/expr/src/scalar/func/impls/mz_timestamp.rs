@@ -131,6 +131,7 @@ sqlfunc!(
 sqlfunc!(
     #[sqlname = "mz_timestamp_to_timestamp"]
     #[preserves_uniqueness = true]
+    #[is_monotone = true]
     #[inverse = to_unary!(super::CastTimestampToMzTimestamp)]
     fn cast_mz_timestamp_to_timestamp(
         a: Timestamp,
@@ -147,6 +148,7 @@ sqlfunc!(
 sqlfunc!(
     #[sqlname = "mz_timestamp_to_timestamp_tz"]
     #[preserves_uniqueness = true]
+    #[is_monotone = true]
     #[inverse = to_unary!(super::CastTimestampTzToMzTimestamp)]
     fn cast_mz_timestamp_to_timestamp_tz(
         a: Timestamp,
@@ -13,6 +13,7 @@ use dec::{OrderedDecimal, Rounding};
 use mz_lowertest::MzReflect;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::{strconv, ColumnType, ScalarType};
+use once_cell::sync::Lazy;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -72,6 +73,50 @@ sqlfunc!(
     }
 );
 
+/// π, rounded to the precision of a [`Numeric`].
+static PI: Lazy<Numeric> = Lazy::new(|| {
+    numeric::cx_datum()
+        .parse("3.14159265358979323846264338327950288419716939937510")
+        .unwrap()
+});
+
+sqlfunc!(
+    #[sqlname = "degreesnumeric"]
+    fn degrees_numeric(mut a: Numeric) -> Result<Numeric, EvalError> {
+        // Multiply before dividing, so that only the division rounds.
+        let mut cx = numeric::cx_datum();
+        cx.mul(&mut a, &Numeric::from(180));
+        cx.div(&mut a, &PI);
+        let cx_status = cx.status();
+        if cx_status.overflow() {
+            Err(EvalError::FloatOverflow)
+        } else if cx_status.subnormal() {
+            Err(EvalError::FloatUnderflow)
+        } else {
+            numeric::munge_numeric(&mut a).unwrap();
+            Ok(a)
+        }
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "radiansnumeric"]
+    fn radians_numeric(mut a: Numeric) -> Result<Numeric, EvalError> {
+        let mut cx = numeric::cx_datum();
+        cx.mul(&mut a, &PI);
+        cx.div(&mut a, &Numeric::from(180));
+        let cx_status = cx.status();
+        if cx_status.overflow() {
+            Err(EvalError::FloatOverflow)
+        } else if cx_status.subnormal() {
+            Err(EvalError::FloatUnderflow)
+        } else {
+            numeric::munge_numeric(&mut a).unwrap();
+            Ok(a)
+        }
+    }
+);
+
 sqlfunc!(
     #[sqlname = "floornumeric"]
     #[is_monotone = true]
@@ -299,6 +344,16 @@ sqlfunc!(
     }
 );
 
+/// Rescales a numeric to at most the given scale, rounding away any excess
+/// digits.
+///
+/// This function has no [`inverse`](EagerUnaryFunc::inverse), even for casts
+/// that widen the scale. The function does not know the scale of its input, so
+/// it cannot distinguish a lossless widening rescale from a rounding narrowing
+/// one. Even for a widening rescale, the right inverse would need to reject
+/// values with more digits than the input type allows. Rescaling back to the
+/// narrower scale would round them instead, e.g. rewriting
+/// `col::numeric(10,4) = 3.1415` into `col = 3.14`.
 #[derive(
     Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
 )]
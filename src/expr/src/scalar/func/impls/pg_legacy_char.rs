@@ -78,3 +78,12 @@ sqlfunc!(
         i32::from(i8::from_ne_bytes([a.0]))
     }
 );
+
+sqlfunc!(
+    #[sqlname = "ascii"]
+    fn ascii_pg_legacy_char(a: PgLegacyChar) -> i32 {
+        // Unlike the cast to `i32`, which treats `PgLegacyChar` as signed,
+        // this returns the unsigned value of the byte.
+        i32::from(a.0)
+    }
+);
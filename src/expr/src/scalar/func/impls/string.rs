@@ -838,6 +838,18 @@ sqlfunc!(
     }
 );
 
+// Like `ascii`, but for the last character. Returns 0 for the empty string to
+// match `ascii`.
+sqlfunc!(
+    #[sqlname = "last_codepoint"]
+    fn last_code_point<'a>(a: &'a str) -> i32 {
+        a.chars()
+            .next_back()
+            .and_then(|c| i32::try_from(u32::from(c)).ok())
+            .unwrap_or(0)
+    }
+);
+
 sqlfunc!(
     #[sqlname = "char_length"]
     fn char_length<'a>(a: &'a str) -> Result<i32, EvalError> {
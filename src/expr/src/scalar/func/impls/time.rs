@@ -74,7 +74,8 @@ where
         | DateTimeUnits::DayOfYear
         | DateTimeUnits::DayOfWeek
         | DateTimeUnits::IsoDayOfYear
-        | DateTimeUnits::IsoDayOfWeek => Err(EvalError::UnsupportedUnits(
+        | DateTimeUnits::IsoDayOfWeek
+        | DateTimeUnits::IsoYear => Err(EvalError::UnsupportedUnits(
             format!("{}", units),
             "time".to_string(),
         )),
@@ -311,7 +311,8 @@ where
         | DateTimeUnits::DayOfWeek
         | DateTimeUnits::DayOfYear
         | DateTimeUnits::IsoDayOfWeek
-        | DateTimeUnits::IsoDayOfYear => Err(EvalError::Unsupported {
+        | DateTimeUnits::IsoDayOfYear
+        | DateTimeUnits::IsoYear => Err(EvalError::Unsupported {
             feature: format!("'{}' timestamp units", units),
             issue_no: None,
         }),
@@ -384,15 +385,26 @@ where
         DateTimeUnits::DayOfWeek => Ok(D::from(ts.day_of_week())),
         DateTimeUnits::DayOfYear => Ok(D::from(ts.ordinal())),
         DateTimeUnits::IsoDayOfWeek => Ok(D::from(ts.iso_day_of_week())),
+        DateTimeUnits::IsoYear => Ok(D::from(ts.iso_year())),
         DateTimeUnits::Hour => Ok(D::from(ts.hour())),
         DateTimeUnits::Minute => Ok(D::from(ts.minute())),
         DateTimeUnits::Second => Ok(ts.extract_second()),
         DateTimeUnits::Milliseconds => Ok(ts.extract_millisecond()),
         DateTimeUnits::Microseconds => Ok(ts.extract_microsecond()),
-        DateTimeUnits::Timezone
-        | DateTimeUnits::TimezoneHour
-        | DateTimeUnits::TimezoneMinute
-        | DateTimeUnits::IsoDayOfYear => Err(EvalError::Unsupported {
+        DateTimeUnits::Timezone | DateTimeUnits::TimezoneHour | DateTimeUnits::TimezoneMinute => {
+            let offset = ts.timezone_offset_seconds().ok_or_else(|| {
+                EvalError::UnsupportedUnits(
+                    units.to_string(),
+                    "timestamp without time zone".to_string(),
+                )
+            })?;
+            Ok(D::from(match units {
+                DateTimeUnits::Timezone => offset,
+                DateTimeUnits::TimezoneHour => offset / 3600,
+                _ => offset / 60 % 60,
+            }))
+        }
+        DateTimeUnits::IsoDayOfYear => Err(EvalError::Unsupported {
             feature: format!("'{}' timestamp units", units),
             issue_no: None,
         }),
@@ -541,7 +553,8 @@ pub fn date_trunc_inner<T: TimestampLike>(units: DateTimeUnits, ts: &T) -> Resul
         | DateTimeUnits::DayOfWeek
         | DateTimeUnits::DayOfYear
         | DateTimeUnits::IsoDayOfWeek
-        | DateTimeUnits::IsoDayOfYear => Err(EvalError::Unsupported {
+        | DateTimeUnits::IsoDayOfYear
+        | DateTimeUnits::IsoYear => Err(EvalError::Unsupported {
             feature: format!("'{}' timestamp units", units),
             issue_no: None,
         }),
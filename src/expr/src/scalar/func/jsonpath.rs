@@ -0,0 +1,808 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A subset of PostgreSQL's SQL/JSON path language.
+//!
+//! Paths are always evaluated in lax mode. The supported syntax is:
+//!
+//!   * the context item `$` and, within filters, the current item `@`;
+//!   * member accessors `.key`, `."key"`, and `.*`;
+//!   * array accessors `[n]`, `[last]`, and `[*]`;
+//!   * filter expressions `? (predicate)`;
+//!   * comparisons `==`, `!=`, `<>`, `<`, `<=`, `>`, and `>=`;
+//!   * the logical operators `&&`, `||`, and `!`, and `exists (path)`;
+//!   * string, numeric, `true`, `false`, and `null` literals.
+//!
+//! See <https://www.postgresql.org/docs/current/functions-json.html#FUNCTIONS-SQLJSON-PATH>
+//! for the semantics that this module mirrors.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use dec::OrderedDecimal;
+use mz_lowertest::MzReflect;
+use mz_ore::lex::LexBuf;
+use mz_repr::adt::numeric::Numeric;
+use mz_repr::{strconv, Datum};
+use proptest::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::EvalError;
+
+/// The maximum nesting depth of parentheses and filters in a path.
+const MAX_DEPTH: usize = 128;
+
+/// A parsed SQL/JSON path expression.
+///
+/// Paths compare, hash, and serialize by their source text, so that a path
+/// parsed once at planning time can be embedded in a function.
+#[derive(Debug, Clone, MzReflect)]
+pub struct JsonPath {
+    /// The text the path was parsed from.
+    pub source: String,
+    #[mzreflect(ignore)]
+    expr: Arc<Expr>,
+}
+
+impl JsonPath {
+    /// Parses a path expression.
+    pub fn parse(s: &str) -> Result<JsonPath, EvalError> {
+        let mut parser = Parser {
+            buf: LexBuf::new(s),
+            depth: 0,
+            filter_depth: 0,
+        };
+        parser.skip_whitespace();
+        if parser.consume_keyword("strict") {
+            return Err(EvalError::InvalidParameterValue(
+                "strict mode jsonpath expressions are not supported".into(),
+            ));
+        }
+        parser.consume_keyword("lax");
+        let expr = parser.parse_expr()?;
+        parser.skip_whitespace();
+        if parser.buf.peek().is_some() {
+            return Err(parser.syntax_error());
+        }
+        Ok(JsonPath {
+            source: s.to_owned(),
+            expr: Arc::new(expr),
+        })
+    }
+
+    /// Reports whether the path returns any item for `root`, as for
+    /// PostgreSQL's `@?` operator.
+    ///
+    /// Predicate check expressions always return an item, so are always
+    /// considered to exist.
+    pub fn exists(&self, root: Datum) -> bool {
+        match &*self.expr {
+            Expr::Operand(operand) => !operand.eval(root, root).is_empty(),
+            Expr::Predicate(_) => true,
+        }
+    }
+
+    /// Returns the result of the path's predicate check for `root`, as for
+    /// PostgreSQL's `@@` operator.
+    ///
+    /// Returns `None` if the result is unknown or if the path does not return
+    /// a single boolean.
+    pub fn predicate_match(&self, root: Datum) -> Option<bool> {
+        match &*self.expr {
+            Expr::Operand(operand) => match operand.eval(root, root)[..] {
+                [Datum::True] => Some(true),
+                [Datum::False] => Some(false),
+                _ => None,
+            },
+            Expr::Predicate(predicate) => predicate.eval(root, root),
+        }
+    }
+}
+
+/// Generates a path from a fixed set of valid path expressions.
+pub fn any_json_path() -> impl Strategy<Value = JsonPath> {
+    proptest::sample::select(vec![
+        "$",
+        "$.a",
+        "$.a[*] > 2",
+        "$.a ? (@ > 4)",
+        "$.a[last] == 5",
+        "exists($.b.c) && !($.a[0] == 2)",
+    ])
+    .prop_map(|source| JsonPath::parse(source).expect("valid path"))
+}
+
+impl PartialEq for JsonPath {
+    fn eq(&self, other: &JsonPath) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for JsonPath {}
+
+impl PartialOrd for JsonPath {
+    fn partial_cmp(&self, other: &JsonPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonPath {
+    fn cmp(&self, other: &JsonPath) -> Ordering {
+        self.source.cmp(&other.source)
+    }
+}
+
+impl Hash for JsonPath {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.source.hash(hasher);
+    }
+}
+
+impl Serialize for JsonPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let source = String::deserialize(deserializer)?;
+        JsonPath::parse(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug)]
+enum Expr {
+    Operand(Operand),
+    Predicate(Predicate),
+}
+
+#[derive(Debug)]
+enum Operand {
+    Path(Path),
+    Literal(Literal),
+}
+
+#[derive(Debug)]
+enum Literal {
+    Null,
+    Bool(bool),
+    Numeric(OrderedDecimal<Numeric>),
+    String(String),
+}
+
+#[derive(Debug)]
+struct Path {
+    /// Whether the path starts at the current item (`@`) rather than the
+    /// context item (`$`).
+    current: bool,
+    accessors: Vec<Accessor>,
+}
+
+#[derive(Debug)]
+enum Accessor {
+    Member(String),
+    MemberWildcard,
+    Element(ElementIndex),
+    ElementWildcard,
+    Filter(Box<Predicate>),
+}
+
+#[derive(Debug)]
+enum ElementIndex {
+    Position(i64),
+    Last,
+}
+
+#[derive(Debug)]
+enum Predicate {
+    Compare(Operand, CompareOp, Operand),
+    Exists(Path),
+    /// A chain of `&&` operators. Chains are kept flat so that their length,
+    /// which is not bounded by [`MAX_DEPTH`], does not affect the depth of the
+    /// predicate tree.
+    And(Vec<Predicate>),
+    /// A chain of `||` operators, kept flat like [`Predicate::And`].
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl CompareOp {
+    fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ordering == Ordering::Equal,
+            CompareOp::NotEq => ordering != Ordering::Equal,
+            CompareOp::Lt => ordering == Ordering::Less,
+            CompareOp::Lte => ordering != Ordering::Greater,
+            CompareOp::Gt => ordering == Ordering::Greater,
+            CompareOp::Gte => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Returns the elements of `datum` if it is an array, or `datum` itself
+/// otherwise, as lax mode does when an array is used where a single item is
+/// expected.
+fn unwrap_array(datum: Datum) -> Vec<Datum> {
+    match datum {
+        Datum::List(list) => list.iter().collect(),
+        datum => vec![datum],
+    }
+}
+
+impl Operand {
+    fn eval<'a>(&'a self, root: Datum<'a>, current: Datum<'a>) -> Vec<Datum<'a>> {
+        match self {
+            Operand::Path(path) => path.eval(root, current),
+            Operand::Literal(literal) => vec![literal.to_datum()],
+        }
+    }
+}
+
+impl Literal {
+    fn to_datum(&self) -> Datum {
+        match self {
+            Literal::Null => Datum::JsonNull,
+            Literal::Bool(b) => Datum::from(*b),
+            Literal::Numeric(n) => Datum::Numeric(*n),
+            Literal::String(s) => Datum::String(s),
+        }
+    }
+}
+
+impl Path {
+    fn eval<'a>(&'a self, root: Datum<'a>, current: Datum<'a>) -> Vec<Datum<'a>> {
+        let mut items = vec![if self.current { current } else { root }];
+        for accessor in &self.accessors {
+            let mut next = Vec::new();
+            for item in items {
+                accessor.apply(item, root, &mut next);
+            }
+            items = next;
+        }
+        items
+    }
+}
+
+impl Accessor {
+    fn apply<'a>(&'a self, item: Datum<'a>, root: Datum<'a>, out: &mut Vec<Datum<'a>>) {
+        match self {
+            Accessor::Member(key) => {
+                for item in unwrap_array(item) {
+                    if let Datum::Map(map) = item {
+                        out.extend(map.iter().find(|(k, _)| *k == key.as_str()).map(|(_, v)| v));
+                    }
+                }
+            }
+            Accessor::MemberWildcard => {
+                for item in unwrap_array(item) {
+                    if let Datum::Map(map) = item {
+                        out.extend(map.iter().map(|(_, v)| v));
+                    }
+                }
+            }
+            Accessor::Element(index) => {
+                let elements = unwrap_array(item);
+                let index = match index {
+                    ElementIndex::Position(i) => usize::try_from(*i).ok(),
+                    ElementIndex::Last => elements.len().checked_sub(1),
+                };
+                out.extend(index.and_then(|i| elements.get(i)));
+            }
+            Accessor::ElementWildcard => out.extend(unwrap_array(item)),
+            Accessor::Filter(predicate) => {
+                for item in unwrap_array(item) {
+                    if predicate.eval(root, item) == Some(true) {
+                        out.push(item);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Predicate {
+    /// Evaluates the predicate, returning `None` if the result is unknown.
+    fn eval<'a>(&'a self, root: Datum<'a>, current: Datum<'a>) -> Option<bool> {
+        match self {
+            Predicate::Compare(left, op, right) => {
+                let left: Vec<_> = left
+                    .eval(root, current)
+                    .into_iter()
+                    .flat_map(unwrap_array)
+                    .collect();
+                let right: Vec<_> = right
+                    .eval(root, current)
+                    .into_iter()
+                    .flat_map(unwrap_array)
+                    .collect();
+                let mut unknown = false;
+                for l in &left {
+                    for r in &right {
+                        match compare(*l, *op, *r) {
+                            Some(true) => return Some(true),
+                            Some(false) => {}
+                            None => unknown = true,
+                        }
+                    }
+                }
+                if unknown {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            Predicate::Exists(path) => Some(!path.eval(root, current).is_empty()),
+            Predicate::And(predicates) => {
+                let mut result = Some(true);
+                for predicate in predicates {
+                    match predicate.eval(root, current) {
+                        Some(false) => return Some(false),
+                        Some(true) => {}
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            Predicate::Or(predicates) => {
+                let mut result = Some(false);
+                for predicate in predicates {
+                    match predicate.eval(root, current) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            Predicate::Not(predicate) => predicate.eval(root, current).map(|b| !b),
+        }
+    }
+}
+
+/// Compares two items, returning `None` if they are not comparable.
+fn compare(left: Datum, op: CompareOp, right: Datum) -> Option<bool> {
+    let ordering = match (left, right) {
+        (Datum::JsonNull, Datum::JsonNull) => Ordering::Equal,
+        // `null` is only equal to itself, but is not incomparable to anything.
+        (Datum::JsonNull, _) | (_, Datum::JsonNull) => return Some(op == CompareOp::NotEq),
+        (Datum::True | Datum::False, Datum::True | Datum::False) => {
+            (left == Datum::True).cmp(&(right == Datum::True))
+        }
+        (Datum::Numeric(l), Datum::Numeric(r)) => l.cmp(&r),
+        (Datum::String(l), Datum::String(r)) => l.cmp(r),
+        _ => return None,
+    };
+    Some(op.matches(ordering))
+}
+
+struct Parser<'a> {
+    buf: LexBuf<'a>,
+    /// The current nesting depth of parentheses and filters.
+    depth: usize,
+    /// The number of filters enclosing the current position, within which `@`
+    /// may be used.
+    filter_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn syntax_error(&self) -> EvalError {
+        let rest = &self.buf.inner()[self.buf.pos()..];
+        let message = match rest.split_whitespace().next() {
+            Some(token) => format!("syntax error at or near \"{token}\" of jsonpath input"),
+            None => "syntax error at end of jsonpath input".into(),
+        };
+        EvalError::InvalidParameterValue(message)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.buf.take_while(|ch| ch.is_ascii_whitespace());
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), EvalError> {
+        self.skip_whitespace();
+        if self.buf.consume(ch) {
+            Ok(())
+        } else {
+            Err(self.syntax_error())
+        }
+    }
+
+    /// Consumes `keyword` if it is the next identifier in the buffer.
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let rest = &self.buf.inner()[self.buf.pos()..];
+        let is_keyword = rest.starts_with(keyword)
+            && !rest[keyword.len()..]
+                .chars()
+                .next()
+                .map_or(false, is_identifier_char);
+        if is_keyword {
+            self.buf.consume_str(keyword);
+        }
+        is_keyword
+    }
+
+    fn enter(&mut self) -> Result<(), EvalError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(EvalError::InvalidParameterValue(
+                "jsonpath expression is nested too deeply".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn parse_predicate(&mut self) -> Result<Predicate, EvalError> {
+        match self.parse_expr()? {
+            Expr::Predicate(predicate) => Ok(predicate),
+            Expr::Operand(_) => Err(self.syntax_error()),
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, EvalError> {
+        let expr = self.parse_and()?;
+        self.skip_whitespace();
+        if !self.buf.consume_str("||") {
+            return Ok(expr);
+        }
+        let mut predicates = vec![into_predicate(expr, self)?];
+        loop {
+            predicates.push(into_predicate(self.parse_and()?, self)?);
+            self.skip_whitespace();
+            if !self.buf.consume_str("||") {
+                return Ok(Expr::Predicate(Predicate::Or(predicates)));
+            }
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, EvalError> {
+        let expr = self.parse_unary()?;
+        self.skip_whitespace();
+        if !self.buf.consume_str("&&") {
+            return Ok(expr);
+        }
+        let mut predicates = vec![into_predicate(expr, self)?];
+        loop {
+            predicates.push(into_predicate(self.parse_unary()?, self)?);
+            self.skip_whitespace();
+            if !self.buf.consume_str("&&") {
+                return Ok(Expr::Predicate(Predicate::And(predicates)));
+            }
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, EvalError> {
+        self.skip_whitespace();
+        if self.buf.consume('!') {
+            self.expect('(')?;
+            self.enter()?;
+            let predicate = self.parse_predicate()?;
+            self.depth -= 1;
+            self.expect(')')?;
+            return Ok(Expr::Predicate(Predicate::Not(Box::new(predicate))));
+        }
+        if self.consume_keyword("exists") {
+            self.expect('(')?;
+            self.enter()?;
+            self.skip_whitespace();
+            let path = match self.parse_operand()? {
+                Operand::Path(path) => path,
+                Operand::Literal(_) => return Err(self.syntax_error()),
+            };
+            self.depth -= 1;
+            self.expect(')')?;
+            return Ok(Expr::Predicate(Predicate::Exists(path)));
+        }
+
+        let left = if self.buf.consume('(') {
+            self.enter()?;
+            let expr = self.parse_expr()?;
+            self.depth -= 1;
+            self.expect(')')?;
+            match expr {
+                Expr::Predicate(predicate) => return Ok(Expr::Predicate(predicate)),
+                Expr::Operand(operand) => operand,
+            }
+        } else {
+            self.parse_operand()?
+        };
+
+        self.skip_whitespace();
+        let op = if self.buf.consume_str("==") {
+            CompareOp::Eq
+        } else if self.buf.consume_str("!=") || self.buf.consume_str("<>") {
+            CompareOp::NotEq
+        } else if self.buf.consume_str("<=") {
+            CompareOp::Lte
+        } else if self.buf.consume_str(">=") {
+            CompareOp::Gte
+        } else if self.buf.consume('<') {
+            CompareOp::Lt
+        } else if self.buf.consume('>') {
+            CompareOp::Gt
+        } else {
+            return Ok(Expr::Operand(left));
+        };
+        self.skip_whitespace();
+        let right = self.parse_operand()?;
+        Ok(Expr::Predicate(Predicate::Compare(left, op, right)))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, EvalError> {
+        if self.buf.consume('$') {
+            return Ok(Operand::Path(self.parse_accessors(false)?));
+        }
+        if self.buf.consume('@') {
+            if self.filter_depth == 0 {
+                return Err(EvalError::InvalidParameterValue(
+                    "@ is not allowed in root expressions".into(),
+                ));
+            }
+            return Ok(Operand::Path(self.parse_accessors(true)?));
+        }
+        if self.buf.consume('"') {
+            return Ok(Operand::Literal(Literal::String(self.parse_string()?)));
+        }
+        if self.consume_keyword("null") {
+            return Ok(Operand::Literal(Literal::Null));
+        }
+        if self.consume_keyword("true") {
+            return Ok(Operand::Literal(Literal::Bool(true)));
+        }
+        if self.consume_keyword("false") {
+            return Ok(Operand::Literal(Literal::Bool(false)));
+        }
+        match self.buf.peek() {
+            Some(ch) if ch == '-' || ch == '.' || ch.is_ascii_digit() => {
+                Ok(Operand::Literal(Literal::Numeric(self.parse_numeric()?)))
+            }
+            _ => Err(self.syntax_error()),
+        }
+    }
+
+    fn parse_accessors(&mut self, current: bool) -> Result<Path, EvalError> {
+        let mut accessors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.buf.consume('.') {
+                if self.buf.consume('*') {
+                    accessors.push(Accessor::MemberWildcard);
+                } else if self.buf.consume('"') {
+                    accessors.push(Accessor::Member(self.parse_string()?));
+                } else {
+                    let key = self.buf.take_while(is_identifier_char);
+                    if key.is_empty() || self.buf.peek() == Some('(') {
+                        return Err(self.syntax_error());
+                    }
+                    accessors.push(Accessor::Member(key.into()));
+                }
+            } else if self.buf.consume('[') {
+                self.skip_whitespace();
+                if self.buf.consume('*') {
+                    accessors.push(Accessor::ElementWildcard);
+                } else if self.consume_keyword("last") {
+                    accessors.push(Accessor::Element(ElementIndex::Last));
+                } else {
+                    let index = self.buf.take_while(|ch| ch == '-' || ch.is_ascii_digit());
+                    let index = strconv::parse_int64(index).map_err(|_| self.syntax_error())?;
+                    accessors.push(Accessor::Element(ElementIndex::Position(index)));
+                }
+                self.expect(']')?;
+            } else if self.buf.consume('?') {
+                self.expect('(')?;
+                self.enter()?;
+                self.filter_depth += 1;
+                let predicate = self.parse_predicate()?;
+                self.filter_depth -= 1;
+                self.depth -= 1;
+                self.expect(')')?;
+                accessors.push(Accessor::Filter(Box::new(predicate)));
+            } else {
+                return Ok(Path { current, accessors });
+            }
+        }
+    }
+
+    /// Parses the remainder of a double-quoted string, whose opening quote has
+    /// already been consumed.
+    fn parse_string(&mut self) -> Result<String, EvalError> {
+        let mut s = String::new();
+        loop {
+            match self.buf.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.buf.next() {
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => s.push(self.parse_unicode_escape()?),
+                    Some(ch) => s.push(ch),
+                    None => return Err(self.syntax_error()),
+                },
+                Some(ch) => s.push(ch),
+                None => {
+                    return Err(EvalError::InvalidParameterValue(
+                        "unterminated quoted string in jsonpath input".into(),
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Parses the remainder of a `\uXXXX` or `\u{X...}` escape, whose `\u` has already been
+    /// consumed. A UTF-16 surrogate pair must be written as two consecutive `\uXXXX` escapes.
+    fn parse_unicode_escape(&mut self) -> Result<char, EvalError> {
+        let high = self.parse_unicode_code_point()?;
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+            if !self.buf.consume_str("\\u") {
+                return Err(self.invalid_unicode_escape());
+            }
+            let low = self.parse_unicode_code_point()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.invalid_unicode_escape());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code_point).ok_or_else(|| self.invalid_unicode_escape())
+    }
+
+    /// Parses the hex digits of a single `\u` escape.
+    fn parse_unicode_code_point(&mut self) -> Result<u32, EvalError> {
+        let digits = if self.buf.consume('{') {
+            let digits = self.buf.take_while(|ch| ch.is_ascii_hexdigit());
+            if !(1..=6).contains(&digits.len()) || !self.buf.consume('}') {
+                return Err(self.invalid_unicode_escape());
+            }
+            digits
+        } else {
+            match self.buf.next_n(4) {
+                Some(digits) if digits.chars().all(|ch| ch.is_ascii_hexdigit()) => digits,
+                _ => return Err(self.invalid_unicode_escape()),
+            }
+        };
+        Ok(u32::from_str_radix(digits, 16).expect("valid hex digits"))
+    }
+
+    fn invalid_unicode_escape(&self) -> EvalError {
+        EvalError::InvalidParameterValue("invalid Unicode escape sequence in jsonpath input".into())
+    }
+
+    fn parse_numeric(&mut self) -> Result<OrderedDecimal<Numeric>, EvalError> {
+        let start = self.buf.pos();
+        self.buf.consume('-');
+        self.buf.take_while(|ch| ch.is_ascii_digit());
+        if self.buf.consume('.') {
+            self.buf.take_while(|ch| ch.is_ascii_digit());
+        }
+        if self.buf.consume('e') || self.buf.consume('E') {
+            if !self.buf.consume('+') {
+                self.buf.consume('-');
+            }
+            self.buf.take_while(|ch| ch.is_ascii_digit());
+        }
+        let s = &self.buf.inner()[start..self.buf.pos()];
+        strconv::parse_numeric(s).map_err(|_| {
+            EvalError::InvalidParameterValue(format!(
+                "syntax error at or near \"{s}\" of jsonpath input"
+            ))
+        })
+    }
+}
+
+fn into_predicate(expr: Expr, parser: &Parser) -> Result<Predicate, EvalError> {
+    match expr {
+        Expr::Predicate(predicate) => Ok(predicate),
+        Expr::Operand(_) => Err(parser.syntax_error()),
+    }
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::adt::jsonb::Jsonb;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_jsonpath() {
+        let json: Jsonb = r#"{"a": [1, 2, 3, 4, 5], "b": {"c": true}, "d": null, "😀": 1}"#
+            .parse()
+            .expect("valid json");
+        let root = json.as_ref().into_datum();
+        let cases = [
+            ("$.a", true, None),
+            ("$.a[*] > 2", true, Some(true)),
+            ("$.a[*] > 5", true, Some(false)),
+            ("$.a ? (@ > 4)", true, None),
+            ("$.a ? (@ > 5)", false, None),
+            ("$.a[last] == 5", true, Some(true)),
+            ("$.a[10]", false, None),
+            ("$.b.c", true, Some(true)),
+            ("$.b.\"c\"", true, Some(true)),
+            ("$.b.*", true, Some(true)),
+            ("$.missing", false, None),
+            ("$.missing == 1", true, Some(false)),
+            ("$.d == null", true, Some(true)),
+            ("$.d != 1", true, Some(true)),
+            ("$.b.c == 1", true, None),
+            ("exists($.b.c) && !($.a[0] == 2)", true, Some(true)),
+            ("$.a[0] == 2 || $.b.c == 1", true, None),
+            ("lax $.a[0] == 1.0", true, Some(true)),
+            ("$.\"\\u0062\".c", true, Some(true)),
+            ("$.\"\\u{62}\".\"\\u0063\"", true, Some(true)),
+            ("$.\"u0062\"", false, None),
+            ("$.\"\\ud83d\\ude00\" == 1", true, Some(true)),
+        ];
+        for (path, exists, predicate) in cases {
+            let parsed = JsonPath::parse(path).unwrap_or_else(|e| panic!("{path}: {e}"));
+            assert_eq!(parsed.exists(root), exists, "{path} exists");
+            assert_eq!(parsed.predicate_match(root), predicate, "{path} predicate");
+        }
+
+        for path in [
+            "",
+            "$.",
+            "$[",
+            "$ ==",
+            "@ > 1",
+            "$.a ? (@)",
+            "strict $",
+            "$.a.size()",
+            r#"$."\u006""#,
+            r#"$."\u{}""#,
+            r#"$."\u{1234567}""#,
+            r#"$."\ud83d""#,
+            r#"$."\udc00""#,
+        ] {
+            assert!(JsonPath::parse(path).is_err(), "{path} should not parse");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_jsonpath_long_chains() {
+        let json: Jsonb = r#"{"a": 1}"#.parse().expect("valid json");
+        let root = json.as_ref().into_datum();
+
+        // Long chains of binary operators must not recurse once per operator.
+        let n = 100_000;
+        let or = vec!["$.a == 2"; n].join(" || ") + " || $.a == 1";
+        let or = JsonPath::parse(&or).expect("valid path");
+        assert_eq!(or.predicate_match(root), Some(true));
+        let and = vec!["$.a == 1"; n].join(" && ") + " && $.a == 2";
+        let and = JsonPath::parse(&and).expect("valid path");
+        assert_eq!(and.predicate_match(root), Some(false));
+
+        // Nesting is still limited.
+        let nested = "(".repeat(MAX_DEPTH + 1) + "$.a == 1" + &")".repeat(MAX_DEPTH + 1);
+        assert!(JsonPath::parse(&nested).is_err());
+    }
+}
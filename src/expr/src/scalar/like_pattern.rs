@@ -179,6 +179,25 @@ pub fn compile(pattern: &str, case_insensitive: bool) -> Result<Matcher, EvalErr
     })
 }
 
+/// Returns the literal prefix of a SQL LIKE pattern, i.e. the longest leading run of characters
+/// that every string matched by the pattern must start with. Escaped wildcards are part of the
+/// prefix, as the literal characters they stand for.
+///
+/// Returns `None` if the pattern starts with a wildcard or is malformed. The pattern must use the
+/// default escape character, see [`normalize_pattern`]. The prefix is only meaningful for
+/// case-sensitive matches.
+pub fn extract_literal_prefix(pattern: &str) -> Option<String> {
+    let subpatterns = build_subpatterns(pattern).ok()?;
+    match subpatterns.first() {
+        Some(Subpattern {
+            consume: 0,
+            many: false,
+            suffix,
+        }) if !suffix.is_empty() => Some(suffix.clone()),
+        _ => None,
+    }
+}
+
 pub fn any_matcher() -> impl Strategy<Value = Matcher> {
     // Generates a string out of a pool of characters. The pool has at least one
     // representative from the following classes of the characters (listed in
@@ -493,6 +512,39 @@ mod test {
         }
     }
 
+    #[mz_ore::test]
+    fn test_extract_literal_prefix() {
+        let test_cases = [
+            // Leading wildcards.
+            ("%abc", None),
+            ("_abc", None),
+            ("%", None),
+            ("", None),
+            // Literal prefixes.
+            ("abc%", Some("abc")),
+            ("abc_def%", Some("abc")),
+            ("ab%c%", Some("ab")),
+            // Escaped wildcards and escapes are part of the prefix.
+            ("a\\%b%", Some("a%b")),
+            ("a\\_b_", Some("a_b")),
+            ("a\\\\b%", Some("a\\b")),
+            ("\\%%", Some("%")),
+            // Pure literals.
+            ("abc", Some("abc")),
+            ("漢字", Some("漢字")),
+            // Malformed patterns.
+            ("abc\\", None),
+            ("abc%\\", None),
+        ];
+        for (pattern, expected) in test_cases {
+            assert_eq!(
+                extract_literal_prefix(pattern).as_deref(),
+                expected,
+                "extract_literal_prefix({pattern:?})"
+            );
+        }
+    }
+
     #[mz_ore::test]
     fn test_escape_too_long() {
         match EscapeBehavior::from_str("foo") {
@@ -693,3 +693,22 @@ pub const VIEW_PG_ATTRIBUTE_ALL_DATABASES_OID: u32 = 16970;
 pub const INDEX_PG_ATTRIBUTE_ALL_DATABASES_IND_OID: u32 = 16971;
 pub const SCHEMA_MZ_CATALOG_UNSTABLE_OID: u32 = 16972;
 pub const SCHEMA_MZ_INTROSPECTION_OID: u32 = 16973;
+pub const FUNC_STRPOS_BYTES_OID: u32 = 16974;
+pub const FUNC_MZ_ACL_ITEM_PRIVILEGES_DIFF_OID: u32 = 16975;
+pub const FUNC_MZ_ACL_ITEM_PRIVILEGES_UNION_OID: u32 = 16976;
+pub const OP_CONTAINS_LIST_LIST_OID: u32 = 16977;
+pub const OP_CONTAINED_LIST_LIST_OID: u32 = 16978;
+pub const FUNC_JSONB_PRETTY_INDENT_OID: u32 = 16979;
+pub const FUNC_MZ_ENVIRONMENT_ID_PARTS_OID: u32 = 16980;
+pub const FUNC_ASCII_PG_LEGACY_CHAR_OID: u32 = 16981;
+pub const FUNC_REPEAT_BYTES_OID: u32 = 16982;
+pub const FUNC_ARRAY_SLICE_OID: u32 = 16983;
+pub const FUNC_BIT_SUBSTRING_OID: u32 = 16984;
+pub const FUNC_INTERVAL_TO_HUMAN_TEXT_OID: u32 = 16985;
+pub const FUNC_DEGREES_NUMERIC_OID: u32 = 16986;
+pub const FUNC_RADIANS_NUMERIC_OID: u32 = 16987;
+pub const OP_PATH_EXISTS_JSONB_OID: u32 = 16988;
+pub const OP_PATH_MATCH_JSONB_OID: u32 = 16989;
+pub const FUNC_LAST_CODEPOINT_OID: u32 = 16990;
+pub const FUNC_ACL_ITEM_PRIVILEGES_DIFF_OID: u32 = 16991;
+pub const FUNC_ACL_ITEM_PRIVILEGES_UNION_OID: u32 = 16992;
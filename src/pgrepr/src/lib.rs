@@ -25,7 +25,8 @@ mod value;
 pub mod oid;
 
 pub use types::{
-    Type, TypeConversionError, TypeFromOidError, ANYCOMPATIBLELIST, ANYCOMPATIBLEMAP, LIST, MAP,
+    parse_typmod, Type, TypeConversionError, TypeFromOidError, ANYCOMPATIBLELIST, ANYCOMPATIBLEMAP,
+    LIST, MAP,
 };
 pub use value::interval::Interval;
 pub use value::jsonb::Jsonb;
@@ -36,6 +36,16 @@ const VARHDRSZ: i32 = 4;
 /// See: <https://github.com/postgres/postgres/blob/27b77ecf9/src/include/datatype/timestamp.h#L54>
 const MAX_INTERVAL_PRECISION: i32 = 6;
 
+/// Mirror of PostgreSQL's [`INTERVAL_FULL_RANGE`] constant.
+///
+/// See: <https://github.com/postgres/postgres/blob/27b77ecf9/src/include/utils/timestamp.h>
+const INTERVAL_FULL_RANGE: i32 = 0x7FFF;
+
+/// Mirror of PostgreSQL's [`INTERVAL_FULL_PRECISION`] constant.
+///
+/// See: <https://github.com/postgres/postgres/blob/27b77ecf9/src/include/utils/timestamp.h>
+const INTERVAL_FULL_PRECISION: i32 = 0xFFFF;
+
 /// Mirror of PostgreSQL's [`MAX_TIMESTAMP_PRECISION`] constant.
 ///
 /// See: <https://github.com/postgres/postgres/blob/27b77ecf9/src/include/datatype/timestamp.h#L53>
@@ -243,7 +253,7 @@ impl TypeConstraint for IntervalConstraints {
             // https://github.com/postgres/postgres/blob/27b77ecf9/src/include/utils/timestamp.h#L53-L54
             let range = typmod >> 16 & 0x7fff;
             let precision = typmod & 0xffff;
-            if precision > MAX_INTERVAL_PRECISION {
+            if precision > MAX_INTERVAL_PRECISION && precision != INTERVAL_FULL_PRECISION {
                 return Err(format!(
                     "exceeds maximum interval precision {MAX_INTERVAL_PRECISION}"
                 ));
@@ -259,9 +269,39 @@ impl TypeConstraint for IntervalConstraints {
 
 impl fmt::Display for IntervalConstraints {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // https://github.com/postgres/postgres/blob/27b77ecf9/src/include/utils/timestamp.h#L52
-        // TODO: handle output of range.
-        write!(f, "({})", self.precision)
+        // Mirrors `intervaltypmodout`:
+        // https://github.com/postgres/postgres/blob/27b77ecf9/src/backend/utils/adt/timestamp.c
+        // The bits of each field within the range mask are defined in:
+        // https://github.com/postgres/postgres/blob/27b77ecf9/src/include/utils/datetime.h
+        const MONTH: i32 = 1 << 1;
+        const YEAR: i32 = 1 << 2;
+        const DAY: i32 = 1 << 3;
+        const HOUR: i32 = 1 << 10;
+        const MINUTE: i32 = 1 << 11;
+        const SECOND: i32 = 1 << 12;
+        let fields = match self.range {
+            YEAR => " year",
+            MONTH => " month",
+            DAY => " day",
+            HOUR => " hour",
+            MINUTE => " minute",
+            SECOND => " second",
+            r if r == YEAR | MONTH => " year to month",
+            r if r == DAY | HOUR => " day to hour",
+            r if r == DAY | HOUR | MINUTE => " day to minute",
+            r if r == DAY | HOUR | MINUTE | SECOND => " day to second",
+            r if r == HOUR | MINUTE => " hour to minute",
+            r if r == HOUR | MINUTE | SECOND => " hour to second",
+            r if r == MINUTE | SECOND => " minute to second",
+            INTERVAL_FULL_RANGE => "",
+            // PostgreSQL reports an internal error for any other range.
+            _ => "",
+        };
+        f.write_str(fields)?;
+        if self.precision != INTERVAL_FULL_PRECISION {
+            write!(f, "({})", self.precision)?;
+        }
+        Ok(())
     }
 }
 
@@ -840,6 +880,15 @@ impl Type {
             &postgres_types::Type::REGPROC_ARRAY => "regproc[]",
             &postgres_types::Type::REGTYPE_ARRAY => "regtype[]",
             &postgres_types::Type::INT2_VECTOR => "int2vector",
+            &postgres_types::Type::INT2_VECTOR_ARRAY => "int2vector[]",
+            &postgres_types::Type::JSON_ARRAY => "json[]",
+            &postgres_types::Type::NAME_ARRAY => "name[]",
+            &postgres_types::Type::INT4_RANGE_ARRAY => "int4range[]",
+            &postgres_types::Type::INT8_RANGE_ARRAY => "int8range[]",
+            &postgres_types::Type::NUM_RANGE_ARRAY => "numrange[]",
+            &postgres_types::Type::TS_RANGE_ARRAY => "tsrange[]",
+            &postgres_types::Type::TSTZ_RANGE_ARRAY => "tstzrange[]",
+            &postgres_types::Type::DATE_RANGE_ARRAY => "daterange[]",
             other => match other.oid() {
                 oid::TYPE_UINT2_ARRAY_OID => "uint2[]",
                 oid::TYPE_UINT4_ARRAY_OID => "uint4[]",
@@ -1001,6 +1050,36 @@ impl fmt::Display for Type {
     }
 }
 
+/// Renders the type modifier `typmod` of the type with the specified OID the
+/// way PostgreSQL's `format_type` appends it to the type's name, e.g. `(10,2)`
+/// for `numeric(10,2)` or `(3) without time zone` for `time(3) without time
+/// zone`.
+///
+/// Like PostgreSQL, an invalid `typmod` is rendered verbatim in parentheses if
+/// it is nonnegative, and the empty string is rendered if it is negative.
+pub fn parse_typmod(oid: u32, typmod: i32) -> String {
+    let typ = match Type::from_oid_and_typmod(oid, typmod) {
+        Ok(typ) => typ,
+        Err(_) if typmod >= 0 => return format!("({typmod})"),
+        Err(_) => return String::new(),
+    };
+    let Some(constraint) = typ.constraint() else {
+        return String::new();
+    };
+    match &typ {
+        // https://github.com/postgres/postgres/blob/52377bb81/src/backend/utils/adt/varchar.c#L77
+        Type::BpChar {
+            length: Some(length),
+        }
+        | Type::VarChar {
+            max_length: Some(length),
+        } if length.into_i32() == 0 => String::new(),
+        Type::Time { .. } | Type::Timestamp { .. } => format!("{constraint} without time zone"),
+        Type::TimeTz { .. } | Type::TimestampTz { .. } => format!("{constraint} with time zone"),
+        _ => constraint.to_string(),
+    }
+}
+
 impl TryFrom<&Type> for ScalarType {
     type Error = TypeConversionError;
 
@@ -1275,3 +1354,137 @@ impl From<&ScalarType> for Type {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_type_name() {
+        let int4 = || Box::new(ScalarType::Int32);
+        let cases = [
+            (ScalarType::Int32, "integer"),
+            (ScalarType::Jsonb, "jsonb"),
+            (ScalarType::Array(int4()), "integer[]"),
+            (ScalarType::Array(Box::new(ScalarType::Jsonb)), "jsonb[]"),
+            (
+                ScalarType::Array(Box::new(ScalarType::Int2Vector)),
+                "int2vector[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::PgLegacyName)),
+                "name[]",
+            ),
+            (
+                ScalarType::List {
+                    element_type: int4(),
+                    custom_id: None,
+                },
+                "list",
+            ),
+            (
+                ScalarType::Map {
+                    value_type: int4(),
+                    custom_id: None,
+                },
+                "map",
+            ),
+            (
+                ScalarType::Record {
+                    fields: vec![("a".into(), ScalarType::Int32.nullable(true))],
+                    custom_id: None,
+                },
+                "record",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Record {
+                    fields: vec![],
+                    custom_id: None,
+                })),
+                "record[]",
+            ),
+            (
+                ScalarType::Range {
+                    element_type: int4(),
+                },
+                "int4range",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: int4(),
+                })),
+                "int4range[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: Box::new(ScalarType::Int64),
+                })),
+                "int8range[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: Box::new(ScalarType::Numeric { max_scale: None }),
+                })),
+                "numrange[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: Box::new(ScalarType::Timestamp { precision: None }),
+                })),
+                "tsrange[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: Box::new(ScalarType::TimestampTz { precision: None }),
+                })),
+                "tstzrange[]",
+            ),
+            (
+                ScalarType::Array(Box::new(ScalarType::Range {
+                    element_type: Box::new(ScalarType::Date),
+                })),
+                "daterange[]",
+            ),
+        ];
+        for (scalar_type, expected) in cases {
+            assert_eq!(Type::from(&scalar_type).name(), expected, "{scalar_type:?}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_parse_typmod() {
+        let cases = [
+            // numeric(10,2)
+            (oid::TYPE_NUMERIC_OID, 655366, "(10,2)"),
+            (oid::TYPE_NUMERIC_OID, -1, ""),
+            // varchar(5) and char(3)
+            (oid::TYPE_VARCHAR_OID, 9, "(5)"),
+            (oid::TYPE_BPCHAR_OID, 7, "(3)"),
+            (oid::TYPE_VARCHAR_OID, 4, ""),
+            (oid::TYPE_VARCHAR_OID, -1, ""),
+            // time(3) and timestamp(3), with and without time zones
+            (oid::TYPE_TIME_OID, 3, "(3) without time zone"),
+            (postgres_types::Type::TIMETZ.oid(), 3, "(3) with time zone"),
+            (oid::TYPE_TIMESTAMP_OID, 3, "(3) without time zone"),
+            (oid::TYPE_TIMESTAMPTZ_OID, 0, "(0) with time zone"),
+            (oid::TYPE_TIMESTAMP_OID, -1, ""),
+            // interval(3), interval year to month, and interval day to second(3)
+            (oid::TYPE_INTERVAL_OID, 2147418115, "(3)"),
+            (oid::TYPE_INTERVAL_OID, 458751, " year to month"),
+            (oid::TYPE_INTERVAL_OID, 470286339, " day to second(3)"),
+            (oid::TYPE_INTERVAL_OID, 470351871, " day to second"),
+            // Invalid typmods are rendered verbatim when nonnegative.
+            (oid::TYPE_TIMESTAMP_OID, 7, "(7)"),
+            (oid::TYPE_BOOL_OID, 3, "(3)"),
+            (oid::TYPE_BOOL_OID, -3, ""),
+            (0, 3, "(3)"),
+        ];
+        for (oid, typmod, expected) in cases {
+            assert_eq!(
+                parse_typmod(oid, typmod),
+                expected,
+                "oid {oid}, typmod {typmod}"
+            );
+        }
+    }
+}
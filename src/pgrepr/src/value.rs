@@ -759,7 +759,7 @@ mod tests {
 
         assert_eq!(
             decoded_int_array.map_err(|e| e.to_string()).unwrap_err(),
-            "invalid input syntax for type array: Specifying array lower bounds is not supported: \"[0:0]={t}\"".to_string()
+            "invalid input syntax for type array: Specifying array lower bounds is not supported: \"[0:0]={t}\" at character 1".to_string()
         );
     }
 }
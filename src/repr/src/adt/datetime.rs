@@ -60,6 +60,7 @@ pub enum DateTimeUnits {
     DayOfYear,
     IsoDayOfWeek,
     IsoDayOfYear,
+    IsoYear,
     Minute,
     Second,
     Milliseconds,
@@ -86,6 +87,7 @@ impl fmt::Display for DateTimeUnits {
             Self::DayOfYear => f.write_str("doy"),
             Self::IsoDayOfWeek => f.write_str("isodow"),
             Self::IsoDayOfYear => f.write_str("isodoy"),
+            Self::IsoYear => f.write_str("isoyear"),
             Self::Minute => f.write_str("minute"),
             Self::Second => f.write_str("seconds"),
             Self::Milliseconds => f.write_str("milliseconds"),
@@ -114,6 +116,7 @@ impl FromStr for DateTimeUnits {
             "doy" => Ok(Self::DayOfYear),
             "isodow" => Ok(Self::IsoDayOfWeek),
             "isodoy" => Ok(Self::IsoDayOfYear),
+            "isoyear" => Ok(Self::IsoYear),
             "h" | "hour" | "hours" | "hr" | "hrs" => Ok(Self::Hour),
             "us" | "usec" | "usecs" | "useconds" | "microsecond" | "microseconds" => {
                 Ok(Self::Microseconds)
@@ -151,6 +154,7 @@ impl RustType<ProtoDateTimeUnits> for DateTimeUnits {
                 DateTimeUnits::DayOfYear => Kind::DayOfYear(()),
                 DateTimeUnits::IsoDayOfWeek => Kind::IsoDayOfWeek(()),
                 DateTimeUnits::IsoDayOfYear => Kind::IsoDayOfYear(()),
+                DateTimeUnits::IsoYear => Kind::IsoYear(()),
                 DateTimeUnits::Minute => Kind::Minute(()),
                 DateTimeUnits::Second => Kind::Second(()),
                 DateTimeUnits::Milliseconds => Kind::Milliseconds(()),
@@ -182,6 +186,7 @@ impl RustType<ProtoDateTimeUnits> for DateTimeUnits {
             Kind::DayOfYear(_) => DateTimeUnits::DayOfYear,
             Kind::IsoDayOfWeek(_) => DateTimeUnits::IsoDayOfWeek,
             Kind::IsoDayOfYear(_) => DateTimeUnits::IsoDayOfYear,
+            Kind::IsoYear(_) => DateTimeUnits::IsoYear,
             Kind::Minute(_) => DateTimeUnits::Minute,
             Kind::Second(_) => DateTimeUnits::Second,
             Kind::Milliseconds(_) => DateTimeUnits::Milliseconds,
@@ -722,6 +722,18 @@ impl Interval {
 
         Ok(Self::new(months, days, micros))
     }
+
+    /// Returns the canonical representation of the interval, as computed by
+    /// [`Interval::justify_interval`].
+    ///
+    /// `Interval`'s `Ord` implementation compares months, days, and
+    /// microseconds lexicographically, whereas PostgreSQL compares intervals by
+    /// their total span, treating a month as 30 days and a day as 24 hours. Two
+    /// intervals have the same span iff their canonical representations are
+    /// equal, and canonical representations order by span.
+    pub fn canonicalize(&self) -> Result<Self, anyhow::Error> {
+        self.justify_interval()
+    }
 }
 
 /// Format an interval in a human form
@@ -1401,6 +1413,70 @@ mod test {
         );
     }
 
+    #[mz_ore::test]
+    fn test_canonicalize() {
+        let month = Interval::new(1, 0, 0);
+        let thirty_days = Interval::new(0, 30, 0);
+        let day = Interval::new(0, 1, 0);
+        let twenty_four_hours = Interval::new(0, 0, *USECS_PER_DAY);
+
+        // Field-wise comparison distinguishes these, unlike PostgreSQL.
+        assert_ne!(month, thirty_days);
+        assert_ne!(day, twenty_four_hours);
+        assert!(month > Interval::new(0, 31, 0));
+
+        assert_eq!(
+            month.canonicalize().unwrap(),
+            thirty_days.canonicalize().unwrap()
+        );
+        assert_eq!(
+            day.canonicalize().unwrap(),
+            twenty_four_hours.canonicalize().unwrap()
+        );
+        assert!(month.canonicalize().unwrap() < Interval::new(0, 31, 0).canonicalize().unwrap());
+        assert_eq!(
+            Interval::new(1, -1, -*USECS_PER_DAY)
+                .canonicalize()
+                .unwrap(),
+            Interval::new(0, 28, 0)
+        );
+    }
+
+    #[mz_ore::test]
+    fn proptest_canonicalize() {
+        fn check(interval: Interval) {
+            let Ok(canonical) = interval.canonicalize() else {
+                return;
+            };
+            assert_eq!(canonical.as_microseconds(), interval.as_microseconds());
+            assert_eq!(canonical.canonicalize().unwrap(), canonical);
+
+            // Moving a month into days, or a day into microseconds, preserves
+            // the span and so the canonical representation.
+            let respelled = [
+                interval
+                    .months
+                    .checked_sub(1)
+                    .zip(interval.days.checked_add(30))
+                    .map(|(months, days)| Interval::new(months, days, interval.micros)),
+                interval
+                    .days
+                    .checked_sub(1)
+                    .zip(interval.micros.checked_add(*USECS_PER_DAY))
+                    .map(|(days, micros)| Interval::new(interval.months, days, micros)),
+            ];
+            for respelled in respelled.into_iter().flatten() {
+                if let Ok(other) = respelled.canonicalize() {
+                    assert_eq!(other, canonical, "{interval:?} vs {respelled:?}");
+                }
+            }
+        }
+
+        proptest!(|(interval in any::<Interval>())| {
+            check(interval);
+        });
+    }
+
     #[mz_ore::test]
     fn proptest_packed_interval_roundtrips() {
         fn roundtrip_interval(og: Interval) {
@@ -210,6 +210,23 @@ impl<'a> JsonbRef<'a> {
         serde_json::to_value(JsonbDatum(self.datum))
             .expect("conversion to serde_json::Value known to be valid")
     }
+
+    /// Formats this JSON value like the alternate [`fmt::Display`] format, but
+    /// uses `indent` as the indentation unit instead of two spaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `JsonbRef` was constructed with a [`Datum`] that is not
+    /// representable as JSON.
+    pub fn to_string_pretty_with_indent(&self, indent: &str) -> String {
+        let mut buf = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
+        JsonbDatum(self.datum)
+            .serialize(&mut serializer)
+            .expect("serialization known to be valid");
+        String::from_utf8(buf).expect("JSON output is valid UTF-8")
+    }
 }
 
 impl fmt::Display for JsonbRef<'_> {
@@ -101,8 +101,11 @@ bitflags! {
 }
 
 impl AclMode {
+    /// Parses a single privilege keyword, ignoring case and surrounding whitespace. The error
+    /// names the offending keyword, with surrounding whitespace removed.
     pub fn parse_single_privilege(s: &str) -> Result<Self, Error> {
-        match s.trim().to_uppercase().as_str() {
+        let s = s.trim();
+        match s.to_uppercase().as_str() {
             INSERT_STR => Ok(AclMode::INSERT),
             SELECT_STR => Ok(AclMode::SELECT),
             UPDATE_STR => Ok(AclMode::UPDATE),
@@ -116,6 +119,8 @@ impl AclMode {
         }
     }
 
+    /// Parses a comma separated list of privilege keywords. The error names the first
+    /// unrecognized keyword, rather than the entire list.
     pub fn parse_multiple_privileges(s: &str) -> Result<Self, Error> {
         let mut acl_mode = AclMode::empty();
         for privilege in s.split(',') {
@@ -129,14 +134,18 @@ impl AclMode {
         self.explode().join(", ")
     }
 
+    /// Returns the keyword of each privilege in `self`.
+    ///
+    /// The order matches [`AclMode`]'s `Display` implementation, which in turn matches the order
+    /// PostgreSQL uses for its `aclitem` output.
     pub fn explode(&self) -> Vec<&'static str> {
         let mut privileges = Vec::new();
-        if self.contains(AclMode::SELECT) {
-            privileges.push(SELECT_STR);
-        }
         if self.contains(AclMode::INSERT) {
             privileges.push(INSERT_STR);
         }
+        if self.contains(AclMode::SELECT) {
+            privileges.push(SELECT_STR);
+        }
         if self.contains(AclMode::UPDATE) {
             privileges.push(UPDATE_STR);
         }
@@ -914,6 +923,51 @@ fn test_mz_acl_parsing() {
     assert!("u2=rw/s66=CU/u33".parse::<MzAclItem>().is_err());
 }
 
+#[mz_ore::test]
+fn test_parse_multiple_privileges() {
+    assert_eq!(
+        AclMode::parse_multiple_privileges("select, Insert ,USAGE").unwrap(),
+        AclMode::SELECT | AclMode::INSERT | AclMode::USAGE
+    );
+    assert_eq!(
+        AclMode::parse_multiple_privileges("SELECT, bogus, INSERT")
+            .unwrap_err()
+            .to_string(),
+        "\"bogus\""
+    );
+    assert_eq!(
+        AclMode::parse_multiple_privileges("SELECT,,INSERT")
+            .unwrap_err()
+            .to_string(),
+        "\"\""
+    );
+}
+
+#[mz_ore::test]
+fn test_acl_mode_explode() {
+    assert_eq!(AclMode::empty().explode(), Vec::<&str>::new());
+    assert_eq!(AclMode::SELECT.explode(), vec!["SELECT"]);
+    assert_eq!(AclMode::CREATE_CLUSTER.explode(), vec!["CREATECLUSTER"]);
+    assert_eq!(
+        AclMode::all().explode(),
+        vec![
+            "INSERT",
+            "SELECT",
+            "UPDATE",
+            "DELETE",
+            "USAGE",
+            "CREATE",
+            "CREATEROLE",
+            "CREATEDB",
+            "CREATECLUSTER"
+        ]
+    );
+    assert_eq!(
+        AclMode::from_str("NCdrU").unwrap().explode(),
+        vec!["SELECT", "DELETE", "USAGE", "CREATE", "CREATECLUSTER"]
+    );
+}
+
 #[mz_ore::test]
 fn test_mz_acl_item_binary() {
     use std::ops::BitAnd;
@@ -221,6 +221,12 @@ pub trait DateLike: chrono::Datelike {
     fn iso_day_of_week(&self) -> u32 {
         self.weekday().number_from_monday()
     }
+
+    /// Extract the year of the iso week, which differs from the calendar year for days at the
+    /// start or end of the year that belong to the previous or next year's iso weeks.
+    fn iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
 }
 
 impl<T> DateLike for T where T: chrono::Datelike {}
@@ -409,8 +415,15 @@ pub trait TimestampLike:
     fn from_date_time(dt: NaiveDateTime) -> Self;
 
     /// Returns a string representing the timezone's offset from UTC.
+    ///
+    /// Timestamps with a timezone are always stored in UTC, so this is `+00`
+    /// regardless of the session's timezone.
     fn timezone_offset(&self) -> &'static str;
 
+    /// Returns the timezone's offset from UTC in seconds, or `None` if the
+    /// timestamp has no timezone.
+    fn timezone_offset_seconds(&self) -> Option<i32>;
+
     /// Returns a string representing the hour portion of the timezone's offset
     /// from UTC.
     fn timezone_hours(&self) -> &'static str;
@@ -420,7 +433,8 @@ pub trait TimestampLike:
     fn timezone_minutes(&self) -> &'static str;
 
     /// Returns the abbreviated name of the timezone with the specified
-    /// capitalization.
+    /// capitalization, i.e. `UTC` for timestamps with a timezone and the empty
+    /// string for timestamps without one.
     fn timezone_name(&self, caps: bool) -> &'static str;
 
     /// Adds given Duration to the current date and time.
@@ -483,6 +497,10 @@ impl TimestampLike for chrono::NaiveDateTime {
         "+00"
     }
 
+    fn timezone_offset_seconds(&self) -> Option<i32> {
+        None
+    }
+
     fn timezone_hours(&self) -> &'static str {
         "+00"
     }
@@ -533,6 +551,10 @@ impl TimestampLike for chrono::DateTime<chrono::Utc> {
         "+00"
     }
 
+    fn timezone_offset_seconds(&self) -> Option<i32> {
+        Some(0)
+    }
+
     fn timezone_hours(&self) -> &'static str {
         "+00"
     }
@@ -1646,6 +1646,10 @@ where
 
 /// Number of bytes required by the datum.
 /// This is used to optimistically pre-allocate buffers for packing rows.
+///
+/// Container datums (arrays, lists, maps, and records) store their elements
+/// inline, so their size includes the full encoded size of every nested
+/// element, recursively.
 pub fn datum_size(datum: &Datum) -> usize {
     match datum {
         Datum::Null => 1,
@@ -2976,6 +2980,35 @@ mod tests {
                     ))))
                     .unwrap();
             }),
+            // Nested containers are accounted for by their full contents.
+            arena.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push_list(&[Datum::Int32(1), Datum::Null]);
+                    packer.push_dict(vec![("a", Datum::Int32(1)), ("b", Datum::String("x"))]);
+                });
+            }),
+            arena.make_datum(|packer| {
+                packer
+                    .push_array(
+                        &[
+                            ArrayDimension {
+                                lower_bound: 1,
+                                length: 2,
+                            },
+                            ArrayDimension {
+                                lower_bound: 1,
+                                length: 2,
+                            },
+                        ],
+                        &[
+                            Datum::Int32(1),
+                            Datum::Null,
+                            Datum::Int32(3),
+                            Datum::Int32(4),
+                        ],
+                    )
+                    .unwrap();
+            }),
         ];
         for value in values_of_interest {
             if datum_size(&value) != Row::pack_slice(&[value]).data.len() {
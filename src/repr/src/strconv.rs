@@ -34,7 +34,7 @@ use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime,
 use dec::OrderedDecimal;
 use fast_float::FastFloat;
 use mz_lowertest::MzReflect;
-use mz_ore::cast::ReinterpretCast;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
 use mz_ore::error::ErrorExt;
 use mz_ore::fmt::FormatBuffer;
 use mz_ore::lex::LexBuf;
@@ -516,6 +516,15 @@ where
     Nestable::MayNeedEscaping
 }
 
+/// Writes a [`DateTime<Utc>`] timestamp to `buf` in the format used by
+/// PostgreSQL's `timeofday` function, e.g. `Thu Oct 17 12:34:56.123456 2024 UTC`.
+pub fn format_time_of_day<F>(buf: &mut F, ts: &DateTime<Utc>)
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{}", ts.format("%a %b %d %H:%M:%S%.6f %Y UTC"));
+}
+
 /// parse
 ///
 /// ```text
@@ -632,6 +641,16 @@ pub fn parse_bytes(s: &str) -> Result<Vec<u8>, ParseError> {
     // [1]: https://www.postgresql.org/docs/current/datatype-binary.html#id-1.5.7.12.10
     if let Some(remainder) = s.strip_prefix(r"\x") {
         parse_bytes_hex(remainder).map_err(|e| {
+            // Report offsets relative to the input, including the prefix.
+            let e = match e {
+                ParseHexError::InvalidHexDigit { digit, offset } => {
+                    ParseHexError::InvalidHexDigit {
+                        digit,
+                        offset: offset + r"\x".len(),
+                    }
+                }
+                e => e,
+            };
             ParseError::invalid_input_syntax("bytea", s).with_details(e.to_string_with_causes())
         })
     } else {
@@ -643,17 +662,20 @@ pub fn parse_bytes_hex(s: &str) -> Result<Vec<u8>, ParseHexError> {
     // Can't use `hex::decode` here, as it doesn't tolerate whitespace
     // between encoded bytes.
 
-    let decode_nibble = |b| match b {
+    let decode_nibble = |(offset, b)| match b {
         b'a'..=b'f' => Ok(b - b'a' + 10),
         b'A'..=b'F' => Ok(b - b'A' + 10),
         b'0'..=b'9' => Ok(b - b'0'),
-        _ => Err(ParseHexError::InvalidHexDigit(char::from(b))),
+        _ => Err(ParseHexError::InvalidHexDigit {
+            digit: char::from(b),
+            offset,
+        }),
     };
 
     let mut buf = vec![];
-    let mut nibbles = s.as_bytes().iter().copied();
+    let mut nibbles = s.as_bytes().iter().copied().enumerate();
     while let Some(n) = nibbles.next() {
-        if let b' ' | b'\n' | b'\t' | b'\r' = n {
+        if let (_, b' ' | b'\n' | b'\t' | b'\r') = n {
             continue;
         }
         let n = decode_nibble(n)?;
@@ -794,15 +816,20 @@ pub fn parse_array<'a, T, E>(
 where
     E: ToString,
 {
-    parse_array_inner(s, make_null, gen_elem)
-        .map_err(|details| ParseError::invalid_input_syntax("array", s).with_details(details))
+    parse_array_inner(s, make_null, gen_elem).map_err(|(details, position)| {
+        ParseError::invalid_input_syntax("array", s)
+            .with_details(details)
+            .with_position(position)
+    })
 }
 
+/// Like [`parse_array`], but reports errors alongside the byte offset in `s` of the token at
+/// which parsing failed.
 fn parse_array_inner<'a, T, E>(
     s: &'a str,
     mut make_null: impl FnMut() -> T,
     mut gen_elem: impl FnMut(Cow<'a, str>) -> Result<T, E>,
-) -> Result<(Vec<T>, Vec<ArrayDimension>), ArrayParsingError>
+) -> Result<(Vec<T>, Vec<ArrayDimension>), (ArrayParsingError, usize)>
 where
     E: ToString,
 {
@@ -833,20 +860,20 @@ where
         current_dim: usize,
         // Whether or not this array may be modified any further.
         sealed: bool,
-        // The elements extracted from the input str. This is on the array
-        // builder to necessitate using `insert_element` so we understand when
-        // elements are staged.
-        elements: Vec<Option<Cow<'a, str>>>,
+        // The elements extracted from the input str, along with the byte
+        // offset at which each element starts. This is on the array builder to
+        // necessitate using `insert_element` so we understand when elements
+        // are staged.
+        elements: Vec<(Option<Cow<'a, str>>, usize)>,
     }
 
     impl<'a> ArrayBuilder<'a> {
         fn build(
-            s: &'a str,
-        ) -> Result<(Vec<Option<Cow<'a, str>>>, Vec<ArrayDimension>), ArrayParsingError> {
-            let buf = &mut LexBuf::new(s);
-
+            buf: &mut LexBuf<'a>,
+        ) -> Result<(Vec<(Option<Cow<'a, str>>, usize)>, Vec<ArrayDimension>), ArrayParsingError>
+        {
             // TODO: support parsing array dimensions
-            if buf.consume('[') {
+            if buf.peek() == Some('[') {
                 Err(DimsUnsupported)?;
             }
 
@@ -882,6 +909,7 @@ where
 
             loop {
                 buf.take_while(|ch| ch.is_ascii_whitespace());
+                let start = buf.pos();
 
                 // Filter command state from terminal states.
                 match buf.next() {
@@ -889,23 +917,31 @@ where
                         break;
                     }
                     None => Err(EarlyTerm)?,
-                    Some(_) if builder.sealed => Err(Junk)?,
+                    Some(_) if builder.sealed => {
+                        buf.prev();
+                        Err(Junk)?
+                    }
                     Some(c) => builder.current_command_char = c,
                 }
 
                 // Run command char
-                match builder.current_command_char {
-                    '{' => builder.enter_dim()?,
-                    '}' => builder.exit_dim()?,
-                    ',' => builder.commit_element(true)?,
+                let res = match builder.current_command_char {
+                    '{' => builder.enter_dim(),
+                    '}' => builder.exit_dim(),
+                    ',' => builder.commit_element(true),
                     c => {
                         buf.prev();
                         let s = match c {
                             '"' => Some(lex_quoted_element(buf)?),
                             _ => lex_unquoted_element(buf, is_special_char, is_end_of_literal)?,
                         };
-                        builder.insert_element(s)?;
+                        builder.insert_element(s, start)
                     }
+                };
+                if let Err(e) = res {
+                    // Report the error at the token that caused it.
+                    rewind(buf, start);
+                    return Err(e);
                 }
             }
 
@@ -947,8 +983,13 @@ where
             Ok(())
         }
 
-        /// Insert a new element into the array, ensuring it is in the proper dimension.
-        fn insert_element(&mut self, s: Option<Cow<'a, str>>) -> Result<(), ArrayParsingError> {
+        /// Insert a new element, which starts at byte offset `start`, into the array, ensuring it
+        /// is in the proper dimension.
+        fn insert_element(
+            &mut self,
+            s: Option<Cow<'a, str>>,
+            start: usize,
+        ) -> Result<(), ArrayParsingError> {
             // Can only insert elements into data-bearing dimension, which is
             // the last one.
             if self.current_dim != self.dimensions.len() - 1 {
@@ -957,7 +998,7 @@ where
 
             self.stage_element()?;
 
-            self.elements.push(s);
+            self.elements.push((s, start));
 
             Ok(())
         }
@@ -1038,15 +1079,16 @@ where
         }
     }
 
-    let (raw_elems, dims) = ArrayBuilder::build(s)?;
+    let buf = &mut LexBuf::new(s);
+    let (raw_elems, dims) = ArrayBuilder::build(buf).map_err(|e| (e, buf.pos()))?;
 
     let mut elems = Vec::with_capacity(raw_elems.len());
 
-    let mut gen = |elem| gen_elem(elem).map_err(|e| e.to_string());
+    let mut gen = |elem| gen_elem(elem).map_err(|e| Generic(e.to_string()));
 
-    for elem in raw_elems.into_iter() {
+    for (elem, start) in raw_elems.into_iter() {
         elems.push(match elem {
-            Some(elem) => gen(elem)?,
+            Some(elem) => gen(elem).map_err(|e| (e, start))?,
             None => make_null(),
         });
     }
@@ -1063,14 +1105,19 @@ pub fn parse_list<'a, T, E>(
 where
     E: ToString,
 {
-    parse_list_inner(s, is_element_type_list, make_null, gen_elem)
-        .map_err(|details| ParseError::invalid_input_syntax("list", s).with_details(details))
+    let buf = &mut LexBuf::new(s);
+    parse_list_inner(buf, is_element_type_list, make_null, gen_elem).map_err(|details| {
+        ParseError::invalid_input_syntax("list", s)
+            .with_details(details)
+            .with_position(buf.pos())
+    })
 }
 
 // `parse_list_inner`'s separation from `parse_list` simplifies error handling
-// by allowing subprocedures to return `String` errors.
+// by allowing subprocedures to return `String` errors. On error, `buf` is left
+// at the start of the token at which parsing failed.
 fn parse_list_inner<'a, T, E>(
-    s: &'a str,
+    buf: &mut LexBuf<'a>,
     is_element_type_list: bool,
     mut make_null: impl FnMut() -> T,
     mut gen_elem: impl FnMut(Cow<'a, str>) -> Result<T, E>,
@@ -1079,13 +1126,12 @@ where
     E: ToString,
 {
     let mut elems = vec![];
-    let buf = &mut LexBuf::new(s);
 
     // Consume opening paren.
     if !buf.consume('{') {
         bail!(
             "expected '{{', found {}",
-            match buf.next() {
+            match buf.peek() {
                 Some(c) => format!("{}", c),
                 None => "empty string".to_string(),
             }
@@ -1109,14 +1155,18 @@ where
                 buf.prev();
             }
             Some(',') => {}
-            Some(c) => bail!("expected ',' or '}}', got '{}'", c),
+            Some(c) => {
+                buf.prev();
+                bail!("expected ',' or '}}', got '{}'", c)
+            }
             None => bail!("unexpected end of input"),
         }
 
         buf.take_while(|ch| ch.is_ascii_whitespace());
+        let start = buf.pos();
         // Get elements.
         let elem = match buf.peek() {
-            Some('"') => gen(lex_quoted_element(buf)?)?,
+            Some('"') => Some(lex_quoted_element(buf)?),
             Some('{') => {
                 if !is_element_type_list {
                     bail!(
@@ -1124,19 +1174,23 @@ where
                         want a nested list, e.g. '{{a}}'::text list list"
                     )
                 }
-                gen(lex_embedded_element(buf)?)?
+                Some(lex_embedded_element(buf)?)
             }
-            Some(_) => match lex_unquoted_element(buf, is_special_char, is_end_of_literal)? {
-                Some(elem) => gen(elem)?,
-                None => make_null(),
-            },
+            Some(_) => lex_unquoted_element(buf, is_special_char, is_end_of_literal)?,
             None => bail!("unexpected end of input"),
         };
+        let elem = match elem {
+            Some(elem) => gen(elem).map_err(|e| {
+                rewind(buf, start);
+                e
+            })?,
+            None => make_null(),
+        };
         elems.push(elem);
     }
 
     buf.take_while(|ch| ch.is_ascii_whitespace());
-    if let Some(c) = buf.next() {
+    if let Some(c) = buf.peek() {
         bail!(
             "malformed array literal; contains '{}' after terminal '}}'",
             c
@@ -1183,6 +1237,14 @@ where
     Ok(elems)
 }
 
+/// Moves `buf` back to the byte offset `pos`, so that an error can be reported at the start of
+/// the token that caused it.
+fn rewind(buf: &mut LexBuf, pos: usize) {
+    while buf.pos() > pos {
+        buf.prev();
+    }
+}
+
 fn lex_quoted_element<'a>(buf: &mut LexBuf<'a>) -> Result<Cow<'a, str>, String> {
     assert!(buf.consume('"'));
     let s = buf.take_while(|ch| !matches!(ch, '"' | '\\'));
@@ -1275,12 +1337,14 @@ fn lex_unquoted_element<'a>(
                 // End of literal characters as the first character indicates
                 // a missing element definition.
                 if s.is_empty() {
+                    buf.prev();
                     bail!("malformed literal; missing element")
                 }
                 buf.prev();
                 break;
             }
             Some(c) if is_special_char(c) => {
+                buf.prev();
                 bail!("malformed literal; must escape special character '{}'", c)
             }
             Some(c) => {
@@ -1308,12 +1372,17 @@ pub fn parse_map<'a, V, E>(
 where
     E: ToString,
 {
-    parse_map_inner(s, is_value_type_map, gen_elem)
-        .map_err(|details| ParseError::invalid_input_syntax("map", s).with_details(details))
+    let buf = &mut LexBuf::new(s);
+    parse_map_inner(buf, is_value_type_map, gen_elem).map_err(|details| {
+        ParseError::invalid_input_syntax("map", s)
+            .with_details(details)
+            .with_position(buf.pos())
+    })
 }
 
+// On error, `buf` is left at the start of the token at which parsing failed.
 fn parse_map_inner<'a, V, E>(
-    s: &'a str,
+    buf: &mut LexBuf<'a>,
     is_value_type_map: bool,
     mut gen_elem: impl FnMut(Option<Cow<'a, str>>) -> Result<V, E>,
 ) -> Result<BTreeMap<String, V>, String>
@@ -1321,13 +1390,12 @@ where
     E: ToString,
 {
     let mut map = BTreeMap::new();
-    let buf = &mut LexBuf::new(s);
 
     // Consume opening paren.
     if !buf.consume('{') {
         bail!(
             "expected '{{', found {}",
-            match buf.next() {
+            match buf.peek() {
                 Some(c) => format!("{}", c),
                 None => "empty string".to_string(),
             }
@@ -1355,18 +1423,25 @@ where
                 buf.prev();
             }
             Some(',') => {}
-            Some(c) => bail!("expected ',' or end of input, got '{}'", c),
+            Some(c) => {
+                buf.prev();
+                bail!("expected ',' or end of input, got '{}'", c)
+            }
             None => bail!("unexpected end of input"),
         }
 
         // Get key.
         buf.take_while(|ch| ch.is_ascii_whitespace());
+        let start = buf.pos();
         let key = match buf.peek() {
             Some('"') => Some(lex_quoted_element(buf)?),
             Some(_) => lex_unquoted_element(buf, is_special_char, is_end_of_literal)?,
             None => bail!("unexpected end of input"),
         };
-        let key = gen_key(key)?;
+        let key = gen_key(key).map_err(|e| {
+            rewind(buf, start);
+            e
+        })?;
 
         // Assert mapping arrow (=>) is present.
         buf.take_while(|ch| ch.is_ascii_whitespace());
@@ -1376,6 +1451,7 @@ where
 
         // Get value.
         buf.take_while(|ch| ch.is_ascii_whitespace());
+        let start = buf.pos();
         let value = match buf.peek() {
             Some('"') => Some(lex_quoted_element(buf)?),
             Some('{') => {
@@ -1390,7 +1466,10 @@ where
             Some(_) => lex_unquoted_element(buf, is_special_char, is_end_of_literal)?,
             None => bail!("unexpected end of input"),
         };
-        let value = gen_value(value)?;
+        let value = gen_value(value).map_err(|e| {
+            rewind(buf, start);
+            e
+        })?;
 
         // Insert elements.
         map.insert(key, value);
@@ -1937,6 +2016,13 @@ pub struct ParseError {
     pub type_name: String,
     pub input: String,
     pub details: Option<String>,
+    /// The position in `input` at which parsing failed, if known.
+    ///
+    /// Like the positions returned by `position` and `strpos`, this is a 1-based index that
+    /// counts characters, not bytes, so it is always at least 1. It points at the first character
+    /// of the token that could not be parsed, or one past the last character if the input ended
+    /// prematurely.
+    pub position: Option<usize>,
 }
 
 #[derive(
@@ -1971,6 +2057,7 @@ impl ParseError {
             type_name: type_name.into(),
             input: input.into(),
             details: None,
+            position: None,
         }
     }
 
@@ -1981,7 +2068,9 @@ impl ParseError {
         ParseError::new(ParseErrorKind::OutOfRange, type_name, input)
     }
 
-    fn invalid_input_syntax<S>(type_name: &'static str, input: S) -> ParseError
+    /// Constructs an error reporting that `input` is not valid syntax for the
+    /// type named `type_name`.
+    pub fn invalid_input_syntax<S>(type_name: &'static str, input: S) -> ParseError
     where
         S: Into<String>,
     {
@@ -1995,6 +2084,12 @@ impl ParseError {
         self.details = Some(details.to_string());
         self
     }
+
+    /// Sets the position at which parsing failed from the byte offset `offset` in the input.
+    fn with_position(mut self, offset: usize) -> ParseError {
+        self.position = Some(self.input[..offset].chars().count() + 1);
+        self
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -2017,7 +2112,11 @@ impl fmt::Display for ParseError {
                 if let Some(details) = &self.details {
                     write!(f, "{}: ", details)?;
                 }
-                write!(f, "{}", self.input.quoted())
+                write!(f, "{}", self.input.quoted())?;
+                if let Some(position) = self.position {
+                    write!(f, " at character {}", position)?;
+                }
+                Ok(())
             }
         }
     }
@@ -2038,6 +2137,7 @@ impl RustType<ProtoParseError> for ParseError {
             type_name: self.type_name.clone(),
             input: self.input.clone(),
             details: self.details.clone(),
+            position: self.position.map(u64::cast_from),
         }
     }
 
@@ -2053,6 +2153,7 @@ impl RustType<ProtoParseError> for ParseError {
                 type_name: proto.type_name,
                 input: proto.input,
                 details: proto.details,
+                position: proto.position.map(usize::cast_from),
             })
         } else {
             Err(TryFromProtoError::missing_field("ProtoParseError::kind"))
@@ -2075,7 +2176,11 @@ impl RustType<ProtoParseError> for ParseError {
     MzReflect,
 )]
 pub enum ParseHexError {
-    InvalidHexDigit(char),
+    InvalidHexDigit {
+        digit: char,
+        /// The byte offset of `digit` in the input.
+        offset: usize,
+    },
     OddLength,
 }
 impl Error for ParseHexError {}
@@ -2083,9 +2188,12 @@ impl Error for ParseHexError {}
 impl fmt::Display for ParseHexError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ParseHexError::InvalidHexDigit(c) => {
-                write!(f, "invalid hexadecimal digit: \"{}\"", c.escape_default())
-            }
+            ParseHexError::InvalidHexDigit { digit, offset } => write!(
+                f,
+                "invalid hexadecimal digit: \"{}\" at offset {}",
+                digit.escape_default(),
+                offset
+            ),
             ParseHexError::OddLength => {
                 f.write_str("invalid hexadecimal data: odd number of digits")
             }
@@ -2097,18 +2205,26 @@ impl RustType<ProtoParseHexError> for ParseHexError {
     fn into_proto(&self) -> ProtoParseHexError {
         use proto_parse_hex_error::*;
         use Kind::*;
-        let kind = match self {
-            ParseHexError::InvalidHexDigit(v) => InvalidHexDigit(v.into_proto()),
-            ParseHexError::OddLength => OddLength(()),
+        let (kind, offset) = match self {
+            ParseHexError::InvalidHexDigit { digit, offset } => {
+                (InvalidHexDigit(digit.into_proto()), offset.into_proto())
+            }
+            ParseHexError::OddLength => (OddLength(()), 0),
         };
-        ProtoParseHexError { kind: Some(kind) }
+        ProtoParseHexError {
+            kind: Some(kind),
+            offset,
+        }
     }
 
     fn from_proto(error: ProtoParseHexError) -> Result<Self, TryFromProtoError> {
         use proto_parse_hex_error::Kind::*;
         match error.kind {
             Some(kind) => match kind {
-                InvalidHexDigit(v) => Ok(ParseHexError::InvalidHexDigit(char::from_proto(v)?)),
+                InvalidHexDigit(v) => Ok(ParseHexError::InvalidHexDigit {
+                    digit: char::from_proto(v)?,
+                    offset: usize::from_proto(error.offset)?,
+                }),
                 OddLength(()) => Ok(ParseHexError::OddLength),
             },
             None => Err(TryFromProtoError::missing_field(
@@ -2145,6 +2261,65 @@ mod tests {
         }
     }
 
+    #[mz_ore::test]
+    fn test_parse_bytes_hex_offset() {
+        let details = |digit, offset| {
+            ParseHexError::InvalidHexDigit { digit, offset }.to_string_with_causes()
+        };
+        assert_eq!(
+            parse_bytes_hex("01g2"),
+            Err(ParseHexError::InvalidHexDigit {
+                digit: 'g',
+                offset: 2
+            })
+        );
+        // The offset includes the `\x` prefix.
+        assert_eq!(
+            parse_bytes(r"\x01g2"),
+            Err(ParseError::invalid_input_syntax("bytea", r"\x01g2").with_details(details('g', 4)))
+        );
+        assert_eq!(
+            parse_bytes(r"\x0 12"),
+            Err(ParseError::invalid_input_syntax("bytea", r"\x0 12").with_details(details(' ', 3)))
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_parse_bool() {
+        for s in [
+            "t", "tr", "tru", "true", "y", "ye", "yes", "on", "1", "TRUE", "Yes", "oN", " true ",
+            "\ton\n",
+        ] {
+            assert_eq!(parse_bool(s), Ok(true), "{s:?}");
+        }
+        for s in [
+            "f", "fa", "fal", "fals", "false", "n", "no", "of", "off", "0", "FALSE", "No", "OfF",
+            " 0 ", "\toff\n",
+        ] {
+            assert_eq!(parse_bool(s), Ok(false), "{s:?}");
+        }
+        for s in ["", "o", "maybe", "truee", "yess", "10", "t rue"] {
+            assert_eq!(
+                parse_bool(s),
+                Err(ParseError::invalid_input_syntax("boolean", s)),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_format_time_of_day() {
+        let ts = DateTime::from_timestamp(1_729_168_496, 123_456_789).unwrap();
+        let mut buf = String::new();
+        format_time_of_day(&mut buf, &ts);
+        assert_eq!(buf, "Thu Oct 17 12:34:56.123456 2024 UTC");
+
+        let ts = DateTime::from_timestamp(1_704_067_200, 0).unwrap();
+        let mut buf = String::new();
+        format_time_of_day(&mut buf, &ts);
+        assert_eq!(buf, "Mon Jan 01 00:00:00.000000 2024 UTC");
+    }
+
     #[mz_ore::test]
     fn test_format_nanos_to_micros() {
         let cases: Vec<(u32, &str)> = vec![
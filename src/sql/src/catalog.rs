@@ -1667,6 +1667,19 @@ mod tests {
                 "local-az1-1497a3b7-a455-4fc48752-b44a94b5f90a-452",
                 Err(InvalidEnvironmentIdError),
             ),
+            ("aws-us-east-1", Err(InvalidEnvironmentIdError)),
+            (
+                "aws-us-east-1-1497a3b7-a455-4fc4-8752-b44a94b5f90a",
+                Err(InvalidEnvironmentIdError),
+            ),
+            (
+                "aws-us-east-1-1497a3b7-a455-4fc4-8752-b44a94b5f90a--7",
+                Err(InvalidEnvironmentIdError),
+            ),
+            (
+                "unknown-us-east-1-1497a3b7-a455-4fc4-8752-b44a94b5f90a-7",
+                Err(InvalidEnvironmentIdError),
+            ),
         ] {
             let actual = input.parse();
             assert_eq!(expected, actual, "input = {}", input);
@@ -1845,6 +1845,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "array_remove" => Scalar {
             params!(ArrayAnyCompatible, AnyCompatible) => BinaryFunc::ArrayRemove => ArrayAnyCompatible, 3167;
         },
+        "array_slice" => Scalar {
+            params!(ArrayAny, Int32, Int32) => VariadicFunc::ArraySlice => ArrayAny, oid::FUNC_ARRAY_SLICE_OID;
+        },
         "array_to_string" => Scalar {
             params!(ArrayAny, String) => Operation::variadic(array_to_string) => String, 395;
             params!(ArrayAny, String, String) => Operation::variadic(array_to_string) => String, 384;
@@ -1854,6 +1857,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         },
         "ascii" => Scalar {
             params!(String) => UnaryFunc::Ascii(func::Ascii) => Int32, 1620;
+            params!(PgLegacyChar) => UnaryFunc::AsciiPgLegacyChar(func::AsciiPgLegacyChar) => Int32, oid::FUNC_ASCII_PG_LEGACY_CHAR_OID;
         },
         "avg" => Scalar {
             params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("avg")) => Numeric, 2100;
@@ -1870,6 +1874,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Bytes) => UnaryFunc::BitLengthBytes(func::BitLengthBytes) => Int32, 1810;
             params!(String) => UnaryFunc::BitLengthString(func::BitLengthString) => Int32, 1811;
         },
+        "bit_substring" => Scalar {
+            params!(Bytes, Int32, Int32) => VariadicFunc::BitSubstring => Bytes, oid::FUNC_BIT_SUBSTRING_OID;
+        },
         "btrim" => Scalar {
             params!(String) => UnaryFunc::TrimWhitespace(func::TrimWhitespace) => String, 885;
             params!(String, String) => BinaryFunc::Trim => String, 884;
@@ -2085,6 +2092,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                     END"
             ) => String, 1081;
         },
+        "get_bit" => Scalar {
+            params!(Bytes, Int32) => BinaryFunc::GetBit => Int32, 723;
+        },
         "get_byte" => Scalar {
             params!(Bytes, Int32) => BinaryFunc::GetByte => Int32, 721;
         },
@@ -2182,8 +2192,17 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => Jsonb, 3273;
         },
+        "jsonb_object" => Scalar {
+            params!(ScalarType::Array(Box::new(ScalarType::String))) =>
+                UnaryFunc::JsonbObjectFlatArray(func::JsonbObjectFlatArray) => Jsonb, 3263;
+            params!(
+                ScalarType::Array(Box::new(ScalarType::String)),
+                ScalarType::Array(Box::new(ScalarType::String))
+            ) => BinaryFunc::JsonbObjectTwoArray => Jsonb, 3264;
+        },
         "jsonb_pretty" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbPretty(func::JsonbPretty) => String, 3306;
+            params!(Jsonb, String) => BinaryFunc::JsonbPrettyIndent => String, oid::FUNC_JSONB_PRETTY_INDENT_OID;
         },
         "jsonb_strip_nulls" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbStripNulls(func::JsonbStripNulls) => Jsonb, 3262;
@@ -2528,6 +2547,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         },
         "position" => Scalar {
             params!(String, String) => BinaryFunc::Position => Int32, 849;
+            params!(Bytes, Bytes) => BinaryFunc::PositionBytes => Int32, 2014;
         },
         "pow" => Scalar {
             params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("pow")) => Float64, 1346;
@@ -2544,6 +2564,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         },
         "repeat" => Scalar {
             params!(String, Int32) => BinaryFunc::RepeatString => String, 1622;
+            params!(Bytes, Int32) => BinaryFunc::RepeatBytes => Bytes, oid::FUNC_REPEAT_BYTES_OID;
         },
         "regexp_match" => Scalar {
             params!(String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3396;
@@ -2622,6 +2643,14 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, oid::FUNC_STDDEV_SAMP_UINT32_OID;
             params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, oid::FUNC_STDDEV_SAMP_UINT64_OID;
         },
+        "strpos" => Scalar {
+            params!(String, String) => Operation::binary(|_ecx, string, substring| {
+                Ok(substring.call_binary(string, BinaryFunc::Position))
+            }) => Int32, 868;
+            params!(Bytes, Bytes) => Operation::binary(|_ecx, bytes, substring| {
+                Ok(substring.call_binary(bytes, BinaryFunc::PositionBytes))
+            }) => Int32, oid::FUNC_STRPOS_BYTES_OID;
+        },
         "substr" => Scalar {
             params!(String, Int32) => VariadicFunc::Substr => String, 883;
             params!(String, Int32, Int32) => VariadicFunc::Substr => String, 877;
@@ -2650,6 +2679,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Timestamp, Timestamp) => BinaryFunc::AgeTimestamp => Interval, 2058;
             params!(TimestampTz, TimestampTz) => BinaryFunc::AgeTimestampTz => Interval, 1199;
         },
+        "timeofday" => Scalar {
+            params!() => UnmaterializableFunc::TimeOfDay => String, 274;
+        },
         "timezone" => Scalar {
             params!(String, Timestamp) => BinaryFunc::TimezoneTimestamp => TimestampTz, 2069;
             params!(String, TimestampTz) => BinaryFunc::TimezoneTimestampTz => Timestamp, 1159;
@@ -2686,6 +2718,13 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "to_char" => Scalar {
             params!(Timestamp, String) => BinaryFunc::ToCharTimestamp => String, 2049;
             params!(TimestampTz, String) => BinaryFunc::ToCharTimestampTz => String, 1770;
+            params!(Int64, String) => BinaryFunc::ToCharInt64 => String, 1774;
+        },
+        "to_date" => Scalar {
+            params!(String, String) => BinaryFunc::ToDate => Date, 1780;
+        },
+        "to_number" => Scalar {
+            params!(String, String) => BinaryFunc::ToNumber => Numeric, 1777;
         },
         // > Returns the value as json or jsonb. Arrays and composites
         // > are converted (recursively) to arrays and objects;
@@ -2709,6 +2748,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         },
         "to_timestamp" => Scalar {
             params!(Float64) => UnaryFunc::ToTimestamp(func::ToTimestamp) => TimestampTz, 1158;
+            params!(String, String) => BinaryFunc::ToTimestampFormat => TimestampTz, 1778;
         },
         "translate" => Scalar {
             params!(String, String, String) => VariadicFunc::Translate => String, 878;
@@ -3419,12 +3459,24 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String, String) => VariadicFunc::RegexpSplitToArray => ScalarType::Array(Box::new(ScalarType::String)), 2768;
         },
         "regexp_split_to_table" => Table {
-            params!(String, String) => sql_impl_table_func("
-                SELECT unnest(regexp_split_to_array($1, $2))
-            ") => ReturnType::set_of(String.into()), 2765;
-            params!(String, String, String) => sql_impl_table_func("
-                SELECT unnest(regexp_split_to_array($1, $2, $3))
-            ") => ReturnType::set_of(String.into()), 2766;
+            params!(String, String) => Operation::variadic(move |_ecx, exprs| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::RegexpSplitToTable,
+                        exprs,
+                    },
+                    column_names: vec!["regexp_split_to_table".into()],
+                })
+            }) => ReturnType::set_of(String.into()), 2765;
+            params!(String, String, String) => Operation::variadic(move |_ecx, exprs| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::RegexpSplitToTable,
+                        exprs,
+                    },
+                    column_names: vec!["regexp_split_to_table".into()],
+                })
+            }) => ReturnType::set_of(String.into()), 2766;
         },
         "regexp_replace" => Scalar {
             params!(String, String, String) => VariadicFunc::RegexpReplace => String, 2284;
@@ -3544,6 +3596,9 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, Date, Date) => VariadicFunc::DateDiffDate => Int64, oid::FUNC_DATEDIFF_DATE;
             params!(String, Time, Time) => VariadicFunc::DateDiffTime => Int64, oid::FUNC_DATEDIFF_TIME;
         },
+        "degrees_numeric" => Scalar {
+            params!(Numeric) => UnaryFunc::DegreesNumeric(func::DegreesNumeric) => Numeric, oid::FUNC_DEGREES_NUMERIC_OID;
+        },
         // We can't use the `privilege_fn!` macro because the macro relies on the object having an
         // OID, and clusters do not have OIDs.
         "has_cluster_privilege" => Scalar {
@@ -3652,6 +3707,12 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3142;
             params!(Oid, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3143;
         },
+        "interval_to_human_text" => Scalar {
+            params!(Interval) => UnaryFunc::IntervalToHumanText(func::IntervalToHumanText) => String, oid::FUNC_INTERVAL_TO_HUMAN_TEXT_OID;
+        },
+        "last_codepoint" => Scalar {
+            params!(String) => UnaryFunc::LastCodePoint(func::LastCodePoint) => Int32, oid::FUNC_LAST_CODEPOINT_OID;
+        },
         "list_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 if let ScalarType::Char {.. }  = ecx.scalar_type(&e) {
@@ -3770,6 +3831,15 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "mz_environment_id" => Scalar {
             params!() => UnmaterializableFunc::MzEnvironmentId => String, oid::FUNC_MZ_ENVIRONMENT_ID_OID;
         },
+        "mz_environment_id_parts" => Scalar {
+            params!() => UnmaterializableFunc::MzEnvironmentIdParts => ScalarType::Record {
+                fields: vec![
+                    (ColumnName::from("organization_id"), ScalarType::Uuid.nullable(false)),
+                    (ColumnName::from("ordinal"), ScalarType::UInt64.nullable(false)),
+                ],
+                custom_id: None,
+            }, oid::FUNC_MZ_ENVIRONMENT_ID_PARTS_OID;
+        },
         "mz_is_superuser" => Scalar {
             params!() => UnmaterializableFunc::MzIsSuperuser => ScalarType::Bool, oid::FUNC_MZ_IS_SUPERUSER;
         },
@@ -3795,6 +3865,9 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 Ok(s.call_binary(width, BinaryFunc::PrettySql))
             }) => String, oid::FUNC_PRETTY_SQL_NOWIDTH;
         },
+        "radians_numeric" => Scalar {
+            params!(Numeric) => UnaryFunc::RadiansNumeric(func::RadiansNumeric) => Numeric, oid::FUNC_RADIANS_NUMERIC_OID;
+        },
         "regexp_extract" => Table {
             params!(String, String) => Operation::binary(move |_ecx, regex, haystack| {
                 let regex = match regex.into_literal_string() {
@@ -3890,6 +3963,12 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
         "aclitem_privileges" => Scalar {
             params!(AclItem) => UnaryFunc::AclItemPrivileges(func::AclItemPrivileges) => String, oid::FUNC_ACL_ITEM_PRIVILEGES_OID;
         },
+        "aclitem_privileges_diff" => Scalar {
+            params!(AclItem, AclItem) => BinaryFunc::AclItemPrivilegesDiff => String, oid::FUNC_ACL_ITEM_PRIVILEGES_DIFF_OID;
+        },
+        "aclitem_privileges_union" => Scalar {
+            params!(AclItem, AclItem) => BinaryFunc::AclItemPrivilegesUnion => String, oid::FUNC_ACL_ITEM_PRIVILEGES_UNION_OID;
+        },
         "is_rbac_enabled" => Scalar {
             params!() => UnmaterializableFunc::IsRbacEnabled => Bool, oid::FUNC_IS_RBAC_ENABLED_OID;
         },
@@ -3919,6 +3998,12 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
         "mz_aclitem_privileges" => Scalar {
             params!(MzAclItem) => UnaryFunc::MzAclItemPrivileges(func::MzAclItemPrivileges) => String, oid::FUNC_MZ_ACL_ITEM_PRIVILEGES_OID;
         },
+        "mz_aclitem_privileges_diff" => Scalar {
+            params!(MzAclItem, MzAclItem) => BinaryFunc::MzAclItemPrivilegesDiff => String, oid::FUNC_MZ_ACL_ITEM_PRIVILEGES_DIFF_OID;
+        },
+        "mz_aclitem_privileges_union" => Scalar {
+            params!(MzAclItem, MzAclItem) => BinaryFunc::MzAclItemPrivilegesUnion => String, oid::FUNC_MZ_ACL_ITEM_PRIVILEGES_UNION_OID;
+        },
         // There is no regclass equivalent for roles to look up connections, so we
         // have this helper function instead.
         //
@@ -4699,6 +4784,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
                 Ok(lhs.call_binary(rhs, TextConcat))
             }) => String, 2780;
             params!(String, String) => TextConcat => String, 654;
+            params!(Bytes, Bytes) => ConcatBytes => Bytes, 2018;
             params!(Jsonb, Jsonb) => JsonbConcat => Jsonb, 3284;
             params!(ArrayAnyCompatible, ArrayAnyCompatible) => ArrayArrayConcat => ArrayAnyCompatible, 375;
             params!(ListAnyCompatible, ListAnyCompatible) => ListListConcat => ListAnyCompatible, oid::OP_CONCAT_LIST_LIST_OID;
@@ -4735,6 +4821,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
                       .call_binary(rhs, JsonbContainsJsonb))
             }) => Bool, oid::OP_CONTAINS_STRING_JSONB_OID;
             params!(MapAnyCompatible, MapAnyCompatible) => MapContainsMap => Bool, oid::OP_CONTAINS_MAP_MAP_OID;
+            params!(ArrayAnyCompatible, ArrayAnyCompatible) => ArrayContainsArray => Bool, 2751;
+            params!(ListAnyCompatible, ListAnyCompatible) => ListContainsList => Bool, oid::OP_CONTAINS_LIST_LIST_OID;
             params!(RangeAny, AnyElement) => Operation::binary(|ecx, lhs, rhs| {
                 let elem_type = ecx.scalar_type(&lhs).unwrap_range_element_type().clone();
                 Ok(lhs.call_binary(rhs, BinaryFunc::RangeContainsElem { elem_type, rev: false }))
@@ -4763,6 +4851,12 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(MapAnyCompatible, MapAnyCompatible) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(rhs.call_binary(lhs, MapContainsMap))
             }) => Bool, oid::OP_CONTAINED_MAP_MAP_OID;
+            params!(ArrayAnyCompatible, ArrayAnyCompatible) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, ArrayContainsArray))
+            }) => Bool, 2752;
+            params!(ListAnyCompatible, ListAnyCompatible) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, ListContainsList))
+            }) => Bool, oid::OP_CONTAINED_LIST_LIST_OID;
             params!(AnyElement, RangeAny) => Operation::binary(|ecx, lhs, rhs| {
                 let elem_type = ecx.scalar_type(&rhs).unwrap_range_element_type().clone();
                 Ok(rhs.call_binary(lhs, BinaryFunc::RangeContainsElem { elem_type, rev: true }))
@@ -4775,6 +4869,12 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Jsonb, String) => JsonbContainsString => Bool, 3247;
             params!(MapAny, String) => MapContainsKey => Bool, oid::OP_CONTAINS_KEY_MAP_OID;
         },
+        "@?" => Scalar {
+            params!(Jsonb, String) => JsonbPathMatchExists => Bool, oid::OP_PATH_EXISTS_JSONB_OID;
+        },
+        "@@" => Scalar {
+            params!(Jsonb, String) => JsonbPathMatchPredicate => Bool, oid::OP_PATH_MATCH_JSONB_OID;
+        },
         "?&" => Scalar {
             params!(MapAny, ScalarType::Array(Box::new(ScalarType::String))) => MapContainsAllKeys => Bool, oid::OP_CONTAINS_ALL_KEYS_MAP_OID;
         },
@@ -297,11 +297,13 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
 
     casts! {
         // BOOL
+        (Bool, Int16) => Explicit: CastBoolToInt16(func::CastBoolToInt16),
         (Bool, Int32) => Explicit: CastBoolToInt32(func::CastBoolToInt32),
         (Bool, Int64) => Explicit: CastBoolToInt64(func::CastBoolToInt64),
         (Bool, String) => Assignment: CastBoolToString(func::CastBoolToString),
 
         //INT16
+        (Int16, Bool) => Explicit: CastInt16ToBool(func::CastInt16ToBool),
         (Int16, Int32) => Implicit: CastInt16ToInt32(func::CastInt16ToInt32),
         (Int16, Int64) => Implicit: CastInt16ToInt64(func::CastInt16ToInt64),
         (Int16, UInt16) => Assignment: CastInt16ToUint16(func::CastInt16ToUint16),
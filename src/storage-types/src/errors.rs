@@ -542,6 +542,8 @@ mod columnation {
                         | e @ EvalError::CharOutOfRange
                         | e @ EvalError::InvalidBase64Equals
                         | e @ EvalError::InvalidBase64EndSequence
+                        | e @ EvalError::InvalidBase32EndSequence
+                        | e @ EvalError::IncompatibleArrayElementDimensions
                         | e @ EvalError::InvalidTimezoneInterval
                         | e @ EvalError::InvalidTimezoneConversion
                         | e @ EvalError::LengthTooLarge
@@ -596,6 +598,12 @@ mod columnation {
                             assert_copy(c);
                             e.clone()
                         }
+                        e @ EvalError::InvalidBase64SymbolAt { symbol, offset }
+                        | e @ EvalError::InvalidBase32Symbol { symbol, offset } => {
+                            assert_copy(symbol);
+                            assert_copy(offset);
+                            e.clone()
+                        }
                         EvalError::InvalidTimezone(x) => {
                             EvalError::InvalidTimezone(self.string_region.copy(x))
                         }
@@ -647,6 +655,7 @@ mod columnation {
                             type_name,
                             input,
                             details,
+                            position,
                         }) => EvalError::Parse(ParseError {
                             kind: *kind,
                             type_name: self.string_region.copy(type_name),
@@ -654,6 +663,7 @@ mod columnation {
                             details: details
                                 .as_ref()
                                 .map(|details| self.string_region.copy(details)),
+                            position: *position,
                         }),
                         e @ EvalError::ParseHex(x) => {
                             assert_copy(x);